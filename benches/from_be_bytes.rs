@@ -0,0 +1,45 @@
+//! Compares `NanBstr::from_be_bytes` with `from_be_bytes_unchecked`, which
+//! skips the NaN check.
+//!
+//! Run with `cargo bench --bench from_be_bytes`.
+
+use std::{hint::black_box, time::Instant};
+
+use cbor_nan_bstr::NanBstr;
+
+const ITERATIONS: u32 = 2_000_000;
+
+fn patterns() -> Vec<Vec<u8>> {
+    vec![
+        0x7E01u16.to_be_bytes().to_vec(),
+        0xFFC0_0001u32.to_be_bytes().to_vec(),
+        0x7FF8_0000_0000_0123u64.to_be_bytes().to_vec(),
+        0x7FFF_8000_0000_0000_0000_0000_0000_002Au128
+            .to_be_bytes()
+            .to_vec(),
+    ]
+}
+
+/// Times `f` over every pattern, returning nanoseconds per call.
+fn time(patterns: &[Vec<u8>], f: impl Fn(&[u8]) -> NanBstr) -> f64 {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for pattern in patterns {
+            black_box(f(black_box(pattern)));
+        }
+    }
+    let calls = ITERATIONS as f64 * patterns.len() as f64;
+    start.elapsed().as_nanos() as f64 / calls
+}
+
+fn main() {
+    let patterns = patterns();
+    let checked =
+        time(&patterns, |bytes| NanBstr::from_be_bytes(bytes).unwrap());
+    let unchecked = time(&patterns, |bytes| {
+        NanBstr::from_be_bytes_unchecked(bytes).unwrap()
+    });
+    println!("from_be_bytes            {:>8.2} ns/call", checked);
+    println!("from_be_bytes_unchecked  {:>8.2} ns/call", unchecked);
+    println!("speedup                  {:>8.2}x", checked / unchecked);
+}
@@ -0,0 +1,48 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{NanBstr, NanWidth};
+
+impl<'a> Arbitrary<'a> for NanWidth {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&NanWidth::ALL).copied()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(1))
+    }
+}
+
+/// Always produces a valid NaN: a width, sign, and quiet bit are drawn
+/// independently, then a payload within the width's range. Half the time the
+/// payload is an edge case, the smallest (zero for a quiet NaN, one for a
+/// signaling NaN, whose zero payload would be an infinity) or the largest;
+/// otherwise it is drawn from the whole range.
+impl<'a> Arbitrary<'a> for NanBstr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let width = NanWidth::arbitrary(u)?;
+        let sign = bool::arbitrary(u)?;
+        let quiet = bool::arbitrary(u)?;
+        let min_payload = if quiet { 0 } else { 1 };
+        let max_payload = width.max_payload();
+        let payload = match u.int_in_range(0..=3u8)? {
+            0 => min_payload,
+            1 => max_payload,
+            _ => u.int_in_range(min_payload..=max_payload)?,
+        };
+        Ok(NanBstr::from_parts(width, sign, quiet, payload)
+            .expect("fields are in range"))
+    }
+
+    /// One byte each for the width, sign, quiet bit, and payload choice,
+    /// then a byte per eight bits of the width's payload range, at most 14
+    /// for binary128.
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (width_min, width_max) = NanWidth::size_hint(depth);
+        let payload_bits =
+            u128::BITS - NanWidth::Binary128.max_payload().leading_zeros();
+        (
+            width_min + 3,
+            width_max.map(|max| max + 3 + payload_bits.div_ceil(8) as usize),
+        )
+    }
+}
@@ -0,0 +1,344 @@
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+use core::fmt;
+
+use dcbor::prelude::*;
+
+use crate::{
+    CborPath, KnownPattern, NanBstr, NanClass, NanPolicy, NanWidth,
+    PolicyViolation, nan_bstr::fold_bits, provenance::nearest_known_bits,
+    walk::walk_nan_bstrs,
+};
+
+/// The largest Hamming distance at which the audit suggests a near-miss
+/// known pattern.
+pub const AUDIT_NEAREST_DISTANCE: u32 = 2;
+
+/// A NaN found during an audit, with everything known about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NanAuditEntry {
+    /// Where the NaN occurs in the document.
+    pub path: CborPath,
+    /// The NaN itself.
+    pub nan: NanBstr,
+    /// The well-known pattern the NaN matches, if any.
+    pub provenance: Option<KnownPattern>,
+    /// If there is no exact match, the nearest well-known pattern and its
+    /// distance in bits, if within [`AUDIT_NEAREST_DISTANCE`].
+    pub nearest: Option<(KnownPattern, u32)>,
+    /// The policy rules the NaN breaks; empty if no policy was supplied.
+    pub violations: Vec<PolicyViolation>,
+}
+
+impl NanAuditEntry {
+    /// Returns the NaN's [`NanClass`], which displays as `quiet`,
+    /// `quiet with payload`, or `signaling`.
+    pub fn classification(&self) -> NanClass {
+        self.nan.classify()
+    }
+}
+
+/// A tag-102 item whose content could not be decoded as a NaN.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedNan {
+    /// Where the item occurs in the document.
+    pub path: CborPath,
+    /// Why the content was rejected.
+    pub reason: String,
+    /// If the content is a byte string of a NaN width, the nearest
+    /// well-known pattern and its distance in bits, if within
+    /// [`AUDIT_NEAREST_DISTANCE`].
+    pub nearest: Option<(KnownPattern, u32)>,
+}
+
+/// A summary of every NaN in a CBOR document, suitable for handing to
+/// auditors.
+///
+/// Renders to Markdown via `Display`, and to CBOR via `From<NanAuditReport>
+/// for CBOR` for machine consumption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NanAuditReport {
+    /// The valid NaNs found, in document order.
+    pub entries: Vec<NanAuditEntry>,
+    /// The tag-102 items that failed to decode, in document order.
+    pub malformed: Vec<MalformedNan>,
+    /// Whether the entries were checked against a policy.
+    pub policy_applied: bool,
+}
+
+impl NanAuditReport {
+    /// Returns the number of valid NaNs found.
+    pub fn count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the number of valid NaNs of the given width.
+    pub fn count_width(&self, width: NanWidth) -> usize {
+        self.count_where(|e| e.nan.width() == width)
+    }
+
+    /// Returns the number of quiet NaNs.
+    pub fn quiet_count(&self) -> usize {
+        self.count_where(|e| e.nan.is_quiet())
+    }
+
+    /// Returns the number of signaling NaNs.
+    pub fn signaling_count(&self) -> usize {
+        self.count_where(|e| e.nan.is_signaling())
+    }
+
+    /// Returns the number of NaNs with a non-zero payload.
+    pub fn payload_count(&self) -> usize {
+        self.count_where(|e| e.nan.payload_bits() != 0)
+    }
+
+    /// Returns the number of NaNs with the sign bit set.
+    pub fn negative_count(&self) -> usize {
+        self.count_where(|e| e.nan.sign())
+    }
+
+    /// Returns the number of NaNs that break the policy.
+    pub fn violation_count(&self) -> usize {
+        self.count_where(|e| !e.violations.is_empty())
+    }
+
+    /// Returns the policy verdict: `Some(true)` if every NaN complies and no
+    /// tag-102 item is malformed, `Some(false)` otherwise, or `None` if no
+    /// policy was supplied.
+    pub fn passes(&self) -> Option<bool> {
+        self.policy_applied
+            .then(|| self.violation_count() == 0 && self.malformed.is_empty())
+    }
+
+    fn count_where(&self, f: impl Fn(&NanAuditEntry) -> bool) -> usize {
+        self.entries.iter().filter(|e| f(e)).count()
+    }
+
+    pub(crate) fn counts(&self) -> Vec<(&'static str, usize)> {
+        let mut counts = vec![
+            ("total", self.count()),
+            ("binary16", self.count_width(NanWidth::Binary16)),
+            ("binary32", self.count_width(NanWidth::Binary32)),
+            ("binary64", self.count_width(NanWidth::Binary64)),
+            ("binary128", self.count_width(NanWidth::Binary128)),
+            ("quiet", self.quiet_count()),
+            ("signaling", self.signaling_count()),
+            ("payload", self.payload_count()),
+            ("negative", self.negative_count()),
+            ("malformed", self.malformed.len()),
+        ];
+        if self.policy_applied {
+            counts.push(("violations", self.violation_count()));
+        }
+        counts
+    }
+}
+
+/// Audits every tag-102 item in `cbor`.
+///
+/// Each NaN is recorded with its path, classification, payload, and best
+/// guess at provenance, including a near-miss known pattern when there is no
+/// exact match. If `policy` is supplied, each NaN is also checked
+/// against it and the report carries an overall verdict.
+pub fn audit_report(cbor: &CBOR, policy: Option<&NanPolicy>) -> NanAuditReport {
+    let mut report = NanAuditReport {
+        entries: Vec::new(),
+        malformed: Vec::new(),
+        policy_applied: policy.is_some(),
+    };
+    walk_nan_bstrs(cbor, &mut |path, content, nan| match nan {
+        Ok(nan) => {
+            let provenance = nan.identify();
+            report.entries.push(NanAuditEntry {
+                path: path.clone(),
+                provenance,
+                nearest: provenance
+                    .is_none()
+                    .then(|| nan.nearest_known(AUDIT_NEAREST_DISTANCE))
+                    .flatten(),
+                violations: policy
+                    .map(|p| p.violations(&nan))
+                    .unwrap_or_default(),
+                nan,
+            })
+        }
+        Err(err) => report.malformed.push(MalformedNan {
+            path: path.clone(),
+            reason: err.to_string(),
+            nearest: nearest_to_content(content),
+        }),
+    });
+    report
+}
+
+fn nearest_to_content(content: &CBOR) -> Option<(KnownPattern, u32)> {
+    let CBORCase::ByteString(bytes) = content.as_case() else {
+        return None;
+    };
+    let width = NanWidth::from_len(bytes.len()).ok()?;
+    nearest_known_bits(width, fold_bits(bytes.data()), AUDIT_NEAREST_DISTANCE)
+}
+
+fn describe_nearest(nearest: Option<(KnownPattern, u32)>) -> String {
+    match nearest {
+        Some((pattern, 1)) => format!("~{} (1 bit)", pattern.name),
+        Some((pattern, distance)) => {
+            format!("~{} ({} bits)", pattern.name, distance)
+        }
+        None => "unknown".to_string(),
+    }
+}
+
+// ───────────────────────────────── Markdown ─────────────────────────────────
+
+impl fmt::Display for NanAuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# NaN Audit Report")?;
+        writeln!(f)?;
+        writeln!(f, "## Summary")?;
+        writeln!(f)?;
+        writeln!(f, "| Metric | Count |")?;
+        writeln!(f, "| --- | ---: |")?;
+        for (name, count) in self.counts() {
+            writeln!(f, "| {} | {} |", name, count)?;
+        }
+        if let Some(passes) = self.passes() {
+            writeln!(f)?;
+            let verdict = if passes { "PASS" } else { "FAIL" };
+            writeln!(f, "Policy verdict: **{}**", verdict)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "## NaNs")?;
+        writeln!(f)?;
+        if self.entries.is_empty() {
+            writeln!(f, "No NaNs found.")?;
+        } else {
+            write!(
+                f,
+                "| Path | Width | Class | Sign | Payload | Provenance |"
+            )?;
+            if self.policy_applied {
+                write!(f, " Policy |")?;
+            }
+            writeln!(f)?;
+            write!(f, "| --- | --- | --- | --- | --- | --- |")?;
+            if self.policy_applied {
+                write!(f, " --- |")?;
+            }
+            writeln!(f)?;
+            for entry in &self.entries {
+                write!(
+                    f,
+                    "| `{}` | {} | {} | {} | 0x{:x} | {} |",
+                    entry.path,
+                    entry.nan.width(),
+                    entry.classification(),
+                    if entry.nan.sign() { "-" } else { "+" },
+                    entry.nan.payload_bits(),
+                    entry.provenance.map_or_else(
+                        || describe_nearest(entry.nearest),
+                        |p| p.name.to_string(),
+                    ),
+                )?;
+                if self.policy_applied {
+                    if entry.violations.is_empty() {
+                        write!(f, " ok |")?;
+                    } else {
+                        let violations: Vec<String> = entry
+                            .violations
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect();
+                        write!(f, " {} |", violations.join("; "))?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if !self.malformed.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "## Malformed tag-102 items")?;
+            writeln!(f)?;
+            writeln!(f, "| Path | Reason | Nearest known |")?;
+            writeln!(f, "| --- | --- | --- |")?;
+            for item in &self.malformed {
+                writeln!(
+                    f,
+                    "| `{}` | {} | {} |",
+                    item.path,
+                    item.reason,
+                    describe_nearest(item.nearest)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ─────────────────────────────────── CBOR ───────────────────────────────────
+
+impl From<NanAuditReport> for CBOR {
+    fn from(report: NanAuditReport) -> Self {
+        let mut counts = Map::new();
+        for (name, count) in report.counts() {
+            counts.insert(name, count);
+        }
+
+        let nans: Vec<CBOR> = report
+            .entries
+            .iter()
+            .map(|entry| {
+                let mut map = Map::new();
+                map.insert("path", entry.path.to_string());
+                map.insert("nan", entry.nan.clone());
+                map.insert("class", entry.classification().to_string());
+                if let Some(provenance) = entry.provenance {
+                    map.insert("provenance", provenance.name);
+                }
+                if let Some(nearest) = entry.nearest {
+                    map.insert("nearest", nearest_to_cbor(nearest));
+                }
+                if report.policy_applied {
+                    let violations: Vec<String> = entry
+                        .violations
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect();
+                    map.insert("violations", violations);
+                }
+                map.into()
+            })
+            .collect();
+
+        let malformed: Vec<CBOR> = report
+            .malformed
+            .iter()
+            .map(|item| {
+                let mut map = Map::new();
+                map.insert("path", item.path.to_string());
+                map.insert("reason", item.reason.as_str());
+                if let Some(nearest) = item.nearest {
+                    map.insert("nearest", nearest_to_cbor(nearest));
+                }
+                map.into()
+            })
+            .collect();
+
+        let mut map = Map::new();
+        map.insert("counts", counts);
+        map.insert("nans", nans);
+        map.insert("malformed", malformed);
+        if let Some(passes) = report.passes() {
+            map.insert("verdict", if passes { "pass" } else { "fail" });
+        }
+        map.into()
+    }
+}
+
+fn nearest_to_cbor((pattern, distance): (KnownPattern, u32)) -> CBOR {
+    let mut map = Map::new();
+    map.insert("name", pattern.name);
+    map.insert("distance", distance);
+    map.into()
+}
@@ -0,0 +1,136 @@
+use core::fmt;
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// A bfloat16 NaN.
+///
+/// bfloat16 has a sign bit, an 8-bit exponent, and a 7-bit fraction whose
+/// most significant bit is the quiet/signaling indicator. A NaN has the
+/// exponent all ones and a non-zero fraction.
+///
+/// bfloat16 is not an IEEE 754 interchange format, and tag 102 admits only
+/// the binary16/32/64/128 widths, so a two-byte bfloat16 pattern placed under
+/// the tag would be read as a binary16 NaN with a different meaning. This
+/// type is therefore not CBOR-encodable; convert it to a binary32
+/// [`NanBstr`] to transport it.
+///
+/// # Payload mapping
+///
+/// A bfloat16 value is the upper half of a binary32 value, so widening
+/// shifts the pattern left by 16: sign, exponent, quiet bit, and payload all
+/// land on their binary32 counterparts, and the low 16 bits are zero.
+/// Narrowing with [`from_nan_bstr`](Self::from_nan_bstr) succeeds only when
+/// those low 16 bits are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bf16Nan(u16);
+
+impl Bf16Nan {
+    /// Construct from a 16-bit bfloat16 bit pattern, validated as
+    /// [`NanBstr::from_bfloat16_bits`] validates it.
+    pub fn from_bits(bits: u16) -> Result<Self> {
+        NanBstr::from_bfloat16_bits(bits).map(|_| Self(bits))
+    }
+
+    /// Narrows a binary32 [`NanBstr`] whose low 16 bits are zero.
+    ///
+    /// Fails with [`Error::WidthMismatch`] for other widths and with
+    /// [`Error::PayloadTooLarge`] if any low bit is set.
+    pub fn from_nan_bstr(nan: &NanBstr) -> Result<Self> {
+        if nan.width() != NanWidth::Binary32 {
+            return Err(Error::WidthMismatch {
+                expected: NanWidth::Binary32,
+                actual: nan.width(),
+            });
+        }
+        let bits = nan.to_bits_u128() as u32;
+        if bits & 0xFFFF != 0 {
+            return Err(Error::PayloadTooLarge {
+                width: NanWidth::Binary32,
+                payload: nan.payload_bits(),
+            });
+        }
+        Self::from_bits((bits >> 16) as u16)
+    }
+
+    /// Returns the 16-bit bfloat16 bit pattern.
+    pub fn to_bits(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the sign bit (true if set).
+    pub fn sign(&self) -> bool {
+        self.0 & 0x8000 != 0
+    }
+
+    /// Returns true if the quiet/signaling indicator bit is 1 (quiet NaN).
+    pub fn is_quiet(&self) -> bool {
+        self.0 & 0x0040 != 0
+    }
+
+    /// Returns true if the NaN is signaling (quiet bit == 0).
+    pub fn is_signaling(&self) -> bool {
+        !self.is_quiet()
+    }
+
+    /// Returns the 7-bit fraction field, including the quiet/signaling
+    /// indicator in its MSB.
+    pub fn fraction_bits(&self) -> u16 {
+        self.0 & 0x007F
+    }
+
+    /// Returns the 6 payload bits beneath the quiet/signaling indicator.
+    pub fn payload_bits(&self) -> u16 {
+        self.0 & 0x003F
+    }
+
+    /// Widens to a binary32 [`NanBstr`]. Always lossless.
+    pub fn to_nan_bstr(&self) -> NanBstr {
+        NanBstr::from_bfloat16_bits(self.0)
+            .expect("bfloat16 NaN widens to a binary32 NaN")
+    }
+}
+
+impl From<Bf16Nan> for NanBstr {
+    fn from(nan: Bf16Nan) -> Self {
+        nan.to_nan_bstr()
+    }
+}
+
+impl TryFrom<&NanBstr> for Bf16Nan {
+    type Error = Error;
+
+    fn try_from(nan: &NanBstr) -> Result<Self> {
+        Self::from_nan_bstr(nan)
+    }
+}
+
+impl TryFrom<half::bf16> for Bf16Nan {
+    type Error = Error;
+
+    fn try_from(value: half::bf16) -> Result<Self> {
+        Self::from_bits(value.to_bits())
+    }
+}
+
+impl From<Bf16Nan> for half::bf16 {
+    fn from(nan: Bf16Nan) -> Self {
+        half::bf16::from_bits(nan.0)
+    }
+}
+
+impl fmt::Display for Bf16Nan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NaN[bf16]: {} {} frac=0x{:x} payload=0x{:x}",
+            if self.sign() { "-" } else { "+" },
+            if self.is_quiet() {
+                "quiet"
+            } else {
+                "signaling"
+            },
+            self.fraction_bits(),
+            self.payload_bits()
+        )
+    }
+}
@@ -0,0 +1,137 @@
+use core::fmt;
+
+use dcbor::prelude::*;
+
+use crate::{Error, Result};
+
+/// An IEEE 754 binary256 NaN.
+///
+/// binary256 has a sign bit, a 19-bit exponent, and a 236-bit trailing
+/// significand whose most significant bit is the quiet/signaling indicator.
+/// A NaN has the exponent all ones and a non-zero significand. All APIs work
+/// on big-endian `[u8; 32]` arrays; no big-integer type is involved.
+///
+/// The draft permits only 2, 4, 8, and 16-byte patterns under tag 102, so
+/// [`NanBstr`](crate::NanBstr) never accepts 32 bytes. This type can be
+/// placed under the tag only when the caller opts in with
+/// [`NanTagProfile::AllowBinary256`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Binary256Nan([u8; 32]);
+
+/// Whether tag 102 may carry widths beyond those the draft lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NanTagProfile {
+    /// Only 2, 4, 8, and 16-byte patterns, as the draft specifies.
+    #[default]
+    Strict,
+    /// Additionally accept 32-byte binary256 patterns.
+    AllowBinary256,
+}
+
+impl Binary256Nan {
+    /// Construct from 32 big-endian bytes.
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Result<Self> {
+        let exponent_all_ones = bytes[0] & 0x7F == 0x7F
+            && bytes[1] == 0xFF
+            && bytes[2] & 0xF0 == 0xF0;
+        let fraction_zero =
+            bytes[2] & 0x0F == 0 && bytes[3..].iter().all(|&b| b == 0);
+        if !exponent_all_ones || fraction_zero {
+            return Err(Error::NotANan);
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Returns the raw bytes in big-endian order.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the sign bit (true if set).
+    pub fn sign(&self) -> bool {
+        self.0[0] & 0x80 != 0
+    }
+
+    /// Returns true if the quiet/signaling indicator bit is 1 (quiet NaN).
+    pub fn is_quiet(&self) -> bool {
+        self.0[2] & 0x08 != 0
+    }
+
+    /// Returns true if the NaN is signaling (quiet bit == 0).
+    pub fn is_signaling(&self) -> bool {
+        !self.is_quiet()
+    }
+
+    /// Returns the 236-bit trailing significand, including the
+    /// quiet/signaling indicator, right-aligned in 32 big-endian bytes.
+    pub fn fraction_bytes(&self) -> [u8; 32] {
+        self.masked(0x0F)
+    }
+
+    /// Returns the 235 payload bits beneath the quiet/signaling indicator,
+    /// right-aligned in 32 big-endian bytes.
+    pub fn payload_bytes(&self) -> [u8; 32] {
+        self.masked(0x07)
+    }
+
+    /// Returns true if every payload bit is zero.
+    pub fn is_payload_zero(&self) -> bool {
+        self.payload_bytes().iter().all(|&b| b == 0)
+    }
+
+    /// Encodes as a tag-102 item, provided `profile` permits binary256.
+    ///
+    /// Under [`NanTagProfile::Strict`] this fails with
+    /// [`Error::InvalidLength`], as for any 32-byte pattern.
+    pub fn to_cbor(&self, profile: NanTagProfile) -> Result<CBOR> {
+        if profile != NanTagProfile::AllowBinary256 {
+            return Err(Error::InvalidLength(32));
+        }
+        Ok(CBOR::to_tagged_value(
+            bc_tags::TAG_NAN_BSTR,
+            CBOR::to_byte_string(self.0),
+        ))
+    }
+
+    /// Decodes a tag-102 item holding 32 bytes, provided `profile` permits
+    /// binary256.
+    pub fn from_cbor(cbor: &CBOR, profile: NanTagProfile) -> Result<Self> {
+        let content =
+            cbor.clone()
+                .try_into_expected_tagged_value(Tag::with_value(
+                    bc_tags::TAG_NAN_BSTR,
+                ))?;
+        let bytes = content.try_into_byte_string()?;
+        if profile != NanTagProfile::AllowBinary256 || bytes.len() != 32 {
+            return Err(Error::InvalidLength(bytes.len()));
+        }
+        Self::from_be_bytes(bytes.try_into().expect("length checked"))
+    }
+
+    fn masked(&self, first: u8) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[2] = self.0[2] & first;
+        bytes[3..].copy_from_slice(&self.0[3..]);
+        bytes
+    }
+}
+
+impl fmt::Display for Binary256Nan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let payload = hex::encode(self.payload_bytes());
+        write!(
+            f,
+            "NaN[256]: {} {} payload=0x{}",
+            if self.sign() { "-" } else { "+" },
+            if self.is_quiet() {
+                "quiet"
+            } else {
+                "signaling"
+            },
+            match payload.trim_start_matches('0') {
+                "" => "0",
+                digits => digits,
+            }
+        )
+    }
+}
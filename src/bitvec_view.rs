@@ -0,0 +1,41 @@
+use bitvec::prelude::*;
+
+use crate::{Error, NanBstr, Result};
+
+impl NanBstr {
+    /// Returns the payload as a bit vector of exactly the width's payload
+    /// length: 9, 22, 51, or 111 bits.
+    ///
+    /// Bits are ordered most significant first: index 0 is the payload bit
+    /// immediately below the quiet bit, and the last index is the least
+    /// significant bit of the pattern. The quiet bit itself is not included.
+    pub fn payload_bits_view(&self) -> BitVec<u8, Msb0> {
+        let len = self.width().fraction_bits() as usize - 1;
+        let bytes = self.payload_bits().to_be_bytes();
+        bytes.view_bits::<Msb0>()[128 - len..].to_bitvec()
+    }
+
+    /// Returns a copy of this NaN with its payload replaced by `bits`, in
+    /// the order [`payload_bits_view`](Self::payload_bits_view) uses.
+    ///
+    /// Fails with [`Error::InvalidPayloadLength`] unless `bits` has exactly
+    /// the width's payload length, and with [`Error::NotANan`] if the result
+    /// would be a signaling NaN with a zero payload.
+    pub fn with_payload_from_bits(
+        &self,
+        bits: &BitSlice<u8, Msb0>,
+    ) -> Result<Self> {
+        let width = self.width();
+        let len = width.fraction_bits() as usize - 1;
+        if bits.len() != len {
+            return Err(Error::InvalidPayloadLength {
+                expected: len,
+                actual: bits.len(),
+            });
+        }
+        let payload = bits
+            .iter()
+            .fold(0u128, |acc, bit| (acc << 1) | *bit as u128);
+        Self::from_parts(width, self.sign(), self.is_quiet(), payload)
+    }
+}
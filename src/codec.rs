@@ -0,0 +1,109 @@
+use bytes::{Buf, BufMut, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{
+    Error, NanBstr, Result,
+    frame::{TAG_HEAD, frame_len},
+};
+
+/// What [`NanBstrCodec`] does when a frame fails to decode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DecodeRecovery {
+    /// Return the error. The stream ends, as `FramedRead` does after any
+    /// decoder error.
+    #[default]
+    Fail,
+    /// Discard the bad frame and continue with the next one. If the frame's
+    /// heads are intact, exactly that frame is skipped; otherwise bytes are
+    /// discarded up to the next tag-102 head.
+    Skip,
+}
+
+/// A `tokio_util` codec for a stream of back-to-back tag-102 items with no
+/// other framing.
+///
+/// Each frame is a tag head `d8 66`, a byte string head `4n`, and `n` bytes
+/// of pattern, exactly as [`NanBstr`] encodes to deterministic CBOR. The
+/// decoder waits for more input whenever a frame is incomplete, so items
+/// may be split across reads at any byte.
+#[derive(Debug, Clone, Default)]
+pub struct NanBstrCodec {
+    recovery: DecodeRecovery,
+    skipped: usize,
+}
+
+impl NanBstrCodec {
+    /// Returns a codec that fails on the first bad frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a codec that handles bad frames as `recovery` directs.
+    pub fn with_recovery(recovery: DecodeRecovery) -> Self {
+        Self {
+            recovery,
+            skipped: 0,
+        }
+    }
+
+    /// Returns the number of bad frames skipped so far.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl Decoder for NanBstrCodec {
+    type Item = NanBstr;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<NanBstr>> {
+        loop {
+            let frame_len = match frame_len(src) {
+                Ok(Some(len)) => len,
+                Ok(None) => return Ok(None),
+                Err(err) => {
+                    if self.recovery == DecodeRecovery::Fail {
+                        return Err(err.into());
+                    }
+                    // Resynchronize on the next tag head after this byte.
+                    let next = src[1..]
+                        .iter()
+                        .position(|&b| b == TAG_HEAD[0])
+                        .map_or(src.len(), |i| i + 1);
+                    src.advance(next);
+                    self.skipped += 1;
+                    continue;
+                }
+            };
+            if src.len() < frame_len {
+                src.reserve(frame_len - src.len());
+                return Ok(None);
+            }
+            let frame = src.split_to(frame_len);
+            match NanBstr::from_be_bytes(&frame[3..]) {
+                Ok(nan) => return Ok(Some(nan)),
+                Err(err) if self.recovery == DecodeRecovery::Fail => {
+                    return Err(err);
+                }
+                Err(_) => self.skipped += 1,
+            }
+        }
+    }
+}
+
+impl Encoder<&NanBstr> for NanBstrCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: &NanBstr, dst: &mut BytesMut) -> Result<()> {
+        dst.put_slice(&item.to_cbor_data());
+        Ok(())
+    }
+}
+
+impl Encoder<NanBstr> for NanBstrCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: NanBstr, dst: &mut BytesMut) -> Result<()> {
+        self.encode(&item, dst)
+    }
+}
@@ -0,0 +1,241 @@
+//! Generates a table of named NaN patterns from a TOML file, for use in
+//! build scripts.
+//!
+//! The file holds an array of `[[nan]]` tables, each with a `name` (in
+//! upper snake case), a `width` in bits, a `pattern` in big-endian hex, and
+//! an optional `doc` comment:
+//!
+//! ```toml
+//! [[nan]]
+//! name = "SENSOR_FAULT"
+//! width = 32
+//! pattern = "7fc00001"
+//! doc = "The sensor reported a fault."
+//! ```
+//!
+//! The generated source holds a `static` [`NanBstr`] for each entry, a
+//! `NAMES` array in file order, and a `by_name(&str) -> Option<&NanBstr>`
+//! lookup. Each pattern is also checked by a `const` item, so a generated
+//! file edited by hand still cannot compile with an invalid entry.
+//!
+//! In `build.rs`, with this crate as a build dependency with the `codegen`
+//! feature:
+//!
+//! ```no_run
+//! if let Err(err) =
+//!     cbor_nan_bstr::codegen::generate_nan_consts("patterns.toml")
+//! {
+//!     panic!("{err}");
+//! }
+//! ```
+//!
+//! and in the crate, with [`nan_consts!`](crate::nan_consts):
+//!
+//! ```ignore
+//! pub mod patterns {
+//!     cbor_nan_bstr::nan_consts!(include = "patterns.toml");
+//! }
+//! ```
+
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use toml::Spanned;
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default)]
+    nan: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    name: Spanned<String>,
+    width: Spanned<u32>,
+    pattern: Spanned<String>,
+    doc: Option<String>,
+}
+
+/// Reads the TOML file at `path`, relative to the package being built, and
+/// writes the generated table to `$OUT_DIR/<path>.rs`, where
+/// [`nan_consts!`](crate::nan_consts) expects it. Returns the output path.
+///
+/// Call this from a build script; it asks Cargo to rerun the script when
+/// the file changes. Any invalid entry fails with
+/// [`Error::InvalidNanConst`], citing its line.
+pub fn generate_nan_consts(path: impl AsRef<Path>) -> Result<PathBuf> {
+    let path = path.as_ref();
+    let manifest_dir =
+        std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let input = Path::new(&manifest_dir).join(path);
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+        std::io::Error::other("OUT_DIR is not set; call from a build script")
+    })?;
+    println!("cargo:rerun-if-changed={}", input.display());
+
+    let text = fs::read_to_string(&input)?;
+    let source = nan_consts_source(&text, &path.display().to_string())?;
+    let mut output = Path::new(&out_dir).join(path).into_os_string();
+    output.push(".rs");
+    let output = PathBuf::from(output);
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output, source)?;
+    Ok(output)
+}
+
+/// Returns the generated table for the TOML `text`, naming `file` in the
+/// header comment and in errors.
+pub fn nan_consts_source(text: &str, file: &str) -> Result<String> {
+    let error = |span: Option<std::ops::Range<usize>>, reason: String| {
+        Error::InvalidNanConst {
+            file: file.to_string(),
+            line: span
+                .map_or(1, |span| text[..span.start].matches('\n').count() + 1),
+            reason,
+        }
+    };
+    let parsed: File = toml::from_str(text)
+        .map_err(|err| error(err.span(), err.message().to_string()))?;
+
+    let mut out = format!(
+        "// @generated by cbor_nan_bstr::codegen from {}. Do not edit.\n",
+        file
+    );
+    let mut names = HashSet::new();
+    for entry in &parsed.nan {
+        let name = entry.name.get_ref();
+        if !is_const_name(name) {
+            return Err(error(
+                Some(entry.name.span()),
+                format!("`{}` is not an upper snake case identifier", name),
+            ));
+        }
+        if name == "NAMES" || !names.insert(name.as_str()) {
+            return Err(error(
+                Some(entry.name.span()),
+                format!("`{}` is already defined", name),
+            ));
+        }
+        let width =
+            NanWidth::from_bits(*entry.width.get_ref()).map_err(|_| {
+                error(
+                    Some(entry.width.span()),
+                    format!(
+                        "width must be 16, 32, 64, or 128, got {}",
+                        entry.width.get_ref()
+                    ),
+                )
+            })?;
+        let pattern = entry.pattern.get_ref();
+        let bytes =
+            hex::decode(pattern.trim_start_matches("0x")).map_err(|err| {
+                error(
+                    Some(entry.pattern.span()),
+                    format!("`{}` is not hex ({})", pattern, err),
+                )
+            })?;
+        if bytes.len() != width.len() {
+            return Err(error(
+                Some(entry.pattern.span()),
+                format!(
+                    "`{}` is {} bytes, but width {} needs {}",
+                    pattern,
+                    bytes.len(),
+                    entry.width.get_ref(),
+                    width.len()
+                ),
+            ));
+        }
+        if let Err(err) = NanBstr::from_be_bytes(&bytes) {
+            return Err(error(
+                Some(entry.pattern.span()),
+                format!("`{}`: {}", pattern, err),
+            ));
+        }
+
+        out.push('\n');
+        match &entry.doc {
+            Some(doc) => doc.lines().for_each(|line| {
+                writeln!(out, "/// {}", line).unwrap();
+            }),
+            None => writeln!(
+                out,
+                "/// binary{} NaN `h'{}'`.",
+                entry.width.get_ref(),
+                hex::encode(&bytes)
+            )
+            .unwrap(),
+        }
+        let array = bytes
+            .iter()
+            .map(|b| format!("0x{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(
+            out,
+            "pub static {name}: ::std::sync::LazyLock<::cbor_nan_bstr::NanBstr> =\n    \
+             ::std::sync::LazyLock::new(|| {{\n        \
+             const PATTERN: [u8; {len}] = [{array}];\n        \
+             const _: [u8; {frame}] =\n            \
+             ::cbor_nan_bstr::tagged_cbor_bytes_const(&PATTERN);\n        \
+             ::cbor_nan_bstr::NanBstr::from_be_bytes(PATTERN).unwrap()\n    \
+             }});\n",
+            name = name,
+            len = bytes.len(),
+            frame = bytes.len() + 3,
+            array = array,
+        )
+        .unwrap();
+    }
+
+    let names = parsed
+        .nan
+        .iter()
+        .map(|entry| entry.name.get_ref().as_str())
+        .collect::<Vec<_>>();
+    write!(
+        out,
+        "\n/// The names of the patterns, in file order.\n\
+         pub const NAMES: [&str; {}] = [{}];\n\
+         \n\
+         /// Returns the pattern named `name`.\n\
+         pub fn by_name(\n    \
+         name: &str,\n\
+         ) -> ::core::option::Option<&'static ::cbor_nan_bstr::NanBstr> {{\n    \
+         match name {{\n",
+        names.len(),
+        names
+            .iter()
+            .map(|name| format!("\"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+    .unwrap();
+    for name in &names {
+        writeln!(out, "        \"{0}\" => Some(&*{0}),", name).unwrap();
+    }
+    out.push_str("        _ => None,\n    }\n}\n");
+    Ok(out)
+}
+
+/// Returns true if `name` is an upper snake case identifier.
+fn is_const_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_uppercase() || c == '_')
+        && chars
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && name != "_"
+}
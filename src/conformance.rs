@@ -0,0 +1,204 @@
+//! Checks an encoding against the normative requirements of
+//! draft-mcnally-cbor-nan-bstr, producing findings that cite the
+//! requirement each one tests.
+
+use alloc::{string::ToString, vec::Vec};
+use core::fmt;
+
+use crate::{
+    NanBstr,
+    head::{Head, read_byte_string},
+};
+
+/// A normative requirement that [`check`] can test from an encoding alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Requirement {
+    /// Short, stable identifier.
+    pub id: &'static str,
+    /// The section of the specification that states the requirement.
+    pub section: &'static str,
+    /// The requirement, as stated.
+    pub text: &'static str,
+}
+
+/// The encoding is a single well-formed CBOR data item.
+pub const WELL_FORMED: Requirement = Requirement {
+    id: "well-formed",
+    section: "RFC 8949 §3",
+    text: "the data MUST be exactly one well-formed CBOR data item",
+};
+
+/// The item carries tag 102.
+pub const TAG_102: Requirement = Requirement {
+    id: "tag-102",
+    section: "§2",
+    text: "a NaN bit pattern is carried under tag 102",
+};
+
+/// The tag content is a byte string.
+pub const BYTE_STRING: Requirement = Requirement {
+    id: "byte-string",
+    section: "§2",
+    text: "the tag content MUST be a byte string",
+};
+
+/// The byte string has a valid length.
+pub const LENGTH: Requirement = Requirement {
+    id: "length",
+    section: "§2",
+    text: "enclosed byte string MUST be 2, 4, 8, or 16 bytes",
+};
+
+/// The bytes encode a NaN.
+pub const NAN: Requirement = Requirement {
+    id: "nan",
+    section: "§2",
+    text: "the bytes, in network byte order, MUST encode a NaN of the \
+           corresponding width: exponent all ones, fraction non-zero",
+};
+
+/// Every requirement [`check`] tests, in the order findings are reported.
+pub const REQUIREMENTS: &[Requirement] =
+    &[TAG_102, BYTE_STRING, WELL_FORMED, LENGTH, NAN];
+
+/// The outcome of testing one requirement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Finding {
+    /// The requirement tested.
+    pub requirement: Requirement,
+    /// Whether the encoding satisfies it.
+    pub passed: bool,
+    /// The offset of the evidence within the data.
+    pub offset: usize,
+    /// The bytes the finding is based on.
+    pub evidence: Vec<u8>,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} [{}]: {} (offset {}: {})",
+            if self.passed { "PASS" } else { "FAIL" },
+            self.requirement.section,
+            self.requirement.id,
+            self.requirement.text,
+            self.offset,
+            if self.evidence.is_empty() {
+                "end of data".to_string()
+            } else {
+                hex::encode(&self.evidence)
+            }
+        )
+    }
+}
+
+/// The findings of [`check`], in [`REQUIREMENTS`] order.
+///
+/// Requirements that cannot be tested because an earlier one failed (for
+/// example, the length of content that is not a byte string) have no
+/// finding.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ConformanceReport {
+    findings: Vec<Finding>,
+}
+
+impl ConformanceReport {
+    /// Returns every finding.
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+
+    /// Returns the findings that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|f| !f.passed)
+    }
+
+    /// Returns true if every requirement was tested and passed.
+    pub fn conforms(&self) -> bool {
+        self.findings.len() == REQUIREMENTS.len()
+            && self.findings.iter().all(|f| f.passed)
+    }
+
+    fn record(
+        &mut self,
+        requirement: Requirement,
+        passed: bool,
+        data: &[u8],
+        range: core::ops::Range<usize>,
+    ) -> bool {
+        self.findings.push(Finding {
+            requirement,
+            passed,
+            offset: range.start,
+            evidence: data[range].to_vec(),
+        });
+        passed
+    }
+
+    /// Records that the data is not well-formed from `offset` on.
+    fn ill_formed(&mut self, data: &[u8], offset: usize) -> Option<()> {
+        self.record(WELL_FORMED, false, data, offset..data.len());
+        None
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in &self.findings {
+            writeln!(f, "{}", finding)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tests `data`, which should hold a single tag-102 item, against the
+/// [`REQUIREMENTS`].
+///
+/// Unlike decoding with [`NanBstr`], this accepts any well-formed CBOR
+/// serialization of the item, including non-preferred heads and
+/// indefinite-length byte strings, since the specification does not
+/// require a particular serialization.
+pub fn check(data: &[u8]) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+    check_item(data, &mut report);
+    report
+        .findings
+        .sort_by_key(|f| REQUIREMENTS.iter().position(|r| *r == f.requirement));
+    report
+}
+
+/// Records findings until one makes the rest untestable.
+fn check_item(data: &[u8], report: &mut ConformanceReport) -> Option<()> {
+    let Some(tag) = Head::read(data, 0).ok() else {
+        return report.ill_formed(data, 0);
+    };
+    let is_tag_102 = tag.major == 6 && tag.argument == Some(102);
+    if !report.record(TAG_102, is_tag_102, data, 0..tag.end) {
+        return None;
+    }
+
+    let Some(content) = Head::read(data, tag.end).ok() else {
+        return report.ill_formed(data, tag.end);
+    };
+    let is_bstr = content.major == 2;
+    if !report.record(BYTE_STRING, is_bstr, data, tag.end..content.end) {
+        return None;
+    }
+
+    let Some((bytes, end)) = read_byte_string(data, &content) else {
+        return report.ill_formed(data, content.end);
+    };
+    if end == data.len() {
+        report.record(WELL_FORMED, true, data, 0..end);
+    } else {
+        report.ill_formed(data, end);
+    }
+
+    let valid_length = matches!(bytes.len(), 2 | 4 | 8 | 16);
+    if report.record(LENGTH, valid_length, data, tag.end..end) {
+        let is_nan = NanBstr::from_be_bytes(&bytes).is_ok();
+        report.record(NAN, is_nan, data, content.end..end);
+    }
+    Some(())
+}
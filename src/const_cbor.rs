@@ -0,0 +1,93 @@
+use crate::{ConstNan, frame::TAG_HEAD};
+
+/// Encodes the big-endian NaN `pattern` as a deterministic tag-102 item, at
+/// compile time when called in a const context.
+///
+/// `N` must be the pattern length plus 3, for the tag and byte string
+/// heads. Panics if the pattern is not 2, 4, 8, or 16 bytes of NaN, which in
+/// a const context is a compile error. The [`nan_bstr_cbor!`] macro picks
+/// `N` for you.
+///
+/// [`nan_bstr_cbor!`]: crate::nan_bstr_cbor
+pub const fn tagged_cbor_bytes_const<const N: usize>(
+    pattern: &[u8],
+) -> [u8; N] {
+    ConstNan::from_be_bytes(pattern);
+    assert!(
+        N == pattern.len() + 3,
+        "N must be the pattern length plus 3"
+    );
+
+    let mut out = [0u8; N];
+    out[0] = TAG_HEAD[0];
+    out[1] = TAG_HEAD[1];
+    out[2] = 0x40 | pattern.len() as u8;
+    let mut i = 0;
+    while i < pattern.len() {
+        out[3 + i] = pattern[i];
+        i += 1;
+    }
+    out
+}
+
+/// Expands to the deterministic tag-102 encoding of a NaN bit pattern, as a
+/// `[u8; N]` computed at compile time.
+///
+/// The width is named first, then the bits as an integer of that width:
+///
+/// ```
+/// use cbor_nan_bstr::nan_bstr_cbor;
+///
+/// static QNAN: [u8; 5] = nan_bstr_cbor!(binary16, 0x7E00);
+/// assert_eq!(QNAN, [0xD8, 0x66, 0x42, 0x7E, 0x00]);
+/// ```
+///
+/// A pattern that is not a NaN does not compile:
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::nan_bstr_cbor;
+///
+/// static INFINITY: [u8; 7] = nan_bstr_cbor!(binary32, 0x7F80_0000);
+/// ```
+#[macro_export]
+macro_rules! nan_bstr_cbor {
+    (binary16, $bits:expr) => {
+        $crate::nan_bstr_cbor!(@bits u16, $bits)
+    };
+    (binary32, $bits:expr) => {
+        $crate::nan_bstr_cbor!(@bits u32, $bits)
+    };
+    (binary64, $bits:expr) => {
+        $crate::nan_bstr_cbor!(@bits u64, $bits)
+    };
+    (binary128, $bits:expr) => {
+        $crate::nan_bstr_cbor!(@bits u128, $bits)
+    };
+    (@bits $ty:ty, $bits:expr) => {{
+        const PATTERN: &[u8] = &<$ty>::to_be_bytes($bits);
+        const ENCODED: [u8; PATTERN.len() + 3] =
+            $crate::tagged_cbor_bytes_const(PATTERN);
+        ENCODED
+    }};
+}
+
+/// Includes the table of named NaN patterns that the build script generated
+/// from `file` with `cbor_nan_bstr::codegen::generate_nan_consts`, which
+/// needs the `codegen` feature.
+///
+/// The table is a `static` `NanBstr` per entry, a `NAMES` array, and a
+/// `by_name` lookup:
+///
+/// ```ignore
+/// pub mod patterns {
+///     cbor_nan_bstr::nan_consts!(include = "patterns.toml");
+/// }
+///
+/// assert_eq!(patterns::by_name("SENSOR_FAULT"), Some(&*patterns::SENSOR_FAULT));
+/// ```
+#[macro_export]
+macro_rules! nan_consts {
+    (include = $file:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $file, ".rs"));
+    };
+}
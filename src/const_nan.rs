@@ -0,0 +1,213 @@
+use crate::{NanBstr, NanWidth};
+
+/// A NaN bit pattern that is built, and checked, at compile time, for
+/// protocol constants that [`NanBstr`] cannot hold because it owns its
+/// bytes.
+///
+/// The constructors are `const fn`s that panic if the bits are not a NaN,
+/// so a constant with a bad pattern does not compile. Convert to a
+/// [`NanBstr`] where one is needed:
+///
+/// ```
+/// use cbor_nan_bstr::{ConstNan, NanBstr};
+///
+/// const SENTINEL: ConstNan = ConstNan::from_binary64_bits(0x7FF8_0000_0000_0042);
+///
+/// let nan = NanBstr::from(SENTINEL);
+/// assert_eq!(nan, NanBstr::from_binary64_bits(0x7FF8_0000_0000_0042).unwrap());
+/// assert_eq!(nan, SENTINEL);
+/// ```
+///
+/// A pattern that is not a NaN does not compile:
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::ConstNan;
+///
+/// const INFINITY: ConstNan = ConstNan::from_binary32_bits(0x7F80_0000);
+/// ```
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::ConstNan;
+///
+/// static ONE: ConstNan = ConstNan::from_binary16_bits(0x3C00);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstNan {
+    width: NanWidth,
+    bits: u128,
+}
+
+impl ConstNan {
+    /// Construct from a 16-bit bit pattern. Panics if it is not a NaN.
+    pub const fn from_binary16_bits(bits: u16) -> Self {
+        Self::new(NanWidth::Binary16, bits as u128)
+    }
+
+    /// Construct from a 32-bit bit pattern. Panics if it is not a NaN.
+    pub const fn from_binary32_bits(bits: u32) -> Self {
+        Self::new(NanWidth::Binary32, bits as u128)
+    }
+
+    /// Construct from a 64-bit bit pattern. Panics if it is not a NaN.
+    pub const fn from_binary64_bits(bits: u64) -> Self {
+        Self::new(NanWidth::Binary64, bits as u128)
+    }
+
+    /// Construct from a 128-bit bit pattern. Panics if it is not a NaN.
+    pub const fn from_binary128_bits(bits: u128) -> Self {
+        Self::new(NanWidth::Binary128, bits)
+    }
+
+    /// Construct from a big-endian pattern of 2, 4, 8, or 16 bytes, such as
+    /// the result of `to_be_bytes` on an integer of the width. Panics if the
+    /// length is not one of these or the pattern is not a NaN.
+    pub const fn from_be_bytes(bytes: &[u8]) -> Self {
+        let width = match bytes.len() {
+            2 => NanWidth::Binary16,
+            4 => NanWidth::Binary32,
+            8 => NanWidth::Binary64,
+            16 => NanWidth::Binary128,
+            _ => panic!("invalid NaN length: expected 2, 4, 8, or 16 bytes"),
+        };
+        let mut bits = 0u128;
+        let mut i = 0;
+        while i < bytes.len() {
+            bits = (bits << 8) | bytes[i] as u128;
+            i += 1;
+        }
+        Self::new(width, bits)
+    }
+
+    /// Returns the positive quiet NaN of `width` with a zero payload.
+    pub const fn canonical_quiet(width: NanWidth) -> Self {
+        Self::new(width, width.canonical_quiet_bits())
+    }
+
+    /// Returns the positive signaling NaN of `width` with a payload of 1.
+    pub const fn minimal_signaling(width: NanWidth) -> Self {
+        let quiet = width.quiet_mask_u128();
+        Self::new(width, width.canonical_quiet_bits() & !quiet | 1)
+    }
+
+    const fn new(width: NanWidth, bits: u128) -> Self {
+        assert!(width.is_nan_bits(bits), "not a NaN bit pattern");
+        Self { width, bits }
+    }
+
+    /// Returns the width.
+    pub const fn width(self) -> NanWidth {
+        self.width
+    }
+
+    /// Returns the bit pattern, right-aligned in a `u128`.
+    pub const fn to_bits(self) -> u128 {
+        self.bits
+    }
+
+    /// Returns the pattern as a [`NanBstr`].
+    pub fn to_nan_bstr(self) -> NanBstr {
+        NanBstr::from_be_bytes(
+            &self.bits.to_be_bytes()[16 - self.width.len()..],
+        )
+        .expect("checked at construction")
+    }
+}
+
+impl From<ConstNan> for NanBstr {
+    fn from(nan: ConstNan) -> Self {
+        nan.to_nan_bstr()
+    }
+}
+
+impl PartialEq<ConstNan> for NanBstr {
+    fn eq(&self, other: &ConstNan) -> bool {
+        self.as_bytes() == &other.bits.to_be_bytes()[16 - other.width.len()..]
+    }
+}
+
+impl PartialEq<NanBstr> for ConstNan {
+    fn eq(&self, other: &NanBstr) -> bool {
+        other == self
+    }
+}
+
+/// Expands to a [`NanBstr`] for a NaN bit pattern that is checked at
+/// compile time.
+///
+/// The width is given by name, as in [`nan_bstr_cbor!`](crate::nan_bstr_cbor)
+/// or as the Rust float type of that width, or by the literal's integer
+/// suffix:
+///
+/// ```
+/// use cbor_nan_bstr::{NanBstr, nan_bstr};
+///
+/// let quiet = nan_bstr!(f32: 0x7FC0_0001);
+/// assert_eq!(quiet, NanBstr::from_binary32_bits(0x7FC0_0001).unwrap());
+/// assert_eq!(nan_bstr!(binary32, 0x7FC0_0001), quiet);
+///
+/// let payload = nan_bstr!(0x7FF8_0000_0000_0123u64);
+/// assert_eq!(payload.payload_bits(), 0x123);
+/// assert_eq!(nan_bstr!(binary16, 0x7E00), nan_bstr!(f16: 0x7E00));
+/// assert_eq!(nan_bstr!(u128::MAX).to_hex(), "f".repeat(32));
+/// ```
+///
+/// A pattern that is not a NaN does not compile:
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::nan_bstr;
+///
+/// let infinity = nan_bstr!(0x7FF0_0000_0000_0000u64);
+/// ```
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::nan_bstr;
+///
+/// let one = nan_bstr!(f16: 0x3C00);
+/// ```
+///
+/// Nor does a literal wider than the named width:
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::nan_bstr;
+///
+/// let nan = nan_bstr!(f32: 0x7FF8_0000_0000_0000);
+/// ```
+///
+/// An unsuffixed literal without a width is an `i32`, so give it a suffix.
+#[macro_export]
+macro_rules! nan_bstr {
+    (binary16, $bits:expr) => {
+        $crate::nan_bstr!(@bits u16, $bits)
+    };
+    (binary32, $bits:expr) => {
+        $crate::nan_bstr!(@bits u32, $bits)
+    };
+    (binary64, $bits:expr) => {
+        $crate::nan_bstr!(@bits u64, $bits)
+    };
+    (binary128, $bits:expr) => {
+        $crate::nan_bstr!(@bits u128, $bits)
+    };
+    (f16: $bits:expr) => {
+        $crate::nan_bstr!(@bits u16, $bits)
+    };
+    (f32: $bits:expr) => {
+        $crate::nan_bstr!(@bits u32, $bits)
+    };
+    (f64: $bits:expr) => {
+        $crate::nan_bstr!(@bits u64, $bits)
+    };
+    (f128: $bits:expr) => {
+        $crate::nan_bstr!(@bits u128, $bits)
+    };
+    (@bits $ty:ty, $bits:expr) => {{
+        const NAN: $crate::ConstNan =
+            $crate::ConstNan::from_be_bytes(&<$ty>::to_be_bytes($bits));
+        NAN.to_nan_bstr()
+    }};
+    ($bits:expr) => {{
+        const NAN: $crate::ConstNan =
+            $crate::ConstNan::from_be_bytes(&($bits).to_be_bytes());
+        NAN.to_nan_bstr()
+    }};
+}
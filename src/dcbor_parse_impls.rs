@@ -0,0 +1,33 @@
+use dcbor::prelude::*;
+
+use crate::{MaybeNan, NanBstr, Result};
+
+impl NanBstr {
+    /// Parses CBOR diagnostic notation, such as `102(h'7fc00000')` from the
+    /// draft or from `CBOR::diagnostic`. A bare byte string, `h'7fc00000'`,
+    /// is taken as the content of the tag.
+    ///
+    /// ```
+    /// use cbor_nan_bstr::NanBstr;
+    ///
+    /// let nan = NanBstr::from_diagnostic("102(h'7fc00000')").unwrap();
+    /// assert_eq!(nan, NanBstr::from_binary32_bits(0x7FC0_0000).unwrap());
+    /// assert_eq!(NanBstr::from_diagnostic("h'7fc00000'").unwrap(), nan);
+    /// ```
+    ///
+    /// Fails with [`Error::DiagnosticNotation`](crate::Error::DiagnosticNotation) if the text
+    /// does not parse, `dcbor::Error::WrongTag` for another tag,
+    /// `dcbor::Error::WrongType` for content that is not a byte string, and
+    /// as [`from_be_bytes`](Self::from_be_bytes) for bytes that are not a
+    /// NaN.
+    pub fn from_diagnostic(text: &str) -> Result<Self> {
+        let cbor = dcbor_parse::parse_dcbor_item(text)?;
+        if let CBORCase::ByteString(bytes) = cbor.as_case() {
+            return Self::from_be_bytes(bytes.data());
+        }
+        match MaybeNan::from_cbor(&cbor)? {
+            MaybeNan::Valid(nan) => Ok(nan),
+            MaybeNan::Invalid { reason, .. } => Err(reason),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Write;
+
+use dcbor_pattern::Pattern;
+use regex::bytes::Regex;
+
+use crate::{NanPattern, NanWidth};
+
+impl NanPattern {
+    /// Compiles this pattern to a `dcbor-pattern` [`Pattern`] that matches
+    /// tag-102 items holding a NaN this pattern matches.
+    ///
+    /// Every constraint a `NanPattern` can express is a set of bit masks on
+    /// the pattern bytes, so the result is built from byte string regexes
+    /// combined with `dcbor-pattern`'s own `and`, `or`, and `!`. It composes
+    /// with any other pattern; for example, to find every map entry whose
+    /// value is a signaling NaN:
+    ///
+    /// ```
+    /// use cbor_nan_bstr::{NanBstr, NanPattern};
+    /// use dcbor::prelude::*;
+    /// use dcbor_pattern::{Matcher, MapPattern, Pattern, StructurePattern};
+    ///
+    /// let entry = Pattern::Structure(StructurePattern::Map(
+    ///     MapPattern::with_key_value_constraints(vec![(
+    ///         Pattern::any(),
+    ///         NanPattern::any().signaling().to_dcbor_pattern(),
+    ///     )]),
+    /// ));
+    /// let query = Pattern::search(entry);
+    ///
+    /// let snan = NanBstr::from_binary32_bits(0x7F80_0001).unwrap();
+    /// let mut inner = Map::new();
+    /// inner.insert("bad", snan);
+    /// let mut doc = Map::new();
+    /// doc.insert("sensor", inner);
+    /// doc.insert("ok", 1.5);
+    ///
+    /// let paths = query.paths(&doc.into());
+    /// assert_eq!(paths.len(), 1);
+    /// ```
+    pub fn to_dcbor_pattern(&self) -> Pattern {
+        Pattern::tagged(
+            bc_tags::TAG_NAN_BSTR,
+            Pattern::and(vec![any_nan(), compile(self)]),
+        )
+    }
+}
+
+impl From<&NanPattern> for Pattern {
+    fn from(pattern: &NanPattern) -> Self {
+        pattern.to_dcbor_pattern()
+    }
+}
+
+impl From<NanPattern> for Pattern {
+    fn from(pattern: NanPattern) -> Self {
+        pattern.to_dcbor_pattern()
+    }
+}
+
+/// Compiles the constraints of `pattern` to a pattern over the content byte
+/// string, assuming it already holds a valid NaN.
+fn compile(pattern: &NanPattern) -> Pattern {
+    match pattern {
+        NanPattern::Any => Pattern::any(),
+        NanPattern::Width(widths) => {
+            any_of(widths.iter().map(|width| masked(width, 0, 0)).collect())
+        }
+        NanPattern::Sign(negative) => per_width(|width| {
+            let bit = 1u128 << (width.bits() - 1);
+            Some(masked(width, bit, if *negative { bit } else { 0 }))
+        }),
+        NanPattern::Quiet(quiet) => per_width(|width| {
+            let bit = 1u128 << (width.fraction_bits() - 1);
+            Some(masked(width, bit, if *quiet { bit } else { 0 }))
+        }),
+        NanPattern::Payload(payload) => per_width(|width| {
+            let max = width.max_payload();
+            (*payload <= max).then(|| masked(width, max, *payload))
+        }),
+        NanPattern::PayloadMasked { mask, value } => per_width(|width| {
+            let mask = mask & width.max_payload();
+            (value & !mask == 0).then(|| masked(width, mask, *value))
+        }),
+        NanPattern::PayloadRange(range) => per_width(|width| {
+            let max = width.max_payload();
+            let (mut low, high) = (*range.start(), (*range.end()).min(max));
+            let mut blocks = Vec::new();
+            while low <= high {
+                let mut size = if low == 0 {
+                    max + 1
+                } else {
+                    low & low.wrapping_neg()
+                };
+                while low + (size - 1) > high {
+                    size >>= 1;
+                }
+                blocks.push(masked(width, max & !(size - 1), low));
+                low += size;
+            }
+            (!blocks.is_empty()).then(|| any_of(blocks))
+        }),
+        NanPattern::And(patterns) => {
+            Pattern::and(patterns.iter().map(compile).collect())
+        }
+        NanPattern::Or(patterns) => {
+            any_of(patterns.iter().map(compile).collect())
+        }
+        NanPattern::Not(pattern) => Pattern::not_matching(compile(pattern)),
+    }
+}
+
+/// Matches a byte string holding a NaN of any width: the exponent is all
+/// ones and the pattern is not an infinity.
+fn any_nan() -> Pattern {
+    per_width(|width| {
+        let fraction = (1u128 << width.fraction_bits()) - 1;
+        let exponent =
+            ((1u128 << width.exponent_bits()) - 1) << width.fraction_bits();
+        Some(Pattern::and(vec![
+            masked(width, exponent, exponent),
+            Pattern::not_matching(masked(width, exponent | fraction, exponent)),
+        ]))
+    })
+}
+
+/// Combines the per-width patterns `f` returns; `None` means no pattern of
+/// that width matches.
+fn per_width(f: impl Fn(NanWidth) -> Option<Pattern>) -> Pattern {
+    any_of(NanWidth::ALL.into_iter().filter_map(f).collect())
+}
+
+fn any_of(mut patterns: Vec<Pattern>) -> Pattern {
+    match patterns.len() {
+        0 => Pattern::not_matching(Pattern::any()),
+        1 => patterns.pop().unwrap(),
+        _ => Pattern::or(patterns),
+    }
+}
+
+/// Matches byte strings of `width` whose bits, read as a right-aligned
+/// integer, satisfy `bits & mask == value`.
+fn masked(width: NanWidth, mask: u128, value: u128) -> Pattern {
+    let mut regex = String::from("(?s-u)^");
+    for i in (0..width.len()).rev() {
+        let (m, v) = ((mask >> (i * 8)) as u8, (value >> (i * 8)) as u8);
+        if m == 0 {
+            regex.push('.');
+            continue;
+        }
+        regex.push('[');
+        let mut bytes = (0..=255u8).filter(|b| b & m == v).peekable();
+        while let Some(start) = bytes.next() {
+            let mut end = start;
+            while bytes.next_if_eq(&end.wrapping_add(1)).is_some() {
+                end += 1;
+            }
+            write!(regex, "\\x{:02x}-\\x{:02x}", start, end).unwrap();
+        }
+        regex.push(']');
+    }
+    regex.push('$');
+    Pattern::byte_string_regex(Regex::new(&regex).expect("valid regex"))
+}
@@ -0,0 +1,218 @@
+use core::fmt;
+
+use crate::{Error, Result, nan_bstr::fold_bits};
+
+/// An IEEE 754 decimal interchange format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalFormat {
+    /// 64 bits, 16 digits.
+    Decimal64,
+    /// 128 bits, 34 digits.
+    Decimal128,
+}
+
+impl DecimalFormat {
+    /// Returns the width of the format in bits.
+    fn bits(&self) -> u32 {
+        match self {
+            DecimalFormat::Decimal64 => 64,
+            DecimalFormat::Decimal128 => 128,
+        }
+    }
+
+    /// Returns the number of 10-bit declets in the trailing significand.
+    fn declets(&self) -> u32 {
+        match self {
+            DecimalFormat::Decimal64 => 5,
+            DecimalFormat::Decimal128 => 11,
+        }
+    }
+
+    /// Returns the precision in decimal digits.
+    fn digits(&self) -> u32 {
+        match self {
+            DecimalFormat::Decimal64 => 16,
+            DecimalFormat::Decimal128 => 34,
+        }
+    }
+}
+
+/// How the trailing significand of a decimal format encodes its digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalEncoding {
+    /// Densely packed decimal: each 10-bit declet holds three digits.
+    Dpd,
+    /// Binary integer decimal: the field is a binary integer.
+    Bid,
+}
+
+/// An IEEE 754 decimal64 or decimal128 NaN.
+///
+/// A decimal NaN has the five combination-field bits after the sign all
+/// ones; the next bit is the signaling indicator (set for signaling, unlike
+/// the binary formats). The payload is the integer held in the trailing
+/// significand, read as declets or as a binary integer depending on the
+/// [`DecimalEncoding`]. The remaining combination-field bits are ignored.
+///
+/// Tag 102 transports binary formats only, so there is no conversion to
+/// [`NanBstr`](crate::NanBstr) and this type is not CBOR-encodable. It
+/// shares the accessor names and `Display` format of the binary types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalNan {
+    format: DecimalFormat,
+    encoding: DecimalEncoding,
+    bits: u128,
+}
+
+impl DecimalNan {
+    /// Construct from a decimal64 bit pattern.
+    pub fn from_decimal64_bits(
+        bits: u64,
+        encoding: DecimalEncoding,
+    ) -> Result<Self> {
+        Self::new(DecimalFormat::Decimal64, encoding, bits as u128)
+    }
+
+    /// Construct from a decimal128 bit pattern.
+    pub fn from_decimal128_bits(
+        bits: u128,
+        encoding: DecimalEncoding,
+    ) -> Result<Self> {
+        Self::new(DecimalFormat::Decimal128, encoding, bits)
+    }
+
+    /// Construct from a big-endian byte slice of length 8 (decimal64) or 16
+    /// (decimal128).
+    pub fn from_be_bytes(
+        bytes: impl AsRef<[u8]>,
+        encoding: DecimalEncoding,
+    ) -> Result<Self> {
+        let bytes = bytes.as_ref();
+        let format = match bytes.len() {
+            8 => DecimalFormat::Decimal64,
+            16 => DecimalFormat::Decimal128,
+            len => return Err(Error::InvalidLength(len)),
+        };
+        Self::new(format, encoding, fold_bits(bytes))
+    }
+
+    fn new(
+        format: DecimalFormat,
+        encoding: DecimalEncoding,
+        bits: u128,
+    ) -> Result<Self> {
+        let combination = (bits >> (format.bits() - 6)) & 0x1F;
+        if combination != 0x1F {
+            return Err(Error::NotANan);
+        }
+        Ok(Self {
+            format,
+            encoding,
+            bits,
+        })
+    }
+
+    /// Returns the decimal format.
+    pub fn format(&self) -> DecimalFormat {
+        self.format
+    }
+
+    /// Returns the significand encoding the payload is read with.
+    pub fn encoding(&self) -> DecimalEncoding {
+        self.encoding
+    }
+
+    /// Returns the bit pattern, right-aligned in a `u128`.
+    pub fn to_bits(&self) -> u128 {
+        self.bits
+    }
+
+    /// Returns the sign bit (true if set).
+    pub fn sign(&self) -> bool {
+        self.bits >> (self.format.bits() - 1) & 1 != 0
+    }
+
+    /// Returns true if the NaN is signaling (signaling bit == 1).
+    pub fn is_signaling(&self) -> bool {
+        self.bits >> (self.format.bits() - 7) & 1 != 0
+    }
+
+    /// Returns true if the NaN is quiet (signaling bit == 0).
+    pub fn is_quiet(&self) -> bool {
+        !self.is_signaling()
+    }
+
+    /// Returns the raw trailing significand field.
+    pub fn trailing_significand_bits(&self) -> u128 {
+        self.bits & ((1u128 << (self.format.declets() * 10)) - 1)
+    }
+
+    /// Returns the payload as an integer.
+    ///
+    /// Under BID, a trailing significand of 10^(p-1) or more is
+    /// non-canonical and reads as 0, per IEEE 754-2008 §3.5.2. Under DPD,
+    /// non-canonical declets decode to the digits they represent.
+    pub fn payload(&self) -> u128 {
+        let field = self.trailing_significand_bits();
+        match self.encoding {
+            DecimalEncoding::Bid => {
+                if field < 10u128.pow(self.format.digits() - 1) {
+                    field
+                } else {
+                    0
+                }
+            }
+            DecimalEncoding::Dpd => (0..self.format.declets())
+                .rev()
+                .map(|i| decode_declet((field >> (i * 10)) as u16 & 0x3FF))
+                .fold(0, |acc, d| acc * 1000 + d as u128),
+        }
+    }
+}
+
+/// Decodes a densely packed decimal declet to a value in 0..=999.
+fn decode_declet(declet: u16) -> u16 {
+    let bit = |i: u16| (declet >> i) & 1;
+    let three = |hi: u16, mid: u16, lo: u16| hi << 2 | mid << 1 | lo;
+    let (pqr, stu) = (declet >> 7, (declet >> 4) & 7);
+    let (p, q, r, s, t, u) = (bit(9), bit(8), bit(7), bit(6), bit(5), bit(4));
+    let (y, big_r, big_u, big_y) = (bit(0), 8 + r, 8 + u, 8 + bit(0));
+    let (d2, d1, d0) = if bit(3) == 0 {
+        (pqr, stu, declet & 7)
+    } else {
+        match (bit(2), bit(1), s, t) {
+            (0, 0, _, _) => (pqr, stu, big_y),
+            (0, 1, _, _) => (pqr, big_u, three(s, t, y)),
+            (1, 0, _, _) => (big_r, stu, three(p, q, y)),
+            (_, _, 0, 0) => (big_r, big_u, three(p, q, y)),
+            (_, _, 0, 1) => (big_r, three(p, q, u), big_y),
+            (_, _, 1, 0) => (pqr, big_u, big_y),
+            _ => (big_r, big_u, big_y),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+impl fmt::Display for DecimalNan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NaN[{}/{}]: {} {} payload={}",
+            match self.format {
+                DecimalFormat::Decimal64 => "decimal64",
+                DecimalFormat::Decimal128 => "decimal128",
+            },
+            match self.encoding {
+                DecimalEncoding::Dpd => "dpd",
+                DecimalEncoding::Bid => "bid",
+            },
+            if self.sign() { "-" } else { "+" },
+            if self.is_quiet() {
+                "quiet"
+            } else {
+                "signaling"
+            },
+            self.payload()
+        )
+    }
+}
@@ -0,0 +1,106 @@
+//! The structured JSON form of NaN diagnostics.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    KnownPattern, MalformedNan, NanAuditEntry, NanAuditReport, NanFields,
+};
+
+/// A [`NanAuditReport`], with the same content as its CBOR form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct AuditReportJson {
+    /// The counts, by name: `total`, each width, `quiet`, `signaling`,
+    /// `payload`, `negative`, `malformed`, and, if a policy was applied,
+    /// `violations`.
+    pub counts: BTreeMap<String, usize>,
+    pub nans: Vec<AuditEntryJson>,
+    pub malformed: Vec<MalformedNanJson>,
+    /// The policy verdict, or `null` if no policy was applied.
+    pub passes: Option<bool>,
+}
+
+/// A [`NanAuditEntry`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct AuditEntryJson {
+    pub path: String,
+    pub nan: NanFields,
+    /// `quiet`, `quiet with payload`, or `signaling`.
+    pub class: String,
+    /// The name of the well-known pattern the NaN matches.
+    pub provenance: Option<String>,
+    pub nearest: Option<NearestPatternJson>,
+    /// The policy rules the NaN breaks.
+    pub violations: Vec<String>,
+}
+
+/// A [`MalformedNan`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct MalformedNanJson {
+    pub path: String,
+    pub reason: String,
+    pub nearest: Option<NearestPatternJson>,
+}
+
+/// A near-miss well-known pattern and its distance in bits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NearestPatternJson {
+    pub name: String,
+    pub distance: u32,
+}
+
+impl From<&NanAuditReport> for AuditReportJson {
+    fn from(report: &NanAuditReport) -> Self {
+        Self {
+            counts: report
+                .counts()
+                .into_iter()
+                .map(|(name, count)| (name.to_string(), count))
+                .collect(),
+            nans: report.entries.iter().map(Into::into).collect(),
+            malformed: report.malformed.iter().map(Into::into).collect(),
+            passes: report.passes(),
+        }
+    }
+}
+
+impl From<&NanAuditEntry> for AuditEntryJson {
+    fn from(entry: &NanAuditEntry) -> Self {
+        Self {
+            path: entry.path.to_string(),
+            nan: (&entry.nan).into(),
+            class: entry.classification().to_string(),
+            provenance: entry.provenance.map(|p| p.name.to_string()),
+            nearest: entry.nearest.map(Into::into),
+            violations: entry
+                .violations
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl From<&MalformedNan> for MalformedNanJson {
+    fn from(item: &MalformedNan) -> Self {
+        Self {
+            path: item.path.to_string(),
+            reason: item.reason.clone(),
+            nearest: item.nearest.map(Into::into),
+        }
+    }
+}
+
+impl From<(KnownPattern, u32)> for NearestPatternJson {
+    fn from((pattern, distance): (KnownPattern, u32)) -> Self {
+        Self {
+            name: pattern.name.to_string(),
+            distance,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use core::fmt;
+
+use embedded_io::{Read, ReadExactError, Write};
+
+use crate::{
+    Error, NanBstr,
+    frame::{MAX_FRAME_LEN, checked_frame_len, encode_frame},
+};
+
+/// An error from decoding a [`NanBstr`] from an `embedded-io` reader.
+#[derive(Debug, thiserror::Error)]
+pub enum EmbeddedIoError<E: fmt::Debug> {
+    #[error("I/O error ({0:?})")]
+    Io(E),
+
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+
+    #[error(transparent)]
+    Decode(#[from] Error),
+}
+
+impl<E: fmt::Debug> From<ReadExactError<E>> for EmbeddedIoError<E> {
+    fn from(err: ReadExactError<E>) -> Self {
+        match err {
+            ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            ReadExactError::Other(err) => Self::Io(err),
+        }
+    }
+}
+
+impl NanBstr {
+    /// Writes this NaN to `w` as a deterministic tag-102 item.
+    pub fn encode_to<W: Write>(&self, w: &mut W) -> Result<(), W::Error> {
+        let (frame, len) = encode_frame(self);
+        w.write_all(&frame[..len])
+    }
+
+    /// Reads one deterministically encoded tag-102 item from `r`, reading
+    /// no further than its end.
+    ///
+    /// The item is read into a fixed buffer; only the returned [`NanBstr`]
+    /// allocates.
+    pub fn decode_from<R: Read>(
+        r: &mut R,
+    ) -> Result<Self, EmbeddedIoError<R::Error>> {
+        let mut frame = [0; MAX_FRAME_LEN];
+        r.read_exact(&mut frame[..3])?;
+        let len = checked_frame_len(&frame[..3])?;
+        r.read_exact(&mut frame[3..len])?;
+        Ok(Self::from_be_bytes(&frame[3..len])?)
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+impl NanBstr {
+    /// Writes this NaN to `w` as a deterministic tag-102 item; the async
+    /// counterpart of [`NanBstr::encode_to`].
+    pub async fn encode_to_async<W: embedded_io_async::Write>(
+        &self,
+        w: &mut W,
+    ) -> Result<(), W::Error> {
+        let (frame, len) = encode_frame(self);
+        w.write_all(&frame[..len]).await
+    }
+
+    /// Reads one deterministically encoded tag-102 item from `r`; the async
+    /// counterpart of [`NanBstr::decode_from`].
+    pub async fn decode_from_async<R: embedded_io_async::Read>(
+        r: &mut R,
+    ) -> Result<Self, EmbeddedIoError<R::Error>> {
+        let mut frame = [0; MAX_FRAME_LEN];
+        r.read_exact(&mut frame[..3]).await?;
+        let len = checked_frame_len(&frame[..3])?;
+        r.read_exact(&mut frame[3..len]).await?;
+        Ok(Self::from_be_bytes(&frame[3..len])?)
+    }
+}
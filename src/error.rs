@@ -1,5 +1,11 @@
+use alloc::{boxed::Box, string::{String, ToString}};
+
 /// Error types for cbor-nan-bstr
+///
+/// Some variants exist only with the feature that produces them, so the enum
+/// is non-exhaustive: enabling a feature must not break a `match` elsewhere.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     #[error("CBOR error ({0})")]
     Cbor(#[from] dcbor::Error),
@@ -9,13 +15,129 @@ pub enum Error {
 
     #[error("not a NaN bit pattern")]
     NotANan,
+
+    #[error("invalid hex digit `{digit}` at offset {offset}")]
+    InvalidHexDigit { offset: usize, digit: char },
+
+    #[error("width mismatch: expected {expected:?}, got {actual:?}")]
+    WidthMismatch {
+        expected: crate::NanWidth,
+        actual: crate::NanWidth,
+    },
+
+    #[error("invalid NaN pattern at offset {offset} (`{token}`): {reason}")]
+    InvalidPattern {
+        offset: usize,
+        token: String,
+        reason: String,
+    },
+
+    #[error("invalid NaN text at offset {offset}: {reason}")]
+    InvalidNanText { offset: usize, reason: String },
+
+    #[cfg(feature = "extended80")]
+    #[error("x87 pseudo-NaN: integer bit is clear")]
+    PseudoNan,
+
+    #[cfg(feature = "std")]
+    #[error("I/O error ({0})")]
+    Io(#[from] std::io::Error),
+
+    #[error("fixed record padding is not zero")]
+    NonZeroPadding,
+
+    #[cfg(feature = "bitvec")]
+    #[error("invalid payload length: expected {expected} bits, got {actual}")]
+    InvalidPayloadLength { expected: usize, actual: usize },
+
+    #[error("payload 0x{payload:x} does not fit in {width:?}")]
+    PayloadTooLarge {
+        width: crate::NanWidth,
+        payload: u128,
+    },
+
+    #[error(
+        "a signaling {0:?} NaN needs a non-zero payload; with a zero payload \
+         it would be an infinity"
+    )]
+    SignalingZeroPayload(crate::NanWidth),
+
+    #[error("invalid payload ({0})")]
+    InvalidPayload(String),
+
+    #[error(
+        "invalid width set `{0}`: expected widths 16, 32, 64, or 128 \
+         separated by `|`, or `none`"
+    )]
+    InvalidWidthSet(String),
+
+    #[error("invalid payload layout: field `{field}` {reason}")]
+    InvalidLayout { field: String, reason: String },
+
+    #[error("invalid payload field `{field}`: {reason}")]
+    InvalidField { field: String, reason: String },
+
+    #[error("payload bits 0x{0:x} are outside every field of the layout")]
+    UnassignedPayloadBits(u128),
+
+    #[error("expected {expected} Modbus registers for {width:?}, got {actual}")]
+    InvalidRegisterCount {
+        width: crate::NanWidth,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[cfg(feature = "parquet")]
+    #[error("sidecar has {sidecar} entries for {values} values")]
+    SidecarLength { values: usize, sidecar: usize },
+
+    #[cfg(feature = "parquet")]
+    #[error("sidecar entry for row {0} does not match a NaN")]
+    SidecarMismatch(usize),
+
+    #[cfg(feature = "codegen")]
+    #[error("invalid NaN constant at {file}:{line}: {reason}")]
+    InvalidNanConst {
+        file: String,
+        line: usize,
+        reason: String,
+    },
+
+    #[cfg(feature = "wasmtime")]
+    #[error("expected a Wasm {0} value")]
+    WasmValType(&'static str),
+
+    #[cfg(feature = "wasmtime")]
+    #[error("Wasm error ({0})")]
+    Wasm(String),
+
+    #[cfg(feature = "dcbor-parse")]
+    #[error("invalid diagnostic notation ({0})")]
+    DiagnosticNotation(#[from] dcbor_parse::ParseError),
+
+    #[error("CBOR sequence item at offset {offset}: {source}")]
+    SeqItem { offset: usize, source: Box<Error> },
+
+    #[error(
+        "invalid NaN width `{0}`: expected binary16, binary32, binary64, or \
+         binary128"
+    )]
+    InvalidWidth(String),
+
+    #[error("payload 0x{payload:x} does not fit in u{bits}")]
+    PayloadOverflow { payload: u128, bits: u32 },
+
+    #[error("bit pattern 0x{bits:x} has bits set above {width}")]
+    BitsExceedWidth { width: crate::NanWidth, bits: u128 },
 }
 
 /// A specialized `Result` type for cbor-nan-bstr operations.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl From<Error> for String {
-    fn from(err: Error) -> Self { err.to_string() }
+    fn from(err: Error) -> Self {
+        err.to_string()
+    }
 }
 
 impl From<Error> for dcbor::Error {
@@ -0,0 +1,95 @@
+use alloc::{format, string::String};
+use core::fmt;
+
+use crate::NanBstr;
+
+impl NanBstr {
+    /// Returns a multi-line breakdown of the bit fields, for documentation
+    /// and triage; see [`explain_to`](Self::explain_to) for the format.
+    pub fn explain(&self) -> String {
+        let mut out = String::new();
+        self.explain_to(&mut out).expect("writing to a String");
+        out
+    }
+
+    /// Writes a multi-line breakdown of the bit fields to `out`.
+    ///
+    /// The first line gives the width and the raw big-endian pattern in
+    /// hex. Then one line each for the sign, exponent, quiet bit, and
+    /// payload gives the field's bits, numbered from 0 at the least
+    /// significant, and its value in hex and binary:
+    ///
+    /// ```
+    /// use cbor_nan_bstr::NanBstr;
+    ///
+    /// let nan = NanBstr::from_binary16_bits(0xFE05).unwrap();
+    /// assert_eq!(
+    ///     nan.explain(),
+    ///     "binary16 fe05\n\
+    ///      \x20 sign      bit  15     1 (-)\n\
+    ///      \x20 exponent  bits 14-10  0x1f 11111 (all ones)\n\
+    ///      \x20 quiet     bit  9      1 (quiet)\n\
+    ///      \x20 payload   bits 8-0    0x5 000000101\n"
+    /// );
+    /// ```
+    pub fn explain_to(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        let width = self.width();
+        let exponent = self.exponent_bits();
+        let payload = self.payload_bits();
+        let sign_index = width.sign_bit_index();
+        let quiet_index = width.quiet_bit_index();
+        let ranges = [
+            format!("bit  {}", sign_index),
+            format!("bits {}-{}", sign_index - 1, width.fraction_bits()),
+            format!("bit  {}", quiet_index),
+            format!("bits {}-0", quiet_index - 1),
+        ];
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0);
+        let all_ones = exponent == (1 << width.exponent_bits()) - 1;
+        let values = [
+            format!(
+                "{} ({})",
+                self.sign() as u8,
+                if self.sign() { "-" } else { "+" }
+            ),
+            format!(
+                "0x{:x} {:0n$b} ({})",
+                exponent,
+                exponent,
+                if all_ones { "all ones" } else { "not all ones" },
+                n = width.exponent_bits() as usize
+            ),
+            format!(
+                "{} ({})",
+                self.is_quiet() as u8,
+                if self.is_quiet() {
+                    "quiet"
+                } else {
+                    "signaling"
+                }
+            ),
+            format!(
+                "0x{:x} {:0n$b}",
+                payload,
+                payload,
+                n = width.payload_bits() as usize
+            ),
+        ];
+        writeln!(out, "{} {}", width, self.to_hex())?;
+        for ((name, range), value) in ["sign", "exponent", "quiet", "payload"]
+            .into_iter()
+            .zip(ranges)
+            .zip(values)
+        {
+            writeln!(
+                out,
+                "  {:<8}  {:<range_width$}  {}",
+                name,
+                range,
+                value,
+                range_width = range_width
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use core::fmt;
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// An x87 80-bit extended-precision NaN.
+///
+/// The format has a sign bit, a 15-bit exponent, an explicit integer bit, and
+/// a 63-bit fraction whose most significant bit is the quiet/signaling
+/// indicator. A NaN has the exponent all ones, the integer bit set, and a
+/// non-zero fraction. With the integer bit clear the same encoding is a
+/// *pseudo-NaN*, which the 80387 and later reject as an invalid operand.
+///
+/// Ten-byte strings are not a permitted tag-102 width, so this type is not
+/// CBOR-encodable. Convert to a [`NanBstr`] to transport it:
+/// [`to_binary128`](Self::to_binary128) is lossless, and
+/// [`to_binary64`](Self::to_binary64) is lossless when the low 11 fraction
+/// bits are zero.
+///
+/// # Payload mapping
+///
+/// Fractions are **left-aligned**, as x87 hardware does when loading and
+/// storing narrower formats: the quiet bit maps to the quiet bit, and the
+/// high payload bits map to the high payload bits. Widening to binary128
+/// shifts the 63-bit fraction left by 49; narrowing to binary64 shifts it
+/// right by 11, discarding the low bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Extended80Nan {
+    sign_exponent: u16,
+    significand: u64,
+}
+
+const EXPONENT_MASK: u16 = 0x7FFF;
+const INTEGER_BIT: u64 = 1 << 63;
+const QUIET_BIT: u64 = 1 << 62;
+const FRACTION_MASK: u64 = INTEGER_BIT - 1;
+
+impl Extended80Nan {
+    // ───────────────────────────── Constructors ─────────────────────────────
+
+    /// Construct from the 16-bit sign/exponent word and the 64-bit
+    /// significand (including the explicit integer bit).
+    ///
+    /// Fails with [`Error::PseudoNan`] if the exponent is all ones, the
+    /// fraction is non-zero, and the integer bit is clear, and with
+    /// [`Error::NotANan`] for any other non-NaN encoding, including
+    /// pseudo-infinity.
+    pub fn from_parts(sign_exponent: u16, significand: u64) -> Result<Self> {
+        if sign_exponent & EXPONENT_MASK != EXPONENT_MASK
+            || significand & FRACTION_MASK == 0
+        {
+            return Err(Error::NotANan);
+        }
+        if significand & INTEGER_BIT == 0 {
+            return Err(Error::PseudoNan);
+        }
+        Ok(Self {
+            sign_exponent,
+            significand,
+        })
+    }
+
+    /// Construct from the 80-bit pattern, right-aligned in a `u128`. Bits
+    /// above the low 80 are ignored.
+    pub fn from_bits(bits: u128) -> Result<Self> {
+        Self::from_parts((bits >> 64) as u16, bits as u64)
+    }
+
+    /// Construct from ten big-endian bytes.
+    pub fn from_be_bytes(bytes: [u8; 10]) -> Result<Self> {
+        let mut buf = [0u8; 16];
+        buf[6..].copy_from_slice(&bytes);
+        Self::from_bits(u128::from_be_bytes(buf))
+    }
+
+    /// Construct from ten little-endian bytes, the layout `FSTP TBYTE`
+    /// writes to memory.
+    pub fn from_le_bytes(bytes: [u8; 10]) -> Result<Self> {
+        let mut buf = [0u8; 16];
+        buf[..10].copy_from_slice(&bytes);
+        Self::from_bits(u128::from_le_bytes(buf))
+    }
+
+    /// Narrows a binary128 [`NanBstr`] whose low 49 fraction bits are zero,
+    /// the inverse of [`to_binary128`](Self::to_binary128).
+    ///
+    /// Fails with [`Error::WidthMismatch`] for other widths and with
+    /// [`Error::PayloadTooLarge`] if any low bit is set.
+    pub fn from_nan_bstr(nan: &NanBstr) -> Result<Self> {
+        if nan.width() != NanWidth::Binary128 {
+            return Err(Error::WidthMismatch {
+                expected: NanWidth::Binary128,
+                actual: nan.width(),
+            });
+        }
+        let fraction = nan.fraction_bits();
+        if fraction & ((1 << 49) - 1) != 0 {
+            return Err(Error::PayloadTooLarge {
+                width: NanWidth::Binary128,
+                payload: nan.payload_bits(),
+            });
+        }
+        let sign_exponent = ((nan.sign() as u16) << 15) | EXPONENT_MASK;
+        Self::from_parts(sign_exponent, INTEGER_BIT | (fraction >> 49) as u64)
+    }
+
+    // ────────────────────────────── Accessors ───────────────────────────────
+
+    /// Returns the 16-bit sign/exponent word.
+    pub fn sign_exponent(&self) -> u16 {
+        self.sign_exponent
+    }
+
+    /// Returns the 64-bit significand, including the explicit integer bit.
+    pub fn significand(&self) -> u64 {
+        self.significand
+    }
+
+    /// Returns the 80-bit pattern, right-aligned in a `u128`.
+    pub fn to_bits(&self) -> u128 {
+        ((self.sign_exponent as u128) << 64) | self.significand as u128
+    }
+
+    /// Returns the pattern as ten big-endian bytes.
+    pub fn to_be_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        bytes.copy_from_slice(&self.to_bits().to_be_bytes()[6..]);
+        bytes
+    }
+
+    /// Returns the pattern as ten little-endian bytes.
+    pub fn to_le_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        bytes.copy_from_slice(&self.to_bits().to_le_bytes()[..10]);
+        bytes
+    }
+
+    /// Returns the sign bit (true if set).
+    pub fn sign(&self) -> bool {
+        self.sign_exponent & 0x8000 != 0
+    }
+
+    /// Returns true if the quiet/signaling indicator bit is 1 (quiet NaN).
+    pub fn is_quiet(&self) -> bool {
+        self.significand & QUIET_BIT != 0
+    }
+
+    /// Returns true if the NaN is signaling (quiet bit == 0).
+    pub fn is_signaling(&self) -> bool {
+        !self.is_quiet()
+    }
+
+    /// Returns the 63-bit fraction field, excluding the integer bit and
+    /// including the quiet/signaling indicator in its MSB.
+    pub fn fraction_bits(&self) -> u64 {
+        self.significand & FRACTION_MASK
+    }
+
+    /// Returns the 62 payload bits beneath the quiet/signaling indicator.
+    pub fn payload_bits(&self) -> u64 {
+        self.significand & (QUIET_BIT - 1)
+    }
+
+    // ───────────────────────────── Conversions ──────────────────────────────
+
+    /// Converts to a binary128 [`NanBstr`]. Always lossless.
+    pub fn to_binary128(&self) -> NanBstr {
+        let sign = (self.sign() as u128) << 127;
+        let exponent = 0x7FFFu128 << 112;
+        let fraction = (self.fraction_bits() as u128) << 49;
+        NanBstr::from_binary128_bits(sign | exponent | fraction)
+            .expect("non-zero fraction is a NaN")
+    }
+
+    /// Converts to a binary64 [`NanBstr`], failing with
+    /// [`Error::PayloadTooLarge`] if any of the low 11 fraction bits would be
+    /// lost. The quiet bit is kept as it is.
+    pub fn to_binary64(&self) -> Result<NanBstr> {
+        if self.fraction_bits() & 0x7FF != 0 {
+            return Err(Error::PayloadTooLarge {
+                width: NanWidth::Binary64,
+                payload: self.payload_bits() as u128,
+            });
+        }
+        Ok(NanBstr::from_binary64_bits(self.binary64_bits())
+            .expect("high fraction bits are non-zero"))
+    }
+
+    /// Converts to a binary64 [`NanBstr`] as `FST m64` does: the low 11
+    /// fraction bits are discarded and the quiet bit is set, since the
+    /// hardware quiets a signaling NaN before storing it. The result is
+    /// therefore always a quiet NaN, never an infinity.
+    pub fn to_binary64_lossy(&self) -> NanBstr {
+        NanBstr::from_binary64_bits(self.binary64_bits() | (1 << 51))
+            .expect("quiet bit is set")
+    }
+
+    /// Returns the binary64 bits with the sign and the high 52 fraction
+    /// bits of this NaN.
+    fn binary64_bits(&self) -> u64 {
+        let sign = (self.sign() as u64) << 63;
+        sign | (0x7FF << 52) | (self.fraction_bits() >> 11)
+    }
+}
+
+impl From<Extended80Nan> for NanBstr {
+    fn from(nan: Extended80Nan) -> Self {
+        nan.to_binary128()
+    }
+}
+
+impl TryFrom<&NanBstr> for Extended80Nan {
+    type Error = Error;
+
+    fn try_from(nan: &NanBstr) -> Result<Self> {
+        Self::from_nan_bstr(nan)
+    }
+}
+
+impl NanBstr {
+    /// Construct a binary128 NaN from an x87 `long double` as stored in
+    /// memory: ten little-endian bytes, as `FSTP TBYTE` writes them.
+    ///
+    /// The fraction is left-aligned, as described for [`Extended80Nan`].
+    /// Fails with [`Error::PseudoNan`] if the integer bit is clear and with
+    /// [`Error::NotANan`] for any other non-NaN, including pseudo-infinity.
+    ///
+    /// Requires the `extended80` feature.
+    pub fn from_x87_extended_bytes(bytes: [u8; 10]) -> Result<Self> {
+        Ok(Extended80Nan::from_le_bytes(bytes)?.to_binary128())
+    }
+
+    /// Construct a binary128 NaN from an x87 `long double` given as its
+    /// sign/exponent word and 64-bit significand, as by
+    /// [`from_x87_extended_bytes`](Self::from_x87_extended_bytes).
+    ///
+    /// Requires the `extended80` feature.
+    pub fn from_x87_extended_parts(
+        sign_exponent: u16,
+        significand: u64,
+    ) -> Result<Self> {
+        Ok(Extended80Nan::from_parts(sign_exponent, significand)?
+            .to_binary128())
+    }
+
+    /// Returns the x87 `long double` for a binary128 NaN, as ten
+    /// little-endian bytes, the inverse of
+    /// [`from_x87_extended_bytes`](Self::from_x87_extended_bytes).
+    ///
+    /// Fails as [`Extended80Nan::from_nan_bstr`] does unless the NaN is
+    /// binary128 with its low 49 fraction bits zero.
+    ///
+    /// Requires the `extended80` feature.
+    pub fn to_x87_extended_bytes(&self) -> Result<[u8; 10]> {
+        Ok(Extended80Nan::from_nan_bstr(self)?.to_le_bytes())
+    }
+}
+
+impl fmt::Display for Extended80Nan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NaN[80]: {} {} frac=0x{:x} payload=0x{:x}",
+            if self.sign() { "-" } else { "+" },
+            if self.is_quiet() {
+                "quiet"
+            } else {
+                "signaling"
+            },
+            self.fraction_bits(),
+            self.payload_bits()
+        )
+    }
+}
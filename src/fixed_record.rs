@@ -0,0 +1,37 @@
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// The length of a fixed record, in bytes.
+pub const FIXED_RECORD_LEN: usize = 17;
+
+impl NanBstr {
+    /// Returns this NaN as a fixed-size 17-byte record, for storage where
+    /// variable-length CBOR is unwelcome. This is not CBOR.
+    ///
+    /// Byte 0 is the pattern length in bytes (2, 4, 8, or 16). Bytes 1..17
+    /// hold the big-endian pattern right-aligned, with the leading bytes
+    /// zero: the same layout as [`PackedNanBstr`](crate::PackedNanBstr), as
+    /// bytes. A binary32 NaN `7fc00001` is thus `04` followed by twelve zero
+    /// bytes and `7f c0 00 01`.
+    pub fn to_fixed_record(&self) -> [u8; FIXED_RECORD_LEN] {
+        let data = self.as_bytes();
+        let mut record = [0u8; FIXED_RECORD_LEN];
+        record[0] = data.len() as u8;
+        record[FIXED_RECORD_LEN - data.len()..].copy_from_slice(data);
+        record
+    }
+
+    /// Parses a record produced by
+    /// [`to_fixed_record`](Self::to_fixed_record).
+    ///
+    /// Fails with [`Error::InvalidLength`] if the length byte is not 2, 4,
+    /// 8, or 16, with [`Error::NonZeroPadding`] if any padding byte is set,
+    /// and with [`Error::NotANan`] if the pattern is not a NaN.
+    pub fn from_fixed_record(record: &[u8; FIXED_RECORD_LEN]) -> Result<Self> {
+        let width = NanWidth::from_len(record[0] as usize)?;
+        let (padding, data) = record[1..].split_at(16 - width.len());
+        if padding.iter().any(|&b| b != 0) {
+            return Err(Error::NonZeroPadding);
+        }
+        Self::from_be_bytes(data)
+    }
+}
@@ -0,0 +1,94 @@
+use dcbor::prelude::*;
+
+use crate::{Error, NanBstr};
+
+/// The head of a tag-102 item: major type 6 with a one-byte argument.
+pub(crate) const TAG_HEAD: [u8; 2] = [0xD8, 0x66];
+
+/// The longest tag-102 item: two bytes of tag head, one of byte string
+/// head, and a 16-byte pattern.
+pub(crate) const MAX_FRAME_LEN: usize = 19;
+
+/// An error in the framing of a tag-102 item, found without allocating.
+///
+/// Converts into the matching [`Error`]: `WrongTag` and `WrongType` into
+/// `Error::Cbor`, the others into the variants of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum FrameError {
+    #[error("expected tag 102, got tag {0}")]
+    WrongTag(u64),
+
+    #[error("expected a tagged byte string")]
+    WrongType,
+
+    #[error("invalid NaN length: expected 2, 4, 8, or 16 bytes, got {0} bytes")]
+    InvalidLength(usize),
+
+    #[error("not a NaN bit pattern")]
+    NotANan,
+}
+
+impl From<FrameError> for Error {
+    fn from(err: FrameError) -> Self {
+        match err {
+            FrameError::WrongTag(actual) => dcbor::Error::WrongTag(
+                Tag::with_value(bc_tags::TAG_NAN_BSTR),
+                Tag::with_value(actual),
+            )
+            .into(),
+            FrameError::WrongType => dcbor::Error::WrongType.into(),
+            FrameError::InvalidLength(len) => Error::InvalidLength(len),
+            FrameError::NotANan => Error::NotANan,
+        }
+    }
+}
+
+/// Checks as much of the deterministically encoded tag-102 item at the start
+/// of `src` as has arrived. Returns the item length once the heads are
+/// complete, `None` if more bytes are needed, or the error in the heads.
+pub(crate) fn frame_len(
+    src: &[u8],
+) -> core::result::Result<Option<usize>, FrameError> {
+    let Some(&b0) = src.first() else {
+        return Ok(None);
+    };
+    if b0 != TAG_HEAD[0] {
+        return Err(match b0 {
+            0xC0..=0xD7 => FrameError::WrongTag(b0 as u64 - 0xC0),
+            _ => FrameError::WrongType,
+        });
+    }
+    let Some(&b1) = src.get(1) else {
+        return Ok(None);
+    };
+    if b1 != TAG_HEAD[1] {
+        return Err(FrameError::WrongTag(b1 as u64));
+    }
+    match src.get(2) {
+        None => Ok(None),
+        Some(&head @ 0x40..=0x57) => Ok(Some(3 + (head - 0x40) as usize)),
+        Some(_) => Err(FrameError::WrongType),
+    }
+}
+
+/// Returns the length of the item whose heads are `heads`, rejecting
+/// pattern lengths that are not a NaN width before anything more is read.
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub(crate) fn checked_frame_len(heads: &[u8]) -> crate::Result<usize> {
+    let len = frame_len(heads)?.expect("heads are complete");
+    match len - 3 {
+        2 | 4 | 8 | 16 => Ok(len),
+        n => Err(Error::InvalidLength(n)),
+    }
+}
+
+/// Encodes `nan` as a deterministic tag-102 item without allocating.
+/// Returns the buffer and the item length within it.
+pub(crate) fn encode_frame(nan: &NanBstr) -> ([u8; MAX_FRAME_LEN], usize) {
+    let pattern = nan.as_bytes();
+    let mut frame = [0; MAX_FRAME_LEN];
+    frame[..2].copy_from_slice(&TAG_HEAD);
+    frame[2] = 0x40 | pattern.len() as u8;
+    frame[3..3 + pattern.len()].copy_from_slice(pattern);
+    (frame, 3 + pattern.len())
+}
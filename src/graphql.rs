@@ -0,0 +1,38 @@
+use async_graphql::{
+    InputValueError, InputValueResult, Scalar, ScalarType, Value,
+};
+
+use crate::NanBstr;
+
+/// The `NanBstr` GraphQL scalar: the bit pattern as a hex string, such as
+/// `"7e00"`. Input may also carry a `0x` prefix and use either case, or be
+/// the [`Display`](core::fmt::Display) form that [`NanBstr::from_str`]
+/// parses, such as `"NaN[16]: + quiet payload=0x0"`.
+#[Scalar(name = "NanBstr")]
+impl ScalarType for NanBstr {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        let Value::String(s) = &value else {
+            return Err(InputValueError::expected_type(value));
+        };
+        if s.starts_with("NaN[") {
+            return s.parse().map_err(|err| {
+                InputValueError::custom(format!("invalid NaN `{}`: {}", s, err))
+            });
+        }
+        let digits = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(digits).map_err(|err| {
+            InputValueError::custom(format!("invalid hex `{}` ({})", s, err))
+        })?;
+        NanBstr::from_be_bytes(bytes).map_err(|err| {
+            InputValueError::custom(format!("`{}` is not a NaN: {}", s, err))
+        })
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(hex::encode(self.as_bytes()))
+    }
+}
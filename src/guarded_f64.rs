@@ -0,0 +1,115 @@
+use core::ops::Deref;
+
+use crate::{Error, NanBstr, Result};
+
+/// What happened to a guarded NaN's bit pattern, as reported by
+/// [`GuardedF64::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadVerdict {
+    /// The bit pattern is exactly the original.
+    Intact,
+    /// The original signaling NaN was quieted; everything else survived.
+    Quieted,
+    /// The pattern was replaced by a platform's default NaN.
+    ReplacedByCanonical,
+    /// The pattern changed in some other way (payload or sign).
+    PayloadChanged { from: NanBstr, to: NanBstr },
+    /// The value is no longer a NaN at all.
+    NoLongerNan,
+}
+
+impl PayloadVerdict {
+    /// Compares the big-endian bit pattern `current`, of the same width,
+    /// against `original`.
+    pub(crate) fn between(original: &NanBstr, current: &[u8]) -> Self {
+        if current == original.as_bytes() {
+            return Self::Intact;
+        }
+        let Ok(current) = NanBstr::from_be_bytes(current) else {
+            return Self::NoLongerNan;
+        };
+        if current.is_quiet() && current.payload_bits() == 0 {
+            Self::ReplacedByCanonical
+        } else if original.is_signaling()
+            && current.is_quiet()
+            && current.sign() == original.sign()
+            && current.payload_bits() == original.payload_bits()
+        {
+            Self::Quieted
+        } else {
+            Self::PayloadChanged {
+                from: original.clone(),
+                to: current,
+            }
+        }
+    }
+}
+
+/// An `f64` NaN that remembers its original bit pattern, so that payload
+/// loss through a stretch of code can be detected and undone.
+///
+/// Dereferences to the `f64` for use in computation; pass the result of the
+/// computation to [`verify`](Self::verify) to learn whether the pattern
+/// survived, and use [`restore`](Self::restore) to reapply it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardedF64 {
+    original: NanBstr,
+    value: f64,
+}
+
+impl GuardedF64 {
+    /// Guards a native `f64` NaN. Returns `Error::NotANan` for any other
+    /// value.
+    pub fn new(value: f64) -> Result<Self> {
+        Ok(Self {
+            original: NanBstr::try_from(value)?,
+            value,
+        })
+    }
+
+    /// Guards a binary64 `NanBstr`. Returns `Error::WidthMismatch` for any
+    /// other width.
+    pub fn from_nan_bstr(nan: NanBstr) -> Result<Self> {
+        let value = f64::try_from(nan.clone())?;
+        Ok(Self {
+            original: nan,
+            value,
+        })
+    }
+
+    /// Returns the bit pattern captured at construction.
+    pub fn original(&self) -> &NanBstr {
+        &self.original
+    }
+
+    /// Compares `current` against the original bit pattern.
+    pub fn verify(&self, current: f64) -> PayloadVerdict {
+        PayloadVerdict::between(
+            &self.original,
+            &current.to_bits().to_be_bytes(),
+        )
+    }
+
+    /// Returns the original NaN as an `f64`, rebuilt from the bit pattern
+    /// captured at construction.
+    pub fn restore(&self) -> f64 {
+        let bits = self.original.to_binary64_bits();
+        f64::from_bits(bits.expect("a guarded NaN is binary64"))
+    }
+}
+
+impl Deref for GuardedF64 {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.value
+    }
+}
+
+impl TryFrom<f64> for GuardedF64 {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self> {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{Error, NanBstr, NanWidth, Result};
+
+impl TryFrom<half::f16> for NanBstr {
+    type Error = Error;
+    fn try_from(value: half::f16) -> Result<Self> {
+        if !value.is_nan() {
+            return Err(Error::NotANan);
+        }
+        Self::from_binary16_bits(value.to_bits())
+    }
+}
+
+impl TryFrom<NanBstr> for half::f16 {
+    type Error = Error;
+    fn try_from(value: NanBstr) -> Result<Self> {
+        if value.width() != NanWidth::Binary16 {
+            return Err(Error::InvalidLength(value.as_bytes().len()));
+        }
+        Ok(half::f16::from_bits(value.to_bits_u128() as u16))
+    }
+}
+
+/// Tag 102 has no bfloat16 width, so a bfloat16 NaN is widened to binary32,
+/// whose upper half it is: sign, exponent, quiet bit, and payload keep their
+/// meaning, and the low 16 bits are zero.
+impl TryFrom<half::bf16> for NanBstr {
+    type Error = Error;
+    fn try_from(value: half::bf16) -> Result<Self> {
+        Self::from_bfloat16_bits(value.to_bits())
+    }
+}
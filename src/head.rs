@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+/// A data item head: major type, argument (`None` for indefinite length),
+/// and the offset just past it.
+pub(crate) struct Head {
+    pub major: u8,
+    pub argument: Option<u64>,
+    pub end: usize,
+}
+
+impl Head {
+    /// Reads the head at `offset`. Fails with `Underrun` if it is
+    /// truncated and with `UnsupportedHeaderValue` if it uses a reserved
+    /// additional-information value.
+    pub fn read(data: &[u8], offset: usize) -> dcbor::Result<Self> {
+        let initial = *data.get(offset).ok_or(dcbor::Error::Underrun)?;
+        let (major, info) = (initial >> 5, initial & 0x1F);
+        let len = match info {
+            0..=23 => 0,
+            24..=27 => 1 << (info - 24),
+            31 if matches!(major, 2..=5 | 7) => 0,
+            _ => return Err(dcbor::Error::UnsupportedHeaderValue(info)),
+        };
+        let end = offset + 1 + len;
+        let argument = match info {
+            0..=23 => Some(info as u64),
+            31 => None,
+            _ => Some(
+                data.get(offset + 1..end)
+                    .ok_or(dcbor::Error::Underrun)?
+                    .iter()
+                    .fold(0, |acc, &b| (acc << 8) | b as u64),
+            ),
+        };
+        Ok(Self {
+            major,
+            argument,
+            end,
+        })
+    }
+}
+
+/// Reads the content of the byte string whose head is `head`, returning the
+/// bytes and the offset just past them. An indefinite-length string is the
+/// concatenation of its definite-length chunks. Returns `None` if the
+/// string is truncated or a chunk is not a definite-length byte string.
+pub(crate) fn read_byte_string(
+    data: &[u8],
+    head: &Head,
+) -> Option<(Vec<u8>, usize)> {
+    let Some(len) = head.argument else {
+        let (mut bytes, mut offset) = (Vec::new(), head.end);
+        while *data.get(offset)? != 0xFF {
+            let chunk = Head::read(data, offset).ok()?;
+            if chunk.major != 2 || chunk.argument.is_none() {
+                return None;
+            }
+            let (chunk_bytes, end) = read_byte_string(data, &chunk)?;
+            bytes.extend(chunk_bytes);
+            offset = end;
+        }
+        return Some((bytes, offset + 1));
+    };
+    let end = head.end.checked_add(usize::try_from(len).ok()?)?;
+    Some((data.get(head.end..end)?.to_vec(), end))
+}
+
+/// Returns the offset just past the data item whose head is at `offset`,
+/// without decoding it. Fails as [`Head::read`] does for any head within
+/// the item, with `Underrun` if the item is truncated, and with
+/// `UnsupportedHeaderValue(31)` for an indefinite length, which dCBOR
+/// forbids.
+pub(crate) fn item_end(data: &[u8], offset: usize) -> dcbor::Result<usize> {
+    // Counts the items still to skip, so nesting needs no recursion.
+    let (mut offset, mut pending) = (offset, 1u64);
+    while pending > 0 {
+        pending -= 1;
+        let head = Head::read(data, offset)?;
+        let Some(argument) = head.argument else {
+            return Err(dcbor::Error::UnsupportedHeaderValue(31));
+        };
+        offset = head.end;
+        let nested = match head.major {
+            2 | 3 => {
+                offset = usize::try_from(argument)
+                    .ok()
+                    .and_then(|len| offset.checked_add(len))
+                    .filter(|&end| end <= data.len())
+                    .ok_or(dcbor::Error::Underrun)?;
+                0
+            }
+            4 => argument,
+            5 => argument.saturating_mul(2),
+            6 => 1,
+            _ => 0,
+        };
+        // Every item takes at least a byte, so more than remain cannot fit.
+        pending = pending.saturating_add(nested);
+        if pending > (data.len() - offset) as u64 {
+            return Err(dcbor::Error::Underrun);
+        }
+    }
+    Ok(offset)
+}
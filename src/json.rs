@@ -0,0 +1,120 @@
+use dcbor::{Simple, prelude::*};
+use serde_json::{Map as JsonMap, Value};
+
+use crate::{CborPath, NanBstr, NanBstrRef, NanFields, PathElement};
+
+/// How [`project_to_json`] renders NaNs, which JSON cannot represent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum JsonNanMode {
+    /// Every NaN becomes `null`.
+    #[default]
+    Null,
+    /// A tag-102 item becomes its flat diagnostic notation, such as
+    /// `"102(h'7e00')"`.
+    String,
+    /// A tag-102 item becomes its [`NanFields`], the same object the audit
+    /// report's JSON uses for a NaN.
+    Object,
+    /// Every NaN becomes `null`, and its path is reported in the sidecar
+    /// list returned by [`project_to_json_with_paths`].
+    NumberNull,
+}
+
+/// Projects a CBOR document to JSON for display, rendering tag-102 items as
+/// `nan_mode` directs.
+///
+/// Finite numbers stay numbers, and native floats that are infinite or NaN
+/// become `null`. Byte strings become hex strings, other tags are dropped in
+/// favour of their content, and map keys that are not text become their
+/// flat diagnostic notation. Negative integers below `i64::MIN` become
+/// decimal strings. Tag-102 items whose content is not a valid NaN are
+/// projected like any other tag.
+pub fn project_to_json(cbor: &CBOR, nan_mode: JsonNanMode) -> Value {
+    project(cbor, nan_mode, &mut CborPath::root(), &mut Vec::new())
+}
+
+/// Like [`project_to_json`], but also returns the path of every NaN, native
+/// or tag-102, in document order. NaNs inside map keys are not reported.
+pub fn project_to_json_with_paths(
+    cbor: &CBOR,
+    nan_mode: JsonNanMode,
+) -> (Value, Vec<CborPath>) {
+    let mut nan_paths = Vec::new();
+    let value = project(cbor, nan_mode, &mut CborPath::root(), &mut nan_paths);
+    (value, nan_paths)
+}
+
+fn project(
+    cbor: &CBOR,
+    mode: JsonNanMode,
+    path: &mut CborPath,
+    nan_paths: &mut Vec<CborPath>,
+) -> Value {
+    match cbor.as_case() {
+        CBORCase::Unsigned(n) => Value::from(*n),
+        CBORCase::Negative(n) => match i64::try_from(*n) {
+            Ok(n) => Value::from(-1 - n),
+            Err(_) => Value::from((-1 - *n as i128).to_string()),
+        },
+        CBORCase::ByteString(bytes) => Value::from(hex::encode(bytes)),
+        CBORCase::Text(text) => Value::from(text.as_str()),
+        CBORCase::Array(items) => Value::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    path.push(PathElement::Index(i));
+                    let value = project(item, mode, path, nan_paths);
+                    path.pop();
+                    value
+                })
+                .collect(),
+        ),
+        CBORCase::Map(map) => {
+            let mut object = JsonMap::new();
+            for (key, value) in map.iter() {
+                let name = match key.as_case() {
+                    CBORCase::Text(text) => text.clone(),
+                    _ => key.diagnostic_flat(),
+                };
+                path.push(PathElement::Value(key.clone()));
+                object.insert(name, project(value, mode, path, nan_paths));
+                path.pop();
+            }
+            Value::Object(object)
+        }
+        CBORCase::Tagged(tag, content) => {
+            if tag.value() == bc_tags::TAG_NAN_BSTR
+                && let Ok(nan) = NanBstrRef::from_untagged_cbor(content)
+            {
+                nan_paths.push(path.clone());
+                return project_nan(cbor, &nan.to_owned(), mode);
+            }
+            path.push(PathElement::Tagged(tag.value()));
+            let value = project(content, mode, path, nan_paths);
+            path.pop();
+            value
+        }
+        CBORCase::Simple(simple) => match simple {
+            Simple::False => Value::Bool(false),
+            Simple::True => Value::Bool(true),
+            Simple::Null => Value::Null,
+            Simple::Float(f) => {
+                if f.is_nan() {
+                    nan_paths.push(path.clone());
+                }
+                serde_json::Number::from_f64(*f)
+                    .map_or(Value::Null, Value::Number)
+            }
+        },
+    }
+}
+
+fn project_nan(cbor: &CBOR, nan: &NanBstr, mode: JsonNanMode) -> Value {
+    match mode {
+        JsonNanMode::Null | JsonNanMode::NumberNull => Value::Null,
+        JsonNanMode::String => Value::from(cbor.diagnostic_flat()),
+        JsonNanMode::Object => serde_json::to_value(NanFields::from(nan))
+            .expect("NanFields serializes to JSON"),
+    }
+}
@@ -11,10 +11,155 @@
 //!
 //! NOTE: This includes **binary128 (f128)** support without using any native
 //! `f128` type: APIs accept/return raw bit patterns as `u128` or `[u8; 16]`.
+//! On a nightly compiler, the `nightly-float` feature adds conversions to and
+//! from the unstable `f16` and `f128` primitives.
+//!
+//! Without the default `std` feature the crate is `no_std` and needs only
+//! `alloc`; [`NanBstrDecoder`] and [`NanBstrRef`] do not allocate at all.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly-float", feature(f16, f128))]
+
+extern crate alloc;
 
 mod nan_bstr;
 pub use nan_bstr::*;
+mod nan_bstr_builder;
+pub use nan_bstr_builder::*;
+mod const_nan;
+pub use const_nan::*;
 mod nan_width;
 pub use nan_width::*;
+mod width_set;
+pub use width_set::*;
 mod error;
 pub use error::*;
+mod nan_pattern;
+pub use nan_pattern::*;
+mod nan_pattern_syntax;
+mod nan_bstr_set16;
+pub use nan_bstr_set16::*;
+mod walk;
+pub use walk::*;
+mod nan_policy;
+pub use nan_policy::*;
+mod provenance;
+pub use provenance::*;
+mod audit;
+pub use audit::*;
+mod guarded_f64;
+pub use guarded_f64::*;
+mod self_test;
+pub use self_test::*;
+mod truncation_policy;
+pub use truncation_policy::*;
+mod nan_class;
+pub use nan_class::*;
+mod nan_info;
+pub use nan_info::*;
+mod nan_bstr_ref;
+pub use nan_bstr_ref::*;
+mod packed;
+pub use packed::*;
+mod fixed_record;
+pub use fixed_record::*;
+mod total_order;
+pub use total_order::*;
+mod explain;
+pub mod conformance;
+mod head;
+mod scan;
+pub use scan::*;
+mod frame;
+pub use frame::*;
+mod push_decoder;
+pub use push_decoder::*;
+mod visitor;
+pub use visitor::*;
+mod maybe_nan;
+pub use maybe_nan::*;
+mod const_cbor;
+pub use const_cbor::*;
+mod payload_layout;
+pub use payload_layout::*;
+pub mod ops;
+mod modbus;
+pub use modbus::*;
+mod table;
+pub use table::*;
+#[cfg(feature = "std")]
+mod std_io;
+mod seq;
+pub use seq::*;
+#[cfg(feature = "extended80")]
+mod extended80;
+#[cfg(feature = "extended80")]
+pub use extended80::*;
+#[cfg(feature = "bf16")]
+mod bf16;
+#[cfg(feature = "bf16")]
+pub use bf16::*;
+#[cfg(feature = "binary256")]
+mod binary256;
+#[cfg(feature = "binary256")]
+pub use binary256::*;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "decimal")]
+pub use decimal::*;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(feature = "bitvec")]
+mod bitvec_view;
+#[cfg(feature = "tokio")]
+mod codec;
+#[cfg(feature = "tokio")]
+pub use codec::*;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+#[cfg(feature = "serde")]
+mod nan_serde;
+#[cfg(feature = "serde")]
+pub use nan_serde::*;
+#[cfg(feature = "serde")]
+mod diagnostics;
+#[cfg(feature = "serde")]
+pub use diagnostics::*;
+#[cfg(feature = "dcbor-pattern")]
+mod dcbor_pattern_impls;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impls;
+#[cfg(feature = "embedded-io")]
+pub use embedded_io_impls::*;
+#[cfg(feature = "parquet")]
+pub mod parquet_sidecar;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "wasmtime")]
+mod wasmtime_impls;
+#[cfg(feature = "wasmtime")]
+pub use wasmtime_impls::*;
+#[cfg(feature = "miette")]
+mod miette_impls;
+#[cfg(feature = "miette")]
+pub use miette_impls::*;
+#[cfg(feature = "dcbor-parse")]
+mod dcbor_parse_impls;
+#[cfg(feature = "half")]
+mod half_impls;
+#[cfg(feature = "nightly-float")]
+mod nightly_float;
+#[cfg(feature = "rand")]
+mod rand_impls;
+#[cfg(feature = "rand")]
+pub use rand_impls::*;
@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use dcbor::prelude::*;
+
+use crate::{
+    AUDIT_NEAREST_DISTANCE, Error, KnownPattern, NanBstr, NanWidth, Result,
+    nan_bstr::fold_bits, provenance::nearest_known_bits,
+};
+
+/// The content of a tag-102 item, kept whether or not it is a valid NaN.
+///
+/// Decoding with [`NanBstr`] rejects bad content outright; `MaybeNan` keeps
+/// the observed bytes and the reason they were rejected, so malformed data
+/// can be reported on and passed along unchanged.
+#[derive(Debug)]
+pub enum MaybeNan {
+    /// The content is a valid NaN.
+    Valid(NanBstr),
+    /// The content is a byte string that is not a valid NaN.
+    Invalid {
+        /// The bytes as observed.
+        bytes: Vec<u8>,
+        /// Why they were rejected.
+        reason: Error,
+    },
+}
+
+impl MaybeNan {
+    /// Classifies big-endian `bytes` of any length. Never fails.
+    pub fn from_be_bytes(bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+        match NanBstr::from_be_bytes(bytes) {
+            Ok(nan) => Self::Valid(nan),
+            Err(reason) => Self::Invalid {
+                bytes: bytes.to_vec(),
+                reason,
+            },
+        }
+    }
+
+    /// Decodes a tag-102 item, failing only if it is not a tag-102 item
+    /// holding a byte string. Content problems yield [`MaybeNan::Invalid`].
+    pub fn from_cbor(cbor: &CBOR) -> Result<Self> {
+        match cbor.as_case() {
+            CBORCase::Tagged(tag, content)
+                if tag.value() == bc_tags::TAG_NAN_BSTR =>
+            {
+                match content.as_case() {
+                    CBORCase::ByteString(bytes) => {
+                        Ok(Self::from_be_bytes(bytes.data()))
+                    }
+                    _ => Err(dcbor::Error::WrongType.into()),
+                }
+            }
+            CBORCase::Tagged(tag, _) => Err(dcbor::Error::WrongTag(
+                Tag::with_value(bc_tags::TAG_NAN_BSTR),
+                tag.clone(),
+            )
+            .into()),
+            _ => Err(dcbor::Error::WrongType.into()),
+        }
+    }
+
+    /// Decodes a tag-102 item from CBOR data, as [`MaybeNan::from_cbor`].
+    pub fn from_cbor_data(data: impl AsRef<[u8]>) -> Result<Self> {
+        Self::from_cbor(&CBOR::try_from_data(data)?)
+    }
+
+    /// Returns true if the content is a valid NaN.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid(_))
+    }
+
+    /// Returns the NaN, if the content is valid.
+    pub fn as_valid(&self) -> Option<&NanBstr> {
+        match self {
+            Self::Valid(nan) => Some(nan),
+            Self::Invalid { .. } => None,
+        }
+    }
+
+    /// Returns why the content was rejected, if it was.
+    pub fn reason(&self) -> Option<&Error> {
+        match self {
+            Self::Valid(_) => None,
+            Self::Invalid { reason, .. } => Some(reason),
+        }
+    }
+
+    /// Returns the content bytes in big-endian order.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Valid(nan) => nan.as_bytes(),
+            Self::Invalid { bytes, .. } => bytes,
+        }
+    }
+
+    /// Returns the well-known pattern nearest to the content by Hamming
+    /// distance, if within `max_distance`, as [`NanBstr::nearest_known`]
+    /// does. Invalid content is compared too, if it has a NaN width, so a
+    /// corrupted pattern can be traced to what it likely was.
+    pub fn nearest_known(
+        &self,
+        max_distance: u32,
+    ) -> Option<(KnownPattern, u32)> {
+        match self {
+            Self::Valid(nan) => nan.nearest_known(max_distance),
+            Self::Invalid { bytes, .. } => {
+                let width = NanWidth::from_len(bytes.len()).ok()?;
+                nearest_known_bits(width, fold_bits(bytes), max_distance)
+            }
+        }
+    }
+}
+
+impl From<NanBstr> for MaybeNan {
+    fn from(nan: NanBstr) -> Self {
+        Self::Valid(nan)
+    }
+}
+
+impl TryFrom<CBOR> for MaybeNan {
+    type Error = Error;
+
+    fn try_from(cbor: CBOR) -> Result<Self> {
+        Self::from_cbor(&cbor)
+    }
+}
+
+/// Re-encodes the item with the observed bytes, valid or not.
+impl From<&MaybeNan> for CBOR {
+    fn from(value: &MaybeNan) -> Self {
+        CBOR::to_tagged_value(
+            bc_tags::TAG_NAN_BSTR,
+            ByteString::from(value.as_bytes()),
+        )
+    }
+}
+
+impl From<MaybeNan> for CBOR {
+    fn from(value: MaybeNan) -> Self {
+        CBOR::from(&value)
+    }
+}
+
+impl fmt::Display for MaybeNan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bytes, reason) = match self {
+            Self::Valid(nan) => return write!(f, "{}", nan),
+            Self::Invalid { bytes, reason } => (bytes, reason),
+        };
+        write!(f, "invalid NaN ({}): h'{}'", reason, hex::encode(bytes))?;
+        let Ok(width) = NanWidth::from_len(bytes.len()) else {
+            return write!(f, " is not a NaN width");
+        };
+        let fields = NonNanFields::new(width, bytes);
+        write!(
+            f,
+            " is {} sign={} exponent=0x{:x} fraction=0x{:x}, {}",
+            width,
+            if fields.negative { "-" } else { "+" },
+            fields.exponent,
+            fields.fraction,
+            fields.kind,
+        )?;
+        if let Some((pattern, distance)) =
+            self.nearest_known(AUDIT_NEAREST_DISTANCE)
+        {
+            write!(
+                f,
+                "; {} bit{} away from {}",
+                distance,
+                if distance == 1 { "" } else { "s" },
+                pattern.name,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The fields of a pattern of a valid width that is not a NaN, and what it
+/// encodes instead.
+pub(crate) struct NonNanFields {
+    pub negative: bool,
+    pub exponent: u128,
+    pub fraction: u128,
+    /// "an infinity", "a zero", "a subnormal number", or "a normal number".
+    pub kind: &'static str,
+}
+
+impl NonNanFields {
+    pub fn new(width: NanWidth, bytes: &[u8]) -> Self {
+        let bits = fold_bits(bytes);
+        let fraction_bits = width.fraction_bits();
+        let exponent_max = (1u128 << width.exponent_bits()) - 1;
+        let exponent = (bits >> fraction_bits) & exponent_max;
+        let fraction = bits & ((1u128 << fraction_bits) - 1);
+        let kind = match (exponent, fraction) {
+            (e, _) if e == exponent_max => "an infinity",
+            (0, 0) => "a zero",
+            (0, _) => "a subnormal number",
+            _ => "a normal number",
+        };
+        Self {
+            negative: bits >> (width.bits() - 1) != 0,
+            exponent,
+            fraction,
+            kind,
+        }
+    }
+}
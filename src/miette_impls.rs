@@ -0,0 +1,260 @@
+use core::fmt;
+
+use dcbor::prelude::*;
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode};
+
+use crate::{
+    Error, NanBstr, NanWidth,
+    head::{Head, read_byte_string},
+    maybe_nan::NonNanFields,
+};
+
+/// A failure to decode a tag-102 item, carrying the input so that
+/// `miette` can point at the bytes at fault.
+///
+/// The input is shown as hex, two digits and a space per byte, so byte `i`
+/// of the input starts at offset `3 * i` of the source. Labels mark the tag
+/// head, the byte string head, and the pattern, as far as the input gets,
+/// and the help text says what the bytes encode instead.
+#[derive(Debug, thiserror::Error)]
+#[error("{}", self.0.error)]
+pub struct DecodeFailure(Box<Details>);
+
+#[derive(Debug)]
+struct Details {
+    error: Error,
+    input: Vec<u8>,
+    named_source: NamedSource<String>,
+    labels: Vec<LabeledSpan>,
+    help: Option<String>,
+}
+
+impl DecodeFailure {
+    /// Returns the underlying error.
+    pub fn error(&self) -> &Error {
+        &self.0.error
+    }
+
+    /// Returns the input bytes.
+    pub fn input(&self) -> &[u8] {
+        &self.0.input
+    }
+
+    /// Returns the underlying error, discarding the input.
+    pub fn into_error(self) -> Error {
+        self.0.error
+    }
+}
+
+impl Diagnostic for DecodeFailure {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let code = match &self.0.error {
+            Error::Cbor(dcbor::Error::WrongTag(..)) => "wrong_tag",
+            Error::Cbor(dcbor::Error::WrongType) => "wrong_type",
+            Error::InvalidLength(_) => "invalid_length",
+            Error::NotANan => "not_a_nan",
+            _ => "malformed",
+        };
+        Some(Box::new(format!("cbor_nan_bstr::{}", code)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.0
+            .help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.0.named_source)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.0.labels.iter().cloned()))
+    }
+}
+
+impl NanBstr {
+    /// Decodes a tag-102 item from CBOR data, as `NanBstr::try_from`, but
+    /// fails with a [`DecodeFailure`] for `miette` to render, naming the
+    /// input `name`.
+    pub fn from_cbor_data_diagnosed(
+        name: impl AsRef<str>,
+        data: impl AsRef<[u8]>,
+    ) -> Result<Self, DecodeFailure> {
+        let data = data.as_ref();
+        let mut failure = Failure {
+            data,
+            labels: Vec::new(),
+        };
+        let (error, help) =
+            match failure.check().and_then(|()| failure.decode_strictly()) {
+                Ok(nan) => return Ok(nan),
+                Err(err) => err,
+            };
+        Err(DecodeFailure(Box::new(Details {
+            error,
+            input: data.to_vec(),
+            named_source: NamedSource::new(
+                name,
+                data.iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            labels: failure.labels,
+            help: Some(help),
+        })))
+    }
+}
+
+/// Labels the parts of an item while checking its structure.
+struct Failure<'a> {
+    data: &'a [u8],
+    labels: Vec<LabeledSpan>,
+}
+
+impl Failure<'_> {
+    /// Labels the bytes in `range`, in the hex source. Empty ranges are
+    /// not labeled.
+    fn label(
+        &mut self,
+        range: core::ops::Range<usize>,
+        text: impl Into<String>,
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let len = range.len() * 3 - 1;
+        self.labels.push(LabeledSpan::new(
+            Some(text.into()),
+            range.start * 3,
+            len,
+        ));
+    }
+
+    fn truncated(&mut self, offset: usize) -> (Error, String) {
+        self.label(offset..self.data.len(), "truncated here");
+        (
+            dcbor::Error::Underrun.into(),
+            "the data ends before the item does".to_string(),
+        )
+    }
+
+    fn bad_head(
+        &mut self,
+        offset: usize,
+        error: dcbor::Error,
+    ) -> (Error, String) {
+        if matches!(error, dcbor::Error::Underrun) {
+            return self.truncated(offset);
+        }
+        self.label(offset..offset + 1, "reserved head");
+        (
+            error.into(),
+            "additional information 28 to 30 is reserved".to_string(),
+        )
+    }
+
+    /// Decodes as `NanBstr::try_from` does, once [`Failure::check`] finds
+    /// nothing wrong. What remains is a serialization that is well-formed
+    /// but not deterministic, such as a longer head than needed.
+    fn decode_strictly(&mut self) -> Result<NanBstr, (Error, String)> {
+        CBOR::try_from_data(self.data)
+            .and_then(NanBstr::try_from)
+            .map_err(|err| {
+                self.label(0..self.data.len(), "not deterministic CBOR");
+                (
+                    err.into(),
+                    "encode with the shortest heads and definite lengths, as \
+                     deterministic CBOR requires"
+                        .to_string(),
+                )
+            })
+    }
+
+    /// Checks the structure the strict decoder does not explain, returning
+    /// the error and help text for the first problem.
+    fn check(&mut self) -> Result<(), (Error, String)> {
+        let data = self.data;
+        let tag = Head::read(data, 0).map_err(|err| self.bad_head(0, err))?;
+        if tag.major != 6 || tag.argument != Some(bc_tags::TAG_NAN_BSTR) {
+            self.label(0..tag.end, "expected tag 102");
+            let error = if tag.major == 6 {
+                dcbor::Error::WrongTag(
+                    Tag::with_value(bc_tags::TAG_NAN_BSTR),
+                    Tag::with_value(tag.argument.unwrap_or_default()),
+                )
+            } else {
+                dcbor::Error::WrongType
+            };
+            return Err((
+                error.into(),
+                "a tag-102 item begins with the tag head d8 66".to_string(),
+            ));
+        }
+        self.label(0..tag.end, "tag 102");
+
+        let content = Head::read(data, tag.end)
+            .map_err(|err| self.bad_head(tag.end, err))?;
+        if content.major != 2 {
+            self.label(tag.end..content.end, "not a byte string");
+            return Err((
+                dcbor::Error::WrongType.into(),
+                "the content of tag 102 must be a byte string".to_string(),
+            ));
+        }
+        let Some((bytes, end)) = read_byte_string(data, &content) else {
+            self.label(tag.end..content.end, "byte string");
+            return Err(self.truncated(content.end));
+        };
+        self.label(tag.end..content.end, format!("{} bytes", bytes.len()));
+
+        let pattern = content.end..end;
+        let Ok(width) = NanWidth::from_len(bytes.len()) else {
+            self.label(pattern, format!("{} bytes of pattern", bytes.len()));
+            return Err((
+                Error::InvalidLength(bytes.len()),
+                "a NaN pattern is 2, 4, 8, or 16 bytes, for binary16 \
+                 through binary128"
+                    .to_string(),
+            ));
+        };
+        if let Err(error) = NanBstr::from_be_bytes(&bytes) {
+            let fields = NonNanFields::new(width, &bytes);
+            let sign = if fields.negative { "-" } else { "+" };
+            self.label(
+                pattern,
+                format!(
+                    "{}: exponent 0x{:x}, fraction 0x{:x}",
+                    width, fields.exponent, fields.fraction
+                ),
+            );
+            let help = if fields.kind == "an infinity" {
+                format!(
+                    "fraction bits are zero \u{2014} this encodes {}infinity, \
+                     not a NaN",
+                    sign
+                )
+            } else {
+                format!(
+                    "exponent bits are not all ones \u{2014} this encodes {}, \
+                     not a NaN",
+                    fields.kind
+                )
+            };
+            return Err((error, help));
+        }
+        self.label(pattern, format!("{} NaN", width));
+        if end < data.len() {
+            self.label(end..data.len(), "trailing data");
+            return Err((
+                dcbor::Error::UnusedData(data.len() - end).into(),
+                "a tag-102 item is a single data item; remove the bytes \
+                 after it"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
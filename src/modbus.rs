@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// The order in which a device lays out a value's bytes across 16-bit
+/// Modbus registers, named for a 32-bit value whose big-endian bytes are
+/// `A B C D`.
+///
+/// For wider values, the word order extends to every register: `CDAB` and
+/// `DCBA` put the least significant register first. For a binary16 value,
+/// which fills one register, `ABCD` and `CDAB` agree, as do `BADC` and
+/// `DCBA`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WordOrder {
+    /// Most significant register first, bytes big-endian in each register.
+    #[default]
+    ABCD,
+    /// Least significant register first, bytes big-endian in each register.
+    CDAB,
+    /// Most significant register first, bytes swapped in each register.
+    BADC,
+    /// Least significant register first, bytes swapped in each register:
+    /// fully little-endian.
+    DCBA,
+}
+
+impl WordOrder {
+    fn reverses_registers(self) -> bool {
+        matches!(self, Self::CDAB | Self::DCBA)
+    }
+
+    fn swaps_bytes(self) -> bool {
+        matches!(self, Self::BADC | Self::DCBA)
+    }
+
+    /// Converts register values as read from the device to their values in
+    /// `ABCD` order, or back: the conversion is its own inverse.
+    fn reorder(self, regs: &[u16]) -> Vec<u16> {
+        let mut regs = regs.to_vec();
+        if self.reverses_registers() {
+            regs.reverse();
+        }
+        if self.swaps_bytes() {
+            regs.iter_mut().for_each(|reg| *reg = reg.swap_bytes());
+        }
+        regs
+    }
+}
+
+impl NanBstr {
+    /// Construct from the 16-bit Modbus registers holding a NaN of `width`,
+    /// laid out in `order`.
+    ///
+    /// Fails with [`Error::InvalidRegisterCount`] unless there is exactly
+    /// one register per two bytes of `width`.
+    pub fn from_modbus_registers(
+        regs: &[u16],
+        width: NanWidth,
+        order: WordOrder,
+    ) -> Result<Self> {
+        let expected = width.len() / 2;
+        if regs.len() != expected {
+            return Err(Error::InvalidRegisterCount {
+                width,
+                expected,
+                actual: regs.len(),
+            });
+        }
+        let bytes: Vec<u8> = order
+            .reorder(regs)
+            .iter()
+            .flat_map(|reg| reg.to_be_bytes())
+            .collect();
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Returns the 16-bit Modbus registers holding this NaN, laid out in
+    /// `order`.
+    pub fn to_modbus_registers(&self, order: WordOrder) -> Vec<u16> {
+        let regs: Vec<u16> = self
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        order.reorder(&regs)
+    }
+}
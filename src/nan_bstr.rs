@@ -1,29 +1,137 @@
-use core::fmt;
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use core::{fmt, str::FromStr};
 use dcbor::prelude::*;
-use crate::{Error, NanWidth, Result};
+use crate::{
+    ConstNan, Error, MaybeNan, NanBstrRef, NanWidth, Result,
+    TruncationPolicy, frame::TAG_HEAD,
+};
 
 /// A CBOR-friendly wrapper for an IEEE‑754 NaN bit pattern transported as a
 /// byte string and tagged with CBOR tag 102 ("nan-bstr").
 ///
 /// The enclosed bytes are kept exactly as given (big‑endian), and validity is
 /// enforced at construction and when decoding from CBOR.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// The x87 conversions, `from_x87_extended_bytes`, `from_x87_extended_parts`,
+/// and `to_x87_extended_bytes`, need the `extended80` feature.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct NanBstr(ByteString);
 
 impl NanBstr {
+    // ────────────────────────────── Constants ───────────────────────────────
+
+    /// The bits of the positive quiet binary16 NaN with a zero payload, as
+    /// returned by [`canonical_quiet`](Self::canonical_quiet).
+    pub const QNAN_BINARY16: u16 =
+        ConstNan::canonical_quiet(NanWidth::Binary16).to_bits() as u16;
+    /// The bits of the positive quiet binary32 NaN with a zero payload.
+    pub const QNAN_BINARY32: u32 =
+        ConstNan::canonical_quiet(NanWidth::Binary32).to_bits() as u32;
+    /// The bits of the positive quiet binary64 NaN with a zero payload.
+    pub const QNAN_BINARY64: u64 =
+        ConstNan::canonical_quiet(NanWidth::Binary64).to_bits() as u64;
+    /// The bits of the positive quiet binary128 NaN with a zero payload.
+    pub const QNAN_BINARY128: u128 =
+        ConstNan::canonical_quiet(NanWidth::Binary128).to_bits();
+
+    /// The bits of the positive signaling binary16 NaN with a payload of 1,
+    /// as returned by [`minimal_signaling`](Self::minimal_signaling).
+    pub const SNAN_MIN_BINARY16: u16 =
+        ConstNan::minimal_signaling(NanWidth::Binary16).to_bits() as u16;
+    /// The bits of the positive signaling binary32 NaN with a payload of 1.
+    pub const SNAN_MIN_BINARY32: u32 =
+        ConstNan::minimal_signaling(NanWidth::Binary32).to_bits() as u32;
+    /// The bits of the positive signaling binary64 NaN with a payload of 1.
+    pub const SNAN_MIN_BINARY64: u64 =
+        ConstNan::minimal_signaling(NanWidth::Binary64).to_bits() as u64;
+    /// The bits of the positive signaling binary128 NaN with a payload of 1.
+    pub const SNAN_MIN_BINARY128: u128 =
+        ConstNan::minimal_signaling(NanWidth::Binary128).to_bits();
+
     // ───────────────────────────── Constructors ─────────────────────────────
 
     /// Construct from a big‑endian byte slice (length 2, 4, 8, or 16).
     /// Validates that the bit pattern encodes a NaN of the corresponding width.
     pub fn from_be_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let b = bytes.as_ref();
+        check_nan_bytes(b)?;
+        Ok(Self(ByteString::from(b)))
+    }
+
+    /// Construct from a big‑endian byte slice already known to hold a NaN,
+    /// such as one validated upstream. Checks the length (2, 4, 8, or 16) but
+    /// not the bits, except in debug builds, which assert that they are a
+    /// NaN.
+    ///
+    /// Passing bits that are not a NaN is not undefined behavior. Accessors
+    /// report the fields of the bits as given, and comparison, hashing, and
+    /// encoding work as usual, but the value is not a NaN: conversions that
+    /// build a new NaN from it may fail or panic, and decoders will reject
+    /// its encoding.
+    pub fn from_be_bytes_unchecked(bytes: impl AsRef<[u8]>) -> Result<Self> {
         let b = bytes.as_ref();
         let width = NanWidth::from_len(b.len())?;
-        if !is_nan_bits(width, b) {
-            return Err(Error::NotANan);
-        }
+        debug_assert!(is_nan_bits(width, b), "not a NaN: {:02x?}", b);
         Ok(Self(ByteString::from(b)))
     }
 
+    /// Construct from a big‑endian byte string, taking ownership of its
+    /// buffer rather than copying it. Validates as
+    /// [`from_be_bytes`](Self::from_be_bytes).
+    pub(crate) fn from_byte_string(bytes: ByteString) -> Result<Self> {
+        check_nan_bytes(bytes.data())?;
+        Ok(Self(bytes))
+    }
+
+    /// Construct by copying a view, whose bytes were validated when it was
+    /// built, without checking them again.
+    pub(crate) fn from_validated(nan: NanBstrRef<'_>) -> Self {
+        Self(ByteString::from(nan.as_bytes()))
+    }
+
+    /// Construct from a little‑endian byte slice (length 2, 4, 8, or 16),
+    /// as found in memory dumps from little‑endian machines. The bytes are
+    /// reversed, then validated as by
+    /// [`from_be_bytes`](Self::from_be_bytes).
+    pub fn from_le_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let mut b = bytes.as_ref().to_vec();
+        b.reverse();
+        Self::from_be_bytes(b)
+    }
+
+    /// Construct from a byte slice (length 2, 4, 8, or 16) in the host's
+    /// native byte order, such as the output of `f64::to_ne_bytes`. The
+    /// pattern is stored big‑endian either way.
+    pub fn from_ne_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        if cfg!(target_endian = "big") {
+            Self::from_be_bytes(bytes)
+        } else {
+            Self::from_le_bytes(bytes)
+        }
+    }
+
+    /// Construct from big‑endian hex, such as `"7ff8000000000123"`. The
+    /// digits may follow a `0x` prefix and be separated by underscores or
+    /// spaces; 4, 8, 16, or 32 of them select the width.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let trimmed = hex.trim_start();
+        let prefixed = trimmed.starts_with("0x") || trimmed.starts_with("0X");
+        let start = hex.len() - trimmed.len() + if prefixed { 2 } else { 0 };
+        let mut digits = String::new();
+        for (offset, c) in hex.char_indices().skip_while(|&(i, _)| i < start) {
+            if c.is_ascii_hexdigit() {
+                digits.push(c);
+            } else if c != '_' && !c.is_whitespace() {
+                return Err(Error::InvalidHexDigit { offset, digit: c });
+            }
+        }
+        if !digits.len().is_multiple_of(2) {
+            return Err(Error::InvalidLength(digits.len().div_ceil(2)));
+        }
+        NanWidth::from_len(digits.len() / 2)?;
+        Self::from_be_bytes(hex::decode(digits).expect("checked hex digits"))
+    }
+
     /// Construct from a native-endian 16-bit bit pattern.
     pub fn from_binary16_bits(bits: u16) -> Result<Self> {
         Self::from_be_bytes(bits.to_be_bytes())
@@ -44,12 +152,192 @@ impl NanBstr {
         Self::from_be_bytes(bits.to_be_bytes())
     }
 
-    /// Construct from two 64-bit words (high, low) representing binary128.
+    /// Construct from a pattern of `width` zero-extended into a `u128`, as
+    /// [`to_bits_u128`](Self::to_bits_u128) returns it.
+    ///
+    /// Fails with [`Error::BitsExceedWidth`] if any bit above the width is
+    /// set, rather than truncating.
+    pub fn from_bits_u128(width: NanWidth, bits: u128) -> Result<Self> {
+        if width.bits() < u128::BITS && bits >> width.bits() != 0 {
+            return Err(Error::BitsExceedWidth { width, bits });
+        }
+        Self::from_be_bytes(&bits.to_be_bytes()[16 - width.len()..])
+    }
+
+    /// Construct from the native-endian bytes of a 16-bit pattern.
+    pub fn from_binary16_ne_bytes(bytes: [u8; 2]) -> Result<Self> {
+        Self::from_binary16_bits(u16::from_ne_bytes(bytes))
+    }
+
+    /// Construct from the native-endian bytes of a 32-bit pattern, as
+    /// returned by `f32::to_ne_bytes`.
+    pub fn from_binary32_ne_bytes(bytes: [u8; 4]) -> Result<Self> {
+        Self::from_binary32_bits(u32::from_ne_bytes(bytes))
+    }
+
+    /// Construct from the native-endian bytes of a 64-bit pattern, as
+    /// returned by `f64::to_ne_bytes`.
+    pub fn from_binary64_ne_bytes(bytes: [u8; 8]) -> Result<Self> {
+        Self::from_binary64_bits(u64::from_ne_bytes(bytes))
+    }
+
+    /// Construct from the native-endian bytes of a 128-bit pattern.
+    pub fn from_binary128_ne_bytes(bytes: [u8; 16]) -> Result<Self> {
+        Self::from_binary128_bits(u128::from_ne_bytes(bytes))
+    }
+
+    /// Construct a NaN of `width` from its fields: the sign bit (true =
+    /// negative), the quiet bit, and the payload beneath it.
+    ///
+    /// Fails with [`Error::PayloadTooLarge`] if `payload` does not fit the
+    /// width (for example, more than 51 bits for binary64), and with
+    /// [`Error::NotANan`] for a signaling NaN with a zero payload, which is
+    /// an infinity.
+    pub fn from_parts(
+        width: NanWidth,
+        sign: bool,
+        quiet: bool,
+        payload: u128,
+    ) -> Result<Self> {
+        if payload > width.max_payload() {
+            return Err(Error::PayloadTooLarge { width, payload });
+        }
+        let bits = (sign as u128) << width.sign_bit_index()
+            | width.exponent_mask_u128()
+            | (quiet as u128) << width.quiet_bit_index()
+            | payload;
+        Self::from_be_bytes(&bits.to_be_bytes()[16 - width.len()..])
+    }
+
+    /// Returns the positive quiet NaN of `width` with a zero payload, such
+    /// as `0x7E00` for binary16 or `f64::NAN` for binary64.
+    pub fn canonical_quiet(width: NanWidth) -> Self {
+        ConstNan::canonical_quiet(width).to_nan_bstr()
+    }
+
+    /// Returns the positive signaling NaN of `width` with a payload of 1,
+    /// the signaling NaN closest to infinity, such as `0x7C01` for
+    /// binary16.
+    pub fn minimal_signaling(width: NanWidth) -> Self {
+        ConstNan::minimal_signaling(width).to_nan_bstr()
+    }
+
+    /// Construct the positive quiet NaN of `width` carrying `payload`.
+    ///
+    /// Fails with [`Error::PayloadTooLarge`] if `payload` exceeds
+    /// [`NanWidth::max_payload`].
+    pub fn from_payload(width: NanWidth, payload: u128) -> Result<Self> {
+        Self::builder(width).payload(payload).build()
+    }
+
+    /// Construct the positive signaling NaN of `width` carrying `payload`.
+    ///
+    /// Fails with [`Error::PayloadTooLarge`] if `payload` exceeds
+    /// [`NanWidth::max_payload`], and with [`Error::SignalingZeroPayload`]
+    /// if it is zero.
+    pub fn from_payload_signaling(
+        width: NanWidth,
+        payload: u128,
+    ) -> Result<Self> {
+        Self::builder(width).signaling().payload(payload).build()
+    }
+
+    /// Construct from two 64-bit words representing binary128: `high` is
+    /// bits 127–64 of the pattern (sign, exponent, and the top 48 fraction
+    /// bits) and `low` is bits 63–0.
     pub fn from_binary128_words(high: u64, low: u64) -> Result<Self> {
         let bits = ((high as u128) << 64) | (low as u128);
         Self::from_binary128_bits(bits)
     }
 
+    /// Construct from 64-bit words, most significant first: one word for
+    /// binary64, two for binary128. Any other count is
+    /// `Error::InvalidLength`, giving the length in bytes.
+    pub fn from_be_words(words: &[u64]) -> Result<Self> {
+        let bytes: Vec<u8> =
+            words.iter().flat_map(|word| word.to_be_bytes()).collect();
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Construct from 32-bit words, most significant first: one word for
+    /// binary32, two for binary64, four for binary128. Any other count is
+    /// `Error::InvalidLength`, giving the length in bytes.
+    pub fn from_be_u32_words(words: &[u32]) -> Result<Self> {
+        let bytes: Vec<u8> =
+            words.iter().flat_map(|word| word.to_be_bytes()).collect();
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Construct from a 16-bit value holding the pattern's bytes in
+    /// little‑endian order; equivalent to
+    /// `from_binary16_bits(bits.swap_bytes())`.
+    pub fn from_binary16_le_bits(bits: u16) -> Result<Self> {
+        Self::from_le_bytes(bits.to_be_bytes())
+    }
+
+    /// Construct from a 32-bit value holding the pattern's bytes in
+    /// little‑endian order; equivalent to
+    /// `from_binary32_bits(bits.swap_bytes())`.
+    pub fn from_binary32_le_bits(bits: u32) -> Result<Self> {
+        Self::from_le_bytes(bits.to_be_bytes())
+    }
+
+    /// Construct from a 64-bit value holding the pattern's bytes in
+    /// little‑endian order; equivalent to
+    /// `from_binary64_bits(bits.swap_bytes())`.
+    pub fn from_binary64_le_bits(bits: u64) -> Result<Self> {
+        Self::from_le_bytes(bits.to_be_bytes())
+    }
+
+    /// Construct from a 128-bit value holding the pattern's bytes in
+    /// little‑endian order; equivalent to
+    /// `from_binary128_bits(bits.swap_bytes())`.
+    pub fn from_binary128_le_bits(bits: u128) -> Result<Self> {
+        Self::from_le_bytes(bits.to_be_bytes())
+    }
+
+    /// Construct from the bits of an `f32`, as returned by `f32::to_bits`.
+    ///
+    /// This is the preferred way to capture a native NaN: passing a
+    /// signaling NaN by value as an `f32` may quiet it on some targets (x87
+    /// calling conventions, some MIPS ABIs), so `NanBstr::try_from(f32)` can
+    /// observe a different pattern than the caller held. Extracting the bits
+    /// where the value lives and passing them as an integer avoids any
+    /// floating-point register round trip.
+    #[inline]
+    pub fn from_f32_bits(bits: u32) -> Result<Self> {
+        Self::from_binary32_bits(bits)
+    }
+
+    /// Construct from the bits of an `f64`, as returned by `f64::to_bits`.
+    ///
+    /// Preferred over `NanBstr::try_from(f64)` for the same reason as
+    /// [`from_f32_bits`](Self::from_f32_bits).
+    #[inline]
+    pub fn from_f64_bits(bits: u64) -> Result<Self> {
+        Self::from_binary64_bits(bits)
+    }
+
+    /// Construct from an `f32` held in memory, reading its bits through the
+    /// reference so the value never passes through a floating-point
+    /// register.
+    #[inline]
+    pub fn from_f32_ref(value: &f32) -> Result<Self> {
+        // `to_bits` is an inlined transmute of the value in memory, with no
+        // call boundary for a float to cross.
+        Self::from_f32_bits(value.to_bits())
+    }
+
+    /// Construct from an `f64` held in memory, reading its bits through the
+    /// reference so the value never passes through a floating-point
+    /// register.
+    #[inline]
+    pub fn from_f64_ref(value: &f64) -> Result<Self> {
+        // `to_bits` is an inlined transmute of the value in memory, with no
+        // call boundary for a float to cross.
+        Self::from_f64_bits(value.to_bits())
+    }
+
     // ───────────────────────────── Accessors ────────────────────────────────
 
     /// Returns the width (binary16/32/64/128) encoded by the enclosed bytes.
@@ -62,55 +350,19 @@ impl NanBstr {
         self.0.data()
     }
 
+    /// Returns the bytes as lowercase hex, without a prefix.
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.as_bytes())
+    }
+
     /// Returns the sign bit (true if set).
     pub fn sign(&self) -> bool {
-        match self.width() {
-            NanWidth::Binary16 => {
-                (u16::from_be_bytes(self.0.data().try_into().unwrap()) >> 15)
-                    & 1
-                    == 1
-            }
-            NanWidth::Binary32 => {
-                (u32::from_be_bytes(self.0.data().try_into().unwrap()) >> 31)
-                    & 1
-                    == 1
-            }
-            NanWidth::Binary64 => {
-                (u64::from_be_bytes(self.0.data().try_into().unwrap()) >> 63)
-                    & 1
-                    == 1
-            }
-            NanWidth::Binary128 => {
-                (u128::from_be_bytes(self.0.data().try_into().unwrap()) >> 127)
-                    & 1
-                    == 1
-            }
-        }
+        (self.to_bits_u128() >> self.width().sign_bit_index()) & 1 == 1
     }
 
     /// Returns true if the quiet/signaling indicator bit is 1 (quiet NaN).
     pub fn is_quiet(&self) -> bool {
-        match self.width() {
-            NanWidth::Binary16 => {
-                (u16::from_be_bytes(self.0.data().try_into().unwrap()) >> 9) & 1
-                    == 1
-            }
-            NanWidth::Binary32 => {
-                (u32::from_be_bytes(self.0.data().try_into().unwrap()) >> 22)
-                    & 1
-                    == 1
-            }
-            NanWidth::Binary64 => {
-                (u64::from_be_bytes(self.0.data().try_into().unwrap()) >> 51)
-                    & 1
-                    == 1
-            }
-            NanWidth::Binary128 => {
-                (u128::from_be_bytes(self.0.data().try_into().unwrap()) >> 111)
-                    & 1
-                    == 1
-            }
-        }
+        self.to_bits_u128() & self.width().quiet_mask_u128() != 0
     }
 
     /// Returns true if the NaN is signaling (quiet bit == 0).
@@ -118,50 +370,369 @@ impl NanBstr {
         !self.is_quiet()
     }
 
+    /// Returns the raw biased exponent field, all ones for any NaN built by
+    /// the checked constructors.
+    pub fn exponent_bits(&self) -> u32 {
+        let width = self.width();
+        ((self.to_bits_u128() & width.exponent_mask_u128())
+            >> width.fraction_bits()) as u32
+    }
+
+    /// Returns the number of bits in the exponent field: 5, 8, 11, or 15.
+    pub fn exponent_field_width(&self) -> u32 {
+        self.width().exponent_bits()
+    }
+
     /// Returns the full significand/fraction field as bits (includes the
     /// quiet/signaling indicator bit in the MSB of the fraction field).
     pub fn fraction_bits(&self) -> u128 {
-        match self.width() {
-            NanWidth::Binary16 => {
-                (u16::from_be_bytes(self.0.data().try_into().unwrap()) & 0x03FF)
-                    as u128
-            } // 10 bits
-            NanWidth::Binary32 => {
-                (u32::from_be_bytes(self.0.data().try_into().unwrap())
-                    & 0x007F_FFFF) as u128
-            } // 23 bits
-            NanWidth::Binary64 => {
-                (u64::from_be_bytes(self.0.data().try_into().unwrap())
-                    & 0x000F_FFFF_FFFF_FFFF) as u128
-            } // 52 bits
-            NanWidth::Binary128 => {
-                let bits =
-                    u128::from_be_bytes(self.0.data().try_into().unwrap());
-                bits & ((1u128 << 112) - 1)
-            }
-        }
+        self.to_bits_u128() & self.width().fraction_mask_u128()
     }
 
     /// Returns the NaN payload bits excluding the quiet/signaling indicator
     /// bit (i.e., the remaining fraction bits beneath the MSB of the
     /// significand). This is the portion commonly treated as user payload.
     pub fn payload_bits(&self) -> u128 {
-        match self.width() {
-            NanWidth::Binary16 => self.fraction_bits() & ((1u128 << 9) - 1), /* 9 bits */
-            NanWidth::Binary32 => self.fraction_bits() & ((1u128 << 22) - 1), /* 22 bits */
-            NanWidth::Binary64 => self.fraction_bits() & ((1u128 << 51) - 1), /* 51 bits */
-            NanWidth::Binary128 => self.fraction_bits() & ((1u128 << 111) - 1), /* 111 bits */
+        self.fraction_bits() & self.width().max_payload()
+    }
+
+    /// Returns the payload as a `u64`. Never fails below binary128, whose
+    /// payloads fail with [`Error::PayloadOverflow`] above 64 bits.
+    pub fn payload_as_u64(&self) -> Result<u64> {
+        self.payload_as()
+    }
+
+    /// Returns the payload as a `u32`. Never fails for binary16 and
+    /// binary32; wider payloads fail with [`Error::PayloadOverflow`] above
+    /// 32 bits.
+    pub fn payload_as_u32(&self) -> Result<u32> {
+        self.payload_as()
+    }
+
+    /// Returns the payload as a `u16`. Never fails for binary16; wider
+    /// payloads fail with [`Error::PayloadOverflow`] above 16 bits.
+    pub fn payload_as_u16(&self) -> Result<u16> {
+        self.payload_as()
+    }
+
+    /// Returns the payload as big-endian bytes without leading zeros, so a
+    /// zero payload is empty. They read back through
+    /// [`NanBstrBuilder::payload_bytes`](crate::NanBstrBuilder::payload_bytes).
+    pub fn payload_bytes(&self) -> Vec<u8> {
+        let bytes = self.payload_bits().to_be_bytes();
+        let start = bytes.iter().position(|&b| b != 0).unwrap_or(16);
+        bytes[start..].to_vec()
+    }
+
+    /// Returns the payload as big-endian bytes, right-aligned in
+    /// [`NanWidth::payload_len`] bytes: 2 for binary16, 3 for binary32, 7
+    /// for binary64, and 14 for binary128. The unused high bits are zero.
+    pub fn payload_bytes_padded(&self) -> Vec<u8> {
+        let len = self.width().payload_len();
+        self.payload_bits().to_be_bytes()[16 - len..].to_vec()
+    }
+
+    /// Returns the [`payload_bytes`](Self::payload_bytes) as text if they
+    /// are all printable ASCII, such as a tag like `NA`. A zero payload has
+    /// no text and gives `None`.
+    pub fn payload_ascii(&self) -> Option<String> {
+        let bytes = self.payload_bytes();
+        let printable = bytes.iter().all(|&b| matches!(b, b' '..=b'~'));
+        (!bytes.is_empty() && printable)
+            .then(|| String::from_utf8(bytes).expect("ASCII is UTF-8"))
+    }
+
+    /// Returns the [`payload_bytes`](Self::payload_bytes) as text if they
+    /// are valid UTF-8, never substituting replacement characters. A zero
+    /// payload has no text and gives `None`.
+    pub fn payload_utf8(&self) -> Option<String> {
+        let bytes = self.payload_bytes();
+        if bytes.is_empty() {
+            return None;
         }
+        String::from_utf8(bytes).ok()
+    }
+
+    /// Returns the `n` most significant payload bits, right-aligned. An `n`
+    /// beyond the width's payload size saturates to the whole payload.
+    ///
+    /// Hardware narrowing keeps the top of the fraction, so payloads of
+    /// different widths compare by these prefixes rather than by value.
+    pub fn payload_top_bits(&self, n: u32) -> u128 {
+        let size = self.width().payload_bits();
+        self.payload_bits() >> (size - n.min(size))
+    }
+
+    /// Returns true if the shorter of the two payloads equals the top of the
+    /// longer, as when one NaN is the hardware-narrowed form of the other.
+    /// Sign and quiet bit are not compared.
+    pub fn payload_prefix_matches(&self, other: &NanBstr) -> bool {
+        let n = self
+            .width()
+            .payload_bits()
+            .min(other.width().payload_bits());
+        self.payload_top_bits(n) == other.payload_top_bits(n)
+    }
+
+    fn payload_as<T: TryFrom<u128>>(&self) -> Result<T> {
+        let payload = self.payload_bits();
+        T::try_from(payload).map_err(|_| Error::PayloadOverflow {
+            payload,
+            bits: size_of::<T>() as u32 * 8,
+        })
+    }
+
+    /// If the width is binary16, return the 16-bit bit pattern. For an
+    /// error naming the actual width, use `u16::try_from`.
+    pub fn to_binary16_bits(&self) -> Option<u16> {
+        u16::try_from(self).ok()
+    }
+
+    /// If the width is binary32, return the 32-bit bit pattern. For an
+    /// error naming the actual width, use `u32::try_from`.
+    pub fn to_binary32_bits(&self) -> Option<u32> {
+        u32::try_from(self).ok()
+    }
+
+    /// If the width is binary64, return the 64-bit bit pattern. For an
+    /// error naming the actual width, use `u64::try_from`.
+    pub fn to_binary64_bits(&self) -> Option<u64> {
+        u64::try_from(self).ok()
     }
 
     /// If the width is binary128, return the full 128-bit bit pattern.
     pub fn to_binary128_bits(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// If the width is binary128, return the pattern as `(high, low)`
+    /// words, in the order
+    /// [`from_binary128_words`](Self::from_binary128_words) takes them.
+    pub fn to_binary128_words(&self) -> Option<(u64, u64)> {
+        let bits = self.to_binary128_bits()?;
+        Some(((bits >> 64) as u64, bits as u64))
+    }
+
+    /// Returns the pattern as 64-bit words, most significant first, as
+    /// [`from_be_words`](Self::from_be_words) takes them. Returns `None` for
+    /// binary16 and binary32, which are narrower than a word.
+    pub fn to_be_words(&self) -> Option<Vec<u64>> {
         match self.width() {
-            NanWidth::Binary128 => {
-                Some(u128::from_be_bytes(self.0.data().try_into().unwrap()))
-            }
-            _ => None,
+            NanWidth::Binary16 | NanWidth::Binary32 => None,
+            _ => Some(
+                self.as_bytes()
+                    .chunks_exact(8)
+                    .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Returns the pattern as 32-bit words, most significant first, as
+    /// [`from_be_u32_words`](Self::from_be_u32_words) takes them. Returns
+    /// `None` for binary16, which is narrower than a word.
+    pub fn to_be_u32_words(&self) -> Option<Vec<u32>> {
+        match self.width() {
+            NanWidth::Binary16 => None,
+            _ => Some(
+                self.as_bytes()
+                    .chunks_exact(4)
+                    .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Returns the bit pattern zero-extended into a `u128`, whatever the
+    /// width. [`from_bits_u128`](Self::from_bits_u128) reverses it.
+    pub fn to_bits_u128(&self) -> u128 {
+        fold_bits(self.0.data())
+    }
+
+    /// Returns bit `index` of the big-endian pattern, where bit 0 is the
+    /// most significant (the sign), or `None` past the width.
+    pub fn bit(&self, index: u32) -> Option<bool> {
+        let byte = self.as_bytes().get(index as usize / 8)?;
+        Some((byte >> (7 - index % 8)) & 1 == 1)
+    }
+
+    /// Returns the bits of the pattern, most significant first, as
+    /// [`bit`](Self::bit) numbers them: 16, 32, 64, or 128 of them.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        self.as_bytes()
+            .iter()
+            .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+    }
+}
+
+// ─────────────────────────── Width Conversions ────────────────────────────
+
+impl NanBstr {
+    /// Converts to a NaN of the same or a wider `width`.
+    ///
+    /// The sign and quiet bit carry over, and the payload is placed in the
+    /// low bits of the wider fraction, so its numeric value is unchanged.
+    /// This never loses information. Fails with [`Error::WidthMismatch`] if
+    /// `width` is narrower than this NaN.
+    pub fn widen_to(&self, width: NanWidth) -> Result<Self> {
+        if width.len() < self.width().len() {
+            return Err(Error::WidthMismatch {
+                expected: width,
+                actual: self.width(),
+            });
+        }
+        Self::from_parts(
+            width,
+            self.sign(),
+            self.is_quiet(),
+            self.payload_bits(),
+        )
+    }
+
+    /// Converts to a NaN of the same or a narrower `width`, the inverse of
+    /// [`widen_to`](Self::widen_to).
+    ///
+    /// Fails with [`Error::PayloadTooLarge`] if the payload does not fit in
+    /// the narrower fraction, and with [`Error::WidthMismatch`] if `width`
+    /// is wider than this NaN.
+    pub fn narrow_to(&self, width: NanWidth) -> Result<Self> {
+        if width.len() > self.width().len() {
+            return Err(Error::WidthMismatch {
+                expected: width,
+                actual: self.width(),
+            });
+        }
+        Self::from_parts(
+            width,
+            self.sign(),
+            self.is_quiet(),
+            self.payload_bits(),
+        )
+    }
+
+    /// Converts a binary16, binary32, or binary64 NaN to an `f64`, widening
+    /// as [`widen_to`](Self::widen_to) does. Fails with
+    /// [`Error::WidthMismatch`] for binary128.
+    pub fn to_f64_checked(&self) -> Result<f64> {
+        if self.width() == NanWidth::Binary128 {
+            return Err(Error::WidthMismatch {
+                expected: NanWidth::Binary64,
+                actual: self.width(),
+            });
+        }
+        f64::try_from(self.widen_to(NanWidth::Binary64)?)
+    }
+
+    /// Converts a binary16 or binary32 NaN to an `f32`, widening as
+    /// [`widen_to`](Self::widen_to) does. Fails with
+    /// [`Error::WidthMismatch`] for wider NaNs.
+    pub fn to_f32_checked(&self) -> Result<f32> {
+        if self.width().len() > NanWidth::Binary32.len() {
+            return Err(Error::WidthMismatch {
+                expected: NanWidth::Binary32,
+                actual: self.width(),
+            });
         }
+        f32::try_from(self.widen_to(NanWidth::Binary32)?)
+    }
+
+    /// Captures a native `f64` NaN directly at `target` width, converting as
+    /// [`convert_width`](Self::convert_width) does.
+    ///
+    /// Fails with [`Error::NotANan`] if `value` is not a NaN, and with
+    /// [`Error::PayloadTooLarge`] if its payload does not fit `target` under
+    /// [`TruncationPolicy::Reject`].
+    pub fn from_f64_as(
+        value: f64,
+        target: NanWidth,
+        policy: TruncationPolicy,
+    ) -> Result<Self> {
+        Self::try_from(value)?.convert_width(target, policy)
+    }
+
+    /// Captures a native `f32` NaN directly at `target` width, as
+    /// [`from_f64_as`](Self::from_f64_as) does.
+    pub fn from_f32_as(
+        value: f32,
+        target: NanWidth,
+        policy: TruncationPolicy,
+    ) -> Result<Self> {
+        Self::try_from(value)?.convert_width(target, policy)
+    }
+
+    /// Returns true if this NaN converts to `width` without losing payload
+    /// bits.
+    pub fn fits_in(&self, width: NanWidth) -> bool {
+        width.fits_payload(self.payload_bits())
+    }
+
+    /// Converts to any `width`, applying `policy` if the payload does not fit.
+    ///
+    /// Widening is always lossless, as for [`widen_to`](Self::widen_to).
+    /// Narrowing keeps the sign and quiet bit and the payload's numeric
+    /// value when it fits; otherwise [`TruncationPolicy::Reject`] fails with
+    /// [`Error::PayloadTooLarge`] and [`TruncationPolicy::Truncate`] keeps
+    /// the low payload bits, making the NaN quiet if no payload bit survives
+    /// and it was signaling.
+    pub fn convert_width(
+        &self,
+        width: NanWidth,
+        policy: TruncationPolicy,
+    ) -> Result<Self> {
+        if self.fits_in(width) || policy == TruncationPolicy::Reject {
+            return Self::from_parts(
+                width,
+                self.sign(),
+                self.is_quiet(),
+                self.payload_bits(),
+            );
+        }
+        let payload = self.payload_bits() & width.max_payload();
+        let quiet = self.is_quiet() || payload == 0;
+        Self::from_parts(width, self.sign(), quiet, payload)
+    }
+
+    /// Construct a binary32 NaN from a bfloat16 NaN bit pattern (8 exponent
+    /// bits all ones, 7-bit fraction non-zero), which tag 102 cannot carry
+    /// at its own width.
+    ///
+    /// bfloat16 is the upper half of binary32, so the pattern is shifted
+    /// left by 16: the payload is left-aligned, unlike
+    /// [`widen_to`](Self::widen_to), and the low 16 bits are zero. This
+    /// never loses information, and the result is a NaN exactly when `bits`
+    /// is.
+    pub fn from_bfloat16_bits(bits: u16) -> Result<Self> {
+        Self::from_binary32_bits((bits as u32) << 16)
+    }
+
+    /// Returns the bfloat16 bit pattern of a binary32 NaN whose low 16 bits
+    /// are zero, the inverse of
+    /// [`from_bfloat16_bits`](Self::from_bfloat16_bits). Returns `None` for
+    /// other widths and when any low bit is set.
+    pub fn to_bfloat16_bits(&self) -> Option<u16> {
+        let bits = self.to_bits_u128();
+        (self.width() == NanWidth::Binary32 && bits & 0xFFFF == 0)
+            .then_some((bits >> 16) as u16)
+    }
+
+    /// Converts to an `f64` of any width, truncating a binary128 payload
+    /// that does not fit as [`TruncationPolicy::Truncate`] does: the low 51
+    /// payload bits are kept. Use [`fits_in`](Self::fits_in) to tell whether
+    /// the conversion lost bits.
+    pub fn to_f64_lossy(&self) -> f64 {
+        let nan = self
+            .convert_width(NanWidth::Binary64, TruncationPolicy::Truncate)
+            .expect("truncation always yields a NaN");
+        f64::try_from(nan).expect("converted to binary64")
+    }
+
+    /// Converts to an `f32` of any width, keeping the low 22 payload bits as
+    /// [`to_f64_lossy`](Self::to_f64_lossy) does.
+    pub fn to_f32_lossy(&self) -> f32 {
+        let nan = self
+            .convert_width(NanWidth::Binary32, TruncationPolicy::Truncate)
+            .expect("truncation always yields a NaN");
+        f32::try_from(nan).expect("converted to binary32")
     }
 }
 
@@ -179,11 +750,22 @@ impl CBORTaggedEncodable for NanBstr {
     }
 }
 
+// The owned and borrowed decoders check the item with the same functions,
+// `tagged_content` and `untagged_content`, so they fail identically; the owned
+// ones then take the content's buffer rather than copying it.
 impl CBORTaggedDecodable for NanBstr {
     fn from_untagged_cbor(cbor: CBOR) -> dcbor::Result<Self> {
-        let bs: ByteString =
-            cbor.try_into().map_err(|_| dcbor::Error::WrongType)?;
-        Ok(NanBstr::from_be_bytes(bs.data())?)
+        untagged_content(&cbor)?;
+        let bs: ByteString = cbor.try_into()?;
+        Ok(Self(bs))
+    }
+
+    fn from_tagged_cbor(cbor: CBOR) -> dcbor::Result<Self> {
+        tagged_content(&cbor)?;
+        match cbor.into_case() {
+            CBORCase::Tagged(_, item) => Self::from_untagged_cbor(item),
+            _ => unreachable!("the tag was checked"),
+        }
     }
 }
 
@@ -200,6 +782,60 @@ impl TryFrom<CBOR> for NanBstr {
     }
 }
 
+impl TryFrom<&CBOR> for NanBstr {
+    type Error = dcbor::Error;
+    fn try_from(cbor: &CBOR) -> dcbor::Result<Self> {
+        Self::from_cbor_ref(cbor)
+    }
+}
+
+impl NanBstr {
+    /// Decodes a tag-102 item without consuming it, copying only the
+    /// pattern. Fails exactly as `NanBstr::try_from(CBOR)` does.
+    pub fn from_cbor_ref(cbor: &CBOR) -> dcbor::Result<Self> {
+        Ok(Self(untagged_content(tagged_content(cbor)?)?.clone()))
+    }
+
+    /// Decodes a tag-102 item from dCBOR data, with no trailing data.
+    ///
+    /// Fails with `Error::Cbor` for malformed or non-deterministic CBOR,
+    /// trailing data (`UnusedData`), another tag (`WrongTag`), or content
+    /// that is not a byte string (`WrongType`), and with
+    /// `Error::InvalidLength` or `Error::NotANan` for a byte string that does
+    /// not hold a NaN.
+    pub fn from_cbor_data(data: impl AsRef<[u8]>) -> Result<Self> {
+        match MaybeNan::from_cbor_data(data)? {
+            MaybeNan::Valid(nan) => Ok(nan),
+            MaybeNan::Invalid { reason, .. } => Err(reason),
+        }
+    }
+
+    /// Returns the deterministic encoding of the tag-102 item: the tag head,
+    /// the byte string head, and the pattern.
+    pub fn to_cbor_data(&self) -> Vec<u8> {
+        let pattern = self.as_bytes();
+        let mut data = Vec::with_capacity(self.encoded_len());
+        data.extend_from_slice(&TAG_HEAD);
+        data.push(0x40 | pattern.len() as u8);
+        data.extend_from_slice(pattern);
+        data
+    }
+
+    /// Returns the length of [`NanBstr::to_cbor_data`]: 5, 7, 11, or 19
+    /// bytes.
+    pub fn encoded_len(&self) -> usize {
+        TAG_HEAD.len() + 1 + self.0.len()
+    }
+
+    /// Returns the size of the encoded tag-102 item, computed without
+    /// encoding it: the tag head, the byte-string head, and the pattern.
+    /// This is [`encoded_len`](Self::encoded_len) under the name used for
+    /// frame packing.
+    pub fn encoded_cbor_size(&self) -> usize {
+        self.encoded_len()
+    }
+}
+
 // ──────────────────────── f32/f64 Conversions ───────────────────────────────
 
 impl TryFrom<f32> for NanBstr {
@@ -215,11 +851,7 @@ impl TryFrom<f32> for NanBstr {
 impl TryFrom<NanBstr> for f32 {
     type Error = Error;
     fn try_from(value: NanBstr) -> Result<Self> {
-        if value.width() != NanWidth::Binary32 {
-            return Err(Error::InvalidLength(value.0.len()));
-        }
-        let bits = u32::from_be_bytes(value.0.data().try_into().unwrap());
-        Ok(f32::from_bits(bits))
+        u32::try_from(&value).map(f32::from_bits)
     }
 }
 
@@ -236,69 +868,329 @@ impl TryFrom<f64> for NanBstr {
 impl TryFrom<NanBstr> for f64 {
     type Error = Error;
     fn try_from(value: NanBstr) -> Result<Self> {
-        if value.width() != NanWidth::Binary64 {
-            return Err(Error::InvalidLength(value.0.len()));
+        u64::try_from(&value).map(f64::from_bits)
+    }
+}
+
+// ────────────────────────── Integer Conversions ────────────────────────────
+
+macro_rules! impl_bits_conversions {
+    ($bits:ty, $width:ident, $from:ident) => {
+        impl TryFrom<$bits> for NanBstr {
+            type Error = Error;
+            fn try_from(bits: $bits) -> Result<Self> {
+                Self::$from(bits)
+            }
+        }
+
+        impl TryFrom<&NanBstr> for $bits {
+            type Error = Error;
+            fn try_from(value: &NanBstr) -> Result<Self> {
+                if value.width() != NanWidth::$width {
+                    return Err(Error::WidthMismatch {
+                        expected: NanWidth::$width,
+                        actual: value.width(),
+                    });
+                }
+                Ok(<$bits>::from_be_bytes(value.0.data().try_into().unwrap()))
+            }
+        }
+
+        impl TryFrom<NanBstr> for $bits {
+            type Error = Error;
+            fn try_from(value: NanBstr) -> Result<Self> {
+                Self::try_from(&value)
+            }
         }
-        let bits = u64::from_be_bytes(value.0.data().try_into().unwrap());
-        Ok(f64::from_bits(bits))
+    };
+}
+
+impl_bits_conversions!(u16, Binary16, from_binary16_bits);
+impl_bits_conversions!(u32, Binary32, from_binary32_bits);
+impl_bits_conversions!(u64, Binary64, from_binary64_bits);
+impl_bits_conversions!(u128, Binary128, from_binary128_bits);
+
+// ─────────────────────────── Array Conversions ─────────────────────────────
+
+macro_rules! impl_array_conversions {
+    ($len:literal, $width:ident) => {
+        /// The width is implied by the array length, so only the NaN
+        /// pattern is validated.
+        impl TryFrom<[u8; $len]> for NanBstr {
+            type Error = Error;
+            fn try_from(bytes: [u8; $len]) -> Result<Self> {
+                Self::try_from(&bytes)
+            }
+        }
+
+        impl TryFrom<&[u8; $len]> for NanBstr {
+            type Error = Error;
+            fn try_from(bytes: &[u8; $len]) -> Result<Self> {
+                if !is_nan_bits(NanWidth::$width, bytes) {
+                    return Err(Error::NotANan);
+                }
+                Ok(Self(ByteString::from(bytes.as_slice())))
+            }
+        }
+    };
+}
+
+impl_array_conversions!(2, Binary16);
+impl_array_conversions!(4, Binary32);
+impl_array_conversions!(8, Binary64);
+impl_array_conversions!(16, Binary128);
+
+// ───────────────────────── Byte String Conversions ─────────────────────────
+
+/// Takes ownership of the buffer; the bytes are validated in place.
+impl TryFrom<ByteString> for NanBstr {
+    type Error = Error;
+    fn try_from(bytes: ByteString) -> Result<Self> {
+        Self::from_byte_string(bytes)
+    }
+}
+
+/// Takes ownership of the buffer; the bytes are validated in place.
+impl TryFrom<Vec<u8>> for NanBstr {
+    type Error = Error;
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        Self::from_byte_string(ByteString::from(bytes))
+    }
+}
+
+// ───────────────────────────────── Default ──────────────────────────────────
+
+/// The positive quiet binary64 NaN with a zero payload,
+/// `0x7FF8_0000_0000_0000`, which `f64::NAN` is on most platforms. This
+/// pattern is part of the API and will not change.
+impl Default for NanBstr {
+    fn default() -> Self {
+        Self::canonical_quiet(NanWidth::Binary64)
     }
 }
 
 // ───────────────────────────────── Display ──────────────────────────────────
 
+/// Formats the decoded fields and the pattern, as in
+/// `NanBstr { width: Binary32, sign: false, quiet: true, payload: 0x1,
+/// bytes: "7fc00001" }`.
+impl fmt::Debug for NanBstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_nan_ref().fmt_debug("NanBstr", f)
+    }
+}
+
+/// Formats the fields, as in
+/// `NaN[binary64]: + quiet frac=0x8000000000123 payload=0x123`. The
+/// alternate flag, `{:#}`, appends the big-endian pattern, as in
+/// `... payload=0x123 (0x7ff8000000000123)`. Both forms are stable, and
+/// both parse back through [`FromStr`].
 impl fmt::Display for NanBstr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (w, s, q, frac, payload) = (
-            self.width(),
-            self.sign(),
-            self.is_quiet(),
-            self.fraction_bits(),
-            self.payload_bits(),
-        );
-        let wbits = w.len() * 8;
-        write!(
-            f,
-            "NaN[{}]: {} {} frac=0x{:x} payload=0x{:x}",
-            wbits,
-            if s { "-" } else { "+" },
-            if q { "quiet" } else { "signaling" },
-            frac,
-            payload,
-        )
+        self.describe().fmt(f)
     }
 }
 
-// ────────────────────────────── Internals ───────────────────────────────────
+/// Formats the big-endian pattern as 4, 8, 16, or 32 hex digits, such as
+/// `7fc00001`. The alternate flag adds a `0x` prefix, and width, fill, and
+/// zero-padding apply as for integers.
+impl fmt::LowerHex for NanBstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_hex())
+    }
+}
 
-fn is_nan_bits(width: NanWidth, be_bytes: &[u8]) -> bool {
-    match width {
-        NanWidth::Binary16 => {
-            let b = <[u8; 2]>::try_from(be_bytes).unwrap();
-            let bits = u16::from_be_bytes(b);
-            let exp = (bits >> 10) & 0x1F; // 5 exponent bits all ones = 0x1F
-            let frac = bits & 0x03FF; // 10 fraction bits
-            exp == 0x1F && frac != 0
+/// Formats as [`LowerHex`](fmt::LowerHex) does, with uppercase digits.
+impl fmt::UpperHex for NanBstr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_hex().to_ascii_uppercase())
+    }
+}
+
+// ───────────────────────────────── FromStr ──────────────────────────────────
+
+/// Parses the [`Display`](fmt::Display) form, such as
+/// `NaN[binary32]: + quiet frac=0x400001 payload=0x1`. The width may take
+/// any form [`NanWidth`] parses, so `NaN[32]:` is also accepted. The `frac`
+/// field may be left out, since the quiet keyword and the payload determine
+/// it; if given, it must agree with them. So must the pattern that the
+/// alternate form appends.
+impl FromStr for NanBstr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut tokens = s.split_whitespace().map(|token| {
+            (token.as_ptr() as usize - s.as_ptr() as usize, token)
+        });
+        let mut next = |expected: &str| {
+            tokens.next().ok_or_else(|| Error::InvalidNanText {
+                offset: s.len(),
+                reason: format!("expected {}", expected),
+            })
+        };
+        let invalid = |offset: usize, reason: String| Error::InvalidNanText {
+            offset,
+            reason,
+        };
+
+        let (offset, token) = next("`NaN[<width>]:`")?;
+        let width = match token
+            .strip_prefix("NaN[")
+            .and_then(|rest| rest.strip_suffix("]:"))
+            .and_then(|width| width.parse::<NanWidth>().ok())
+        {
+            Some(width) => width,
+            None => {
+                return Err(invalid(
+                    offset,
+                    format!(
+                        "expected `NaN[<width>]:` with a width of binary16, \
+                         binary32, binary64, or binary128, got `{}`",
+                        token
+                    ),
+                ));
+            }
+        };
+
+        let (offset, token) = next("a sign")?;
+        let builder = match token {
+            "+" => NanBstr::builder(width),
+            "-" => NanBstr::builder(width).negative(),
+            _ => {
+                return Err(invalid(
+                    offset,
+                    format!("expected `+` or `-`, got `{}`", token),
+                ));
+            }
+        };
+
+        let (offset, token) = next("`quiet` or `signaling`")?;
+        let builder = match token {
+            "quiet" => builder,
+            "signaling" => builder.signaling(),
+            _ => {
+                return Err(invalid(
+                    offset,
+                    format!("expected `quiet` or `signaling`, got `{}`", token),
+                ));
+            }
+        };
+
+        let field = |(offset, token): (usize, &str), name: &str| {
+            token
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix("=0x"))
+                .and_then(|digits| u128::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| {
+                    invalid(
+                        offset,
+                        format!("expected `{}=0x<hex>`, got `{}`", name, token),
+                    )
+                })
+        };
+        let mut token = next("`payload=0x<hex>`")?;
+        let frac = if token.1.starts_with("frac=") {
+            let frac = (token.0, field(token, "frac")?);
+            token = next("`payload=0x<hex>`")?;
+            Some(frac)
+        } else {
+            None
+        };
+        let payload = field(token, "payload")?;
+        let pattern = match tokens.next() {
+            Some((offset, token)) => {
+                let hex = token
+                    .strip_prefix("(0x")
+                    .and_then(|rest| rest.strip_suffix(')'));
+                match hex {
+                    Some(hex) => Some((offset, token, hex)),
+                    None => {
+                        return Err(invalid(
+                            offset,
+                            "unexpected trailing text".to_string(),
+                        ));
+                    }
+                }
+            }
+            None => None,
+        };
+        if let Some((offset, _)) = tokens.next() {
+            return Err(invalid(
+                offset,
+                "unexpected trailing text".to_string(),
+            ));
         }
-        NanWidth::Binary32 => {
-            let b = <[u8; 4]>::try_from(be_bytes).unwrap();
-            let bits = u32::from_be_bytes(b);
-            let exp = (bits >> 23) & 0xFF; // 8 exponent bits all ones = 0xFF
-            let frac = bits & 0x007F_FFFF; // 23 fraction bits
-            exp == 0xFF && frac != 0
+
+        let nan = builder.payload(payload).build()?;
+        if let Some((offset, token, hex)) = pattern
+            && hex != nan.to_hex()
+        {
+            return Err(invalid(
+                offset,
+                format!(
+                    "pattern `{}` disagrees with the fields, which give \
+                     `(0x{})`",
+                    token,
+                    nan.to_hex()
+                ),
+            ));
         }
-        NanWidth::Binary64 => {
-            let b = <[u8; 8]>::try_from(be_bytes).unwrap();
-            let bits = u64::from_be_bytes(b);
-            let exp = (bits >> 52) & 0x7FF; // 11 exponent bits all ones = 0x7FF
-            let frac = bits & 0x000F_FFFF_FFFF_FFFF; // 52 fraction bits
-            exp == 0x7FF && frac != 0
+        if let Some((offset, frac)) = frac
+            && frac != nan.fraction_bits()
+        {
+            return Err(invalid(
+                offset,
+                format!(
+                    "frac=0x{:x} disagrees with the quiet bit and payload, \
+                     which give frac=0x{:x}",
+                    frac,
+                    nan.fraction_bits()
+                ),
+            ));
         }
-        NanWidth::Binary128 => {
-            let b = <[u8; 16]>::try_from(be_bytes).unwrap();
-            let bits = u128::from_be_bytes(b);
-            let exp = (bits >> 112) & 0x7FFF; // 15 exponent bits all ones = 0x7FFF
-            let frac = bits & ((1u128 << 112) - 1); // 112 fraction bits
-            exp == 0x7FFF && frac != 0
+        Ok(nan)
+    }
+}
+
+// ────────────────────────────── Internals ───────────────────────────────────
+
+/// Returns the content of `cbor` if it is a tag-102 item.
+fn tagged_content(cbor: &CBOR) -> dcbor::Result<&CBOR> {
+    match cbor.as_case() {
+        CBORCase::Tagged(tag, item) if tag.value() == bc_tags::TAG_NAN_BSTR => {
+            Ok(item)
         }
+        CBORCase::Tagged(tag, _) => Err(dcbor::Error::WrongTag(
+            Tag::with_value(bc_tags::TAG_NAN_BSTR),
+            tag.clone(),
+        )),
+        _ => Err(dcbor::Error::WrongType),
+    }
+}
+
+/// Returns `cbor` as a byte string if it is one holding a NaN.
+fn untagged_content(cbor: &CBOR) -> dcbor::Result<&ByteString> {
+    let CBORCase::ByteString(bytes) = cbor.as_case() else {
+        return Err(dcbor::Error::WrongType);
+    };
+    check_nan_bytes(bytes.data())?;
+    Ok(bytes)
+}
+
+fn check_nan_bytes(be_bytes: &[u8]) -> Result<()> {
+    let width = NanWidth::from_len(be_bytes.len())?;
+    if !is_nan_bits(width, be_bytes) {
+        return Err(Error::NotANan);
     }
+    Ok(())
+}
+
+fn is_nan_bits(width: NanWidth, be_bytes: &[u8]) -> bool {
+    debug_assert_eq!(be_bytes.len(), width.len());
+    width.is_nan_bits(fold_bits(be_bytes))
+}
+
+/// Returns big-endian bytes as a right-aligned `u128`.
+pub(crate) fn fold_bits(be_bytes: &[u8]) -> u128 {
+    be_bytes.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128)
 }
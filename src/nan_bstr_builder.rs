@@ -0,0 +1,161 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+
+use crate::{Error, NanBstr, NanWidth, Result, nan_bstr::fold_bits};
+
+/// Builds a [`NanBstr`] field by field, starting from the positive quiet
+/// NaN with a zero payload.
+///
+/// ```
+/// use cbor_nan_bstr::{NanBstr, NanWidth};
+///
+/// let nan = NanBstr::builder(NanWidth::Binary32)
+///     .negative()
+///     .signaling()
+///     .payload(0x1234)
+///     .build()
+///     .unwrap();
+/// assert_eq!(nan, NanBstr::from_binary32_bits(0xFF80_1234).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NanBstrBuilder {
+    width: NanWidth,
+    negative: bool,
+    quiet: bool,
+    payload: Payload,
+}
+
+/// The payload as given, checked by [`NanBstrBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Payload {
+    Bits(u128),
+    Bytes(Vec<u8>),
+    Hex(String),
+}
+
+impl NanBstr {
+    /// Returns a builder for a NaN of `width`.
+    pub fn builder(width: NanWidth) -> NanBstrBuilder {
+        NanBstrBuilder::new(width)
+    }
+}
+
+impl NanBstrBuilder {
+    /// Returns a builder for the positive quiet NaN of `width` with a zero
+    /// payload.
+    pub fn new(width: NanWidth) -> Self {
+        Self {
+            width,
+            negative: false,
+            quiet: true,
+            payload: Payload::Bits(0),
+        }
+    }
+
+    /// Sets the sign bit.
+    pub fn negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
+
+    /// Clears the sign bit.
+    pub fn positive(mut self) -> Self {
+        self.negative = false;
+        self
+    }
+
+    /// Sets the quiet bit.
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Clears the quiet bit. A signaling NaN needs a non-zero payload.
+    pub fn signaling(mut self) -> Self {
+        self.quiet = false;
+        self
+    }
+
+    /// Sets the payload, the fraction bits beneath the quiet bit.
+    pub fn payload(mut self, payload: u128) -> Self {
+        self.payload = Payload::Bits(payload);
+        self
+    }
+
+    /// Sets the payload from big-endian bytes, which may have leading
+    /// zeros.
+    pub fn payload_bytes(mut self, bytes: &[u8]) -> Self {
+        self.payload = Payload::Bytes(bytes.to_vec());
+        self
+    }
+
+    /// Sets the payload from a hex number, as written in specifications:
+    /// an optional `0x` prefix, then digits that may be separated by spaces
+    /// or underscores.
+    pub fn payload_hex(mut self, hex: &str) -> Self {
+        self.payload = Payload::Hex(hex.to_string());
+        self
+    }
+
+    /// Builds the NaN.
+    ///
+    /// Fails with [`Error::InvalidPayload`] if the payload bytes or hex
+    /// cannot be read as a number, [`Error::PayloadTooLarge`] if the payload
+    /// does not fit the width, and [`Error::SignalingZeroPayload`] for a
+    /// signaling NaN with a zero payload, which would be an infinity.
+    pub fn build(&self) -> Result<NanBstr> {
+        let payload = self.payload.resolve()?;
+        if payload > self.width.max_payload() {
+            return Err(Error::PayloadTooLarge {
+                width: self.width,
+                payload,
+            });
+        }
+        if !self.quiet && payload == 0 {
+            return Err(Error::SignalingZeroPayload(self.width));
+        }
+        NanBstr::from_parts(self.width, self.negative, self.quiet, payload)
+    }
+}
+
+impl Payload {
+    fn resolve(&self) -> Result<u128> {
+        match self {
+            Self::Bits(bits) => Ok(*bits),
+            Self::Bytes(bytes) => {
+                let start = bytes.iter().position(|&b| b != 0);
+                let significant = &bytes[start.unwrap_or(bytes.len())..];
+                if significant.len() > 16 {
+                    return Err(Error::InvalidPayload(format!(
+                        "{} significant bytes exceed 128 bits",
+                        significant.len()
+                    )));
+                }
+                Ok(fold_bits(significant))
+            }
+            Self::Hex(hex) => {
+                let digits: String = hex
+                    .trim()
+                    .trim_start_matches("0x")
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && *c != '_')
+                    .collect();
+                let significant = digits.trim_start_matches('0');
+                if digits.is_empty()
+                    || !digits.chars().all(|c| c.is_ascii_hexdigit())
+                {
+                    return Err(Error::InvalidPayload(format!(
+                        "`{}` is not a hex number",
+                        hex
+                    )));
+                }
+                if significant.len() > 32 {
+                    return Err(Error::InvalidPayload(format!(
+                        "`{}` exceeds 128 bits",
+                        hex
+                    )));
+                }
+                Ok(u128::from_str_radix(significant, 16).unwrap_or(0))
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use core::fmt;
+
+use dcbor::prelude::*;
+
+use crate::{Error, NanBstr, NanWidth, Result, nan_bstr::fold_bits};
+
+/// A borrowed, validated view of a NaN bit pattern: the zero-copy
+/// counterpart of [`NanBstr`].
+///
+/// The bytes are big-endian and are validated once, at construction. The
+/// view cannot outlive the bytes it borrows:
+///
+/// ```compile_fail
+/// use cbor_nan_bstr::NanBstrRef;
+///
+/// let nan = {
+///     let bytes = vec![0x7E, 0x00];
+///     NanBstrRef::new(&bytes).unwrap()
+/// };
+/// println!("{}", nan);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NanBstrRef<'a>(&'a [u8]);
+
+impl<'a> NanBstrRef<'a> {
+    /// Borrows a big-endian byte slice (length 2, 4, 8, or 16), validating
+    /// that it encodes a NaN of the corresponding width.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let width = NanWidth::from_len(bytes.len())?;
+        if !width.is_nan_bits(fold_bits(bytes)) {
+            return Err(Error::NotANan);
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Borrows the content of a tag-102 item without copying it.
+    pub fn from_cbor(cbor: &'a CBOR) -> Result<Self> {
+        match cbor.as_case() {
+            CBORCase::Tagged(tag, content)
+                if tag.value() == bc_tags::TAG_NAN_BSTR =>
+            {
+                Self::from_untagged_cbor(content)
+            }
+            CBORCase::Tagged(tag, _) => Err(dcbor::Error::WrongTag(
+                Tag::with_value(bc_tags::TAG_NAN_BSTR),
+                tag.clone(),
+            )
+            .into()),
+            _ => Err(dcbor::Error::WrongType.into()),
+        }
+    }
+
+    /// Borrows a byte string that is the content of a tag-102 item.
+    pub(crate) fn from_untagged_cbor(content: &'a CBOR) -> Result<Self> {
+        match content.as_case() {
+            CBORCase::ByteString(bytes) => Self::new(bytes.data()),
+            _ => Err(dcbor::Error::WrongType.into()),
+        }
+    }
+
+    /// Returns the width (binary16/32/64/128) encoded by the bytes.
+    pub fn width(&self) -> NanWidth {
+        NanWidth::from_len(self.0.len()).unwrap()
+    }
+
+    /// Returns the borrowed bytes in big‑endian order.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the sign bit (true if set).
+    pub fn sign(&self) -> bool {
+        self.0[0] & 0x80 != 0
+    }
+
+    /// Returns true if the quiet/signaling indicator bit is 1 (quiet NaN).
+    pub fn is_quiet(&self) -> bool {
+        fold_bits(self.0) & self.width().quiet_mask_u128() != 0
+    }
+
+    /// Returns true if the NaN is signaling (quiet bit == 0).
+    pub fn is_signaling(&self) -> bool {
+        !self.is_quiet()
+    }
+
+    /// Returns the full significand/fraction field as bits (includes the
+    /// quiet/signaling indicator bit in the MSB of the fraction field).
+    pub fn fraction_bits(&self) -> u128 {
+        fold_bits(self.0) & self.width().fraction_mask_u128()
+    }
+
+    /// Returns the NaN payload bits excluding the quiet/signaling indicator
+    /// bit.
+    pub fn payload_bits(&self) -> u128 {
+        self.fraction_bits() & self.width().max_payload()
+    }
+
+    /// Copies the bytes into an owned [`NanBstr`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn to_owned(&self) -> NanBstr {
+        NanBstr::from_validated(*self)
+    }
+
+    /// Formats the decoded fields and the pattern as a struct called `name`,
+    /// the `Debug` form of both this view and [`NanBstr`].
+    pub(crate) fn fmt_debug(
+        &self,
+        name: &str,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct(name)
+            .field("width", &self.width())
+            .field("sign", &self.sign())
+            .field("quiet", &self.is_quiet())
+            .field("payload", &format_args!("0x{:x}", self.payload_bits()))
+            .field("bytes", &HexBytes(self.0))
+            .finish()
+    }
+}
+
+impl NanBstr {
+    /// Returns a borrowed view of this NaN.
+    pub fn as_nan_ref(&self) -> NanBstrRef<'_> {
+        NanBstrRef(self.as_bytes())
+    }
+}
+
+impl From<NanBstrRef<'_>> for NanBstr {
+    fn from(nan: NanBstrRef<'_>) -> Self {
+        nan.to_owned()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for NanBstrRef<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::new(bytes)
+    }
+}
+
+impl PartialEq<NanBstr> for NanBstrRef<'_> {
+    fn eq(&self, other: &NanBstr) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<NanBstrRef<'_>> for NanBstr {
+    fn eq(&self, other: &NanBstrRef<'_>) -> bool {
+        self.as_bytes() == other.0
+    }
+}
+
+/// Formats as [`NanBstr`]'s `Debug` does, as in
+/// `NanBstrRef { width: Binary32, sign: false, quiet: true, payload: 0x1,
+/// bytes: "7fc00001" }`.
+impl fmt::Debug for NanBstrRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_debug("NanBstrRef", f)
+    }
+}
+
+impl fmt::Display for NanBstrRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.describe().fmt(f)
+    }
+}
+
+/// Debug-formats bytes as a quoted hex string without allocating.
+struct HexBytes<'a>(&'a [u8]);
+
+impl fmt::Debug for HexBytes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        f.write_str("\"")
+    }
+}
@@ -0,0 +1,198 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+const WORDS: usize = (1 << 16) / 64;
+
+/// A set of binary16 NaN bit patterns, backed by an 8 KiB bitset over the
+/// entire `u16` space.
+///
+/// Membership tests are O(1), which makes this suitable as an allowlist on
+/// hot ingest paths. Only binary16 NaNs can be members; wider NaNs are never
+/// contained.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NanBstrSet16 {
+    words: Box<[u64; WORDS]>,
+}
+
+impl NanBstrSet16 {
+    /// Returns an empty set.
+    pub fn new() -> Self {
+        Self {
+            words: Box::new([0; WORDS]),
+        }
+    }
+
+    /// Inserts a binary16 NaN, returning true if it was not already present.
+    ///
+    /// Returns `Error::WidthMismatch` if `nan` is not binary16.
+    pub fn insert(&mut self, nan: &NanBstr) -> Result<bool> {
+        let bits = binary16_bits(nan)?;
+        let (word, mask) = locate(bits);
+        let inserted = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        Ok(inserted)
+    }
+
+    /// Removes a NaN, returning true if it was present.
+    pub fn remove(&mut self, nan: &NanBstr) -> bool {
+        let Ok(bits) = binary16_bits(nan) else {
+            return false;
+        };
+        let (word, mask) = locate(bits);
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// Returns true if `nan` is a member. Always false for widths other than
+    /// binary16.
+    pub fn contains(&self, nan: &NanBstr) -> bool {
+        match binary16_bits(nan) {
+            Ok(bits) => {
+                let (word, mask) = locate(bits);
+                self.words[word] & mask != 0
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns true if the set has no members.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns the set of NaNs present in `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Returns the set of NaNs present in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Returns an iterator over the members in ascending order of their
+    /// bit patterns.
+    pub fn iter(&self) -> NanBstrSet16Iter<'_> {
+        NanBstrSet16Iter {
+            words: &self.words,
+            index: 0,
+            current: self.words[0],
+        }
+    }
+
+    /// Builds a set from binary16 NaNs, failing on the first NaN of any other
+    /// width.
+    pub fn try_from_iter<'a>(
+        iter: impl IntoIterator<Item = &'a NanBstr>,
+    ) -> Result<Self> {
+        let mut set = Self::new();
+        for nan in iter {
+            set.insert(nan)?;
+        }
+        Ok(set)
+    }
+
+    fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let mut result = Self::new();
+        for (i, word) in result.words.iter_mut().enumerate() {
+            *word = op(self.words[i], other.words[i]);
+        }
+        result
+    }
+}
+
+impl Default for NanBstrSet16 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NanBstrSet16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|nan| hex::encode(nan.as_bytes())))
+            .finish()
+    }
+}
+
+/// Collects binary16 NaNs into a set. NaNs of other widths can never be
+/// members and are skipped; use [`NanBstrSet16::try_from_iter`] to reject
+/// them instead.
+impl FromIterator<NanBstr> for NanBstrSet16 {
+    fn from_iter<I: IntoIterator<Item = NanBstr>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for nan in iter {
+            let _ = set.insert(&nan);
+        }
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a NanBstr> for NanBstrSet16 {
+    fn from_iter<I: IntoIterator<Item = &'a NanBstr>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for nan in iter {
+            let _ = set.insert(nan);
+        }
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a NanBstrSet16 {
+    type Item = NanBstr;
+    type IntoIter = NanBstrSet16Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the members of a [`NanBstrSet16`], in ascending order of
+/// their bit patterns.
+pub struct NanBstrSet16Iter<'a> {
+    words: &'a [u64; WORDS],
+    index: usize,
+    current: u64,
+}
+
+impl Iterator for NanBstrSet16Iter<'_> {
+    type Item = NanBstr;
+
+    fn next(&mut self) -> Option<NanBstr> {
+        while self.current == 0 {
+            self.index += 1;
+            if self.index >= WORDS {
+                return None;
+            }
+            self.current = self.words[self.index];
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        let bits = (self.index * 64 + bit) as u16;
+        Some(NanBstr::from_binary16_bits(bits).unwrap())
+    }
+}
+
+fn binary16_bits(nan: &NanBstr) -> Result<u16> {
+    match nan.width() {
+        NanWidth::Binary16 => {
+            Ok(u16::from_be_bytes(nan.as_bytes().try_into().unwrap()))
+        }
+        actual => Err(Error::WidthMismatch {
+            expected: NanWidth::Binary16,
+            actual,
+        }),
+    }
+}
+
+fn locate(bits: u16) -> (usize, u64) {
+    ((bits as usize) / 64, 1u64 << (bits % 64))
+}
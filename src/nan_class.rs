@@ -0,0 +1,38 @@
+use core::fmt;
+
+use crate::NanBstr;
+
+/// The kind of a NaN, for exhaustive `match`es in policy code.
+///
+/// A signaling NaN always has a non-zero payload, since a zero one would be
+/// an infinity. Displays as `quiet`, `quiet with payload`, or `signaling`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NanClass {
+    /// A quiet NaN with a zero payload, such as the canonical quiet NaN.
+    QuietZeroPayload,
+    /// A quiet NaN with a non-zero payload.
+    QuietWithPayload,
+    /// A signaling NaN.
+    Signaling,
+}
+
+impl NanBstr {
+    /// Returns the kind of this NaN; the sign is not considered.
+    pub fn classify(&self) -> NanClass {
+        match (self.is_quiet(), self.payload_bits()) {
+            (false, _) => NanClass::Signaling,
+            (true, 0) => NanClass::QuietZeroPayload,
+            (true, _) => NanClass::QuietWithPayload,
+        }
+    }
+}
+
+impl fmt::Display for NanClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::QuietZeroPayload => "quiet",
+            Self::QuietWithPayload => "quiet with payload",
+            Self::Signaling => "signaling",
+        })
+    }
+}
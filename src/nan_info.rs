@@ -0,0 +1,158 @@
+use core::fmt;
+
+use crate::{NanBstr, NanBstrRef, NanWidth};
+#[cfg(feature = "serde")]
+use crate::{Error, Result};
+
+/// Every decoded field of a [`NanBstr`] in one value, for structured
+/// logging.
+///
+/// `NanInfo` is `Copy` and owns no heap data. With the `serde` feature it
+/// serializes as its [`NanFields`], the crate's one structured form of a
+/// NaN. Displays as the [`NanBstr`] does, with or without the alternate
+/// flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "NanFields", try_from = "NanFields")
+)]
+pub struct NanInfo {
+    /// The width.
+    pub width: NanWidth,
+    /// The sign bit.
+    pub sign: bool,
+    /// The quiet bit.
+    pub quiet: bool,
+    /// The fraction field, quiet bit included.
+    pub fraction: u128,
+    /// The payload, the fraction bits beneath the quiet bit.
+    pub payload: u128,
+    /// The big-endian pattern, right-aligned and zero-padded, as in
+    /// [`PackedNanBstr`](crate::PackedNanBstr).
+    pub be_bytes: [u8; 16],
+}
+
+impl NanInfo {
+    /// Returns the big-endian pattern, without padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.be_bytes[16 - self.width.len()..]
+    }
+}
+
+impl NanBstr {
+    /// Returns every decoded field at once.
+    pub fn describe(&self) -> NanInfo {
+        NanInfo::from(self)
+    }
+}
+
+impl NanBstrRef<'_> {
+    /// Returns every decoded field at once.
+    pub fn describe(&self) -> NanInfo {
+        NanInfo::from(*self)
+    }
+}
+
+impl From<&NanBstr> for NanInfo {
+    fn from(nan: &NanBstr) -> Self {
+        nan.as_nan_ref().into()
+    }
+}
+
+impl From<NanBstrRef<'_>> for NanInfo {
+    fn from(nan: NanBstrRef<'_>) -> Self {
+        let data = nan.as_bytes();
+        let mut be_bytes = [0u8; 16];
+        be_bytes[16 - data.len()..].copy_from_slice(data);
+        Self {
+            width: nan.width(),
+            sign: nan.sign(),
+            quiet: nan.is_quiet(),
+            fraction: nan.fraction_bits(),
+            payload: nan.payload_bits(),
+            be_bytes,
+        }
+    }
+}
+
+impl fmt::Display for NanInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NaN[{}]: {} {} frac=0x{:x} payload=0x{:x}",
+            self.width,
+            if self.sign { "-" } else { "+" },
+            if self.quiet { "quiet" } else { "signaling" },
+            self.fraction,
+            self.payload,
+        )?;
+        if f.alternate() {
+            write!(f, " (0x{})", hex::encode(self.as_bytes()))?;
+        }
+        Ok(())
+    }
+}
+
+/// The serialized form of a [`NanInfo`]: its fields, with the wide integers
+/// as hex strings such as `"0x2a"`, since they may exceed JSON's safe
+/// integers, and the unpadded pattern in hex.
+///
+/// This is the one structured form of a NaN that the crate serializes: the
+/// audit report's JSON and the `Object` mode of `project_to_json` both use
+/// it.
+#[cfg(feature = "serde")]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NanFields {
+    pub width: NanWidth,
+    pub sign: bool,
+    pub quiet: bool,
+    /// The fraction field, quiet bit included, as a hex string.
+    pub fraction: String,
+    /// The payload, excluding the quiet bit, as a hex string.
+    pub payload: String,
+    /// The pattern in hex.
+    pub bytes: String,
+}
+
+#[cfg(feature = "serde")]
+impl From<NanInfo> for NanFields {
+    fn from(info: NanInfo) -> Self {
+        Self {
+            width: info.width,
+            sign: info.sign,
+            quiet: info.quiet,
+            fraction: format!("0x{:x}", info.fraction),
+            payload: format!("0x{:x}", info.payload),
+            bytes: hex::encode(info.as_bytes()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<&NanBstr> for NanFields {
+    fn from(nan: &NanBstr) -> Self {
+        nan.describe().into()
+    }
+}
+
+/// Decodes `bytes` and checks that every other field agrees with it.
+#[cfg(feature = "serde")]
+impl TryFrom<NanFields> for NanInfo {
+    type Error = Error;
+
+    fn try_from(fields: NanFields) -> Result<Self> {
+        let nan = NanBstr::from_hex(&fields.bytes)?;
+        let info = nan.describe();
+        if NanFields::from(info) != fields {
+            return Err(Error::InvalidNanText {
+                offset: 0,
+                reason: "fields do not match `bytes`".to_string(),
+            });
+        }
+        Ok(info)
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{borrow::Borrow, ops::RangeInclusive};
+
+use crate::{NanBstr, NanWidth, WidthSet};
+
+/// A predicate over `NanBstr` values, used to select NaNs by width, sign,
+/// quietness, and payload.
+///
+/// Patterns are built fluently starting from [`NanPattern::any`]; each
+/// builder method adds a constraint that must also hold. Patterns compose
+/// with [`and`](Self::and), [`or`](Self::or), and `!` (via `std::ops::Not`).
+///
+/// # Text syntax
+///
+/// Patterns also have a textual form, produced by `Display` and accepted by
+/// `FromStr`, for use in configuration files and command-line flags:
+///
+/// ```text
+/// pattern := alt ( "|" alt )*
+/// alt     := unary ( unary )*            clauses separated by whitespace
+/// unary   := "!" unary | "(" pattern ")" | clause
+/// clause  := "any"
+///          | "w=" width ( "," width )*   width: 16, 32, 64, or 128
+///          | "w=none"                    matches nothing
+///          | "sign=" ( "+" | "-" )
+///          | "kind=" ( "qnan" | "snan" )
+///          | "payload==" int
+///          | "payload&" int "==" int     mask, then value
+///          | "payload=" int "..=" int    inclusive range
+/// int     := decimal digits | "0x" hex digits
+/// ```
+///
+/// Juxtaposed clauses are combined with AND, which binds more tightly than
+/// `|`; so `w=32 kind=snan | sign=-` means `(w=32 AND kind=snan) OR sign=-`.
+/// For example, `w=32,64 sign=- kind=snan payload&0xff==0x7` selects
+/// negative signaling binary32 or binary64 NaNs whose low payload byte is 7.
+///
+/// Printing a pattern and parsing the result yields the same pattern for any
+/// pattern built with the builder methods or parsed from text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum NanPattern {
+    /// Matches every NaN.
+    #[default]
+    Any,
+    /// Matches NaNs whose width is one of the listed widths.
+    Width(WidthSet),
+    /// Matches NaNs whose sign bit equals the given value (true = negative).
+    Sign(bool),
+    /// Matches NaNs whose quiet bit equals the given value (true = quiet).
+    Quiet(bool),
+    /// Matches NaNs whose payload is exactly the given value.
+    Payload(u128),
+    /// Matches NaNs where `payload & mask == value`.
+    PayloadMasked { mask: u128, value: u128 },
+    /// Matches NaNs whose payload lies within the inclusive range.
+    PayloadRange(RangeInclusive<u128>),
+    /// Matches when every sub-pattern matches.
+    And(Vec<NanPattern>),
+    /// Matches when at least one sub-pattern matches.
+    Or(Vec<NanPattern>),
+    /// Matches when the sub-pattern does not match.
+    Not(Box<NanPattern>),
+}
+
+impl NanPattern {
+    // ───────────────────────────── Builders ─────────────────────────────────
+
+    /// Returns a pattern that matches every NaN.
+    pub fn any() -> Self {
+        Self::Any
+    }
+
+    /// Restricts the pattern to a single width.
+    pub fn width(self, width: NanWidth) -> Self {
+        self.and(Self::Width(width.into()))
+    }
+
+    /// Restricts the pattern to any of the given widths.
+    pub fn widths(self, widths: impl IntoIterator<Item = NanWidth>) -> Self {
+        self.and(Self::Width(widths.into_iter().collect()))
+    }
+
+    /// Restricts the pattern to NaNs with the sign bit clear.
+    pub fn positive(self) -> Self {
+        self.and(Self::Sign(false))
+    }
+
+    /// Restricts the pattern to NaNs with the sign bit set.
+    pub fn negative(self) -> Self {
+        self.and(Self::Sign(true))
+    }
+
+    /// Restricts the pattern to quiet NaNs.
+    pub fn quiet(self) -> Self {
+        self.and(Self::Quiet(true))
+    }
+
+    /// Restricts the pattern to signaling NaNs.
+    pub fn signaling(self) -> Self {
+        self.and(Self::Quiet(false))
+    }
+
+    /// Restricts the pattern to NaNs carrying exactly `payload`.
+    pub fn payload(self, payload: u128) -> Self {
+        self.and(Self::Payload(payload))
+    }
+
+    /// Restricts the pattern to NaNs where `payload & mask == value`.
+    pub fn payload_masked(self, mask: u128, value: u128) -> Self {
+        self.and(Self::PayloadMasked { mask, value })
+    }
+
+    /// Restricts the pattern to NaNs whose payload lies within `range`.
+    pub fn payload_range(self, range: RangeInclusive<u128>) -> Self {
+        self.and(Self::PayloadRange(range))
+    }
+
+    // ──────────────────────────── Combinators ───────────────────────────────
+
+    /// Returns a pattern matching when both `self` and `other` match.
+    pub fn and(self, other: NanPattern) -> Self {
+        match (self, other) {
+            (Self::Any, p) | (p, Self::Any) => p,
+            (Self::And(mut a), Self::And(b)) => {
+                a.extend(b);
+                Self::And(a)
+            }
+            (Self::And(mut a), p) => {
+                a.push(p);
+                Self::And(a)
+            }
+            (p, Self::And(mut b)) => {
+                b.insert(0, p);
+                Self::And(b)
+            }
+            (a, b) => Self::And(vec![a, b]),
+        }
+    }
+
+    /// Returns a pattern matching when either `self` or `other` matches.
+    pub fn or(self, other: NanPattern) -> Self {
+        match (self, other) {
+            (Self::Any, _) | (_, Self::Any) => Self::Any,
+            (Self::Or(mut a), Self::Or(b)) => {
+                a.extend(b);
+                Self::Or(a)
+            }
+            (Self::Or(mut a), p) => {
+                a.push(p);
+                Self::Or(a)
+            }
+            (p, Self::Or(mut b)) => {
+                b.insert(0, p);
+                Self::Or(b)
+            }
+            (a, b) => Self::Or(vec![a, b]),
+        }
+    }
+
+    // ───────────────────────────── Matching ─────────────────────────────────
+
+    /// Returns true if `nan` satisfies this pattern.
+    pub fn matches(&self, nan: &NanBstr) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Width(widths) => widths.contains(nan.width()),
+            Self::Sign(sign) => nan.sign() == *sign,
+            Self::Quiet(quiet) => nan.is_quiet() == *quiet,
+            Self::Payload(payload) => nan.payload_bits() == *payload,
+            Self::PayloadMasked { mask, value } => {
+                nan.payload_bits() & mask == *value
+            }
+            Self::PayloadRange(range) => range.contains(&nan.payload_bits()),
+            Self::And(patterns) => patterns.iter().all(|p| p.matches(nan)),
+            Self::Or(patterns) => patterns.iter().any(|p| p.matches(nan)),
+            Self::Not(pattern) => !pattern.matches(nan),
+        }
+    }
+
+    /// Returns an iterator over the items of `iter` that match this pattern.
+    ///
+    /// Accepts anything yielding owned or borrowed `NanBstr`s.
+    pub fn filter<'a, I>(
+        &'a self,
+        iter: I,
+    ) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: Borrow<NanBstr>,
+    {
+        iter.into_iter()
+            .filter(move |nan| self.matches(nan.borrow()))
+    }
+}
+
+impl core::ops::Not for NanPattern {
+    type Output = NanPattern;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Self::Not(pattern) => *pattern,
+            pattern => Self::Not(Box::new(pattern)),
+        }
+    }
+}
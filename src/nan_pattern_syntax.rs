@@ -0,0 +1,280 @@
+use alloc::{boxed::Box, format, string::{String, ToString}, vec, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{Error, NanPattern, Result, WidthSet};
+
+// ───────────────────────────────── Display ──────────────────────────────────
+
+impl fmt::Display for NanPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            // `|` separates alternatives here, so the list uses `,`.
+            Self::Width(widths) => {
+                write!(f, "w=")?;
+                widths.fmt_list(f, ',')
+            }
+            Self::Sign(sign) => {
+                write!(f, "sign={}", if *sign { "-" } else { "+" })
+            }
+            Self::Quiet(quiet) => {
+                write!(f, "kind={}", if *quiet { "qnan" } else { "snan" })
+            }
+            Self::Payload(payload) => write!(f, "payload==0x{:x}", payload),
+            Self::PayloadMasked { mask, value } => {
+                write!(f, "payload&0x{:x}==0x{:x}", mask, value)
+            }
+            Self::PayloadRange(range) => {
+                write!(f, "payload=0x{:x}..=0x{:x}", range.start(), range.end())
+            }
+            Self::And(patterns) => {
+                if patterns.is_empty() {
+                    return write!(f, "any");
+                }
+                for (i, p) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    match p {
+                        Self::And(_) | Self::Or(_) => write!(f, "({})", p)?,
+                        _ => write!(f, "{}", p)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Or(patterns) => {
+                if patterns.is_empty() {
+                    return write!(f, "!any");
+                }
+                for (i, p) in patterns.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+                    match p {
+                        Self::Or(_) => write!(f, "({})", p)?,
+                        _ => write!(f, "{}", p)?,
+                    }
+                }
+                Ok(())
+            }
+            Self::Not(p) => match p.as_ref() {
+                Self::And(_) | Self::Or(_) => write!(f, "!({})", p),
+                _ => write!(f, "!{}", p),
+            },
+        }
+    }
+}
+
+// ───────────────────────────────── FromStr ──────────────────────────────────
+
+impl FromStr for NanPattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(s),
+            pos: 0,
+            end: s.len(),
+        };
+        let pattern = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(pattern),
+            Some(token) => Err(token.error("unexpected token")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    Not,
+    Or,
+    Clause,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    offset: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, reason: impl Into<String>) -> Error {
+        Error::InvalidPattern {
+            offset: self.offset,
+            token: self.text.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(offset, c)) = chars.peek() {
+        let kind = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '!' => TokenKind::Not,
+            '|' => TokenKind::Or,
+            _ => {
+                let mut end = offset;
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || "()!|".contains(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Clause,
+                    text: &s[offset..end],
+                    offset,
+                });
+                continue;
+            }
+        };
+        chars.next();
+        tokens.push(Token {
+            kind,
+            text: &s[offset..offset + 1],
+            offset,
+        });
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn end_of_input(&self, reason: &str) -> Error {
+        Error::InvalidPattern {
+            offset: self.end,
+            token: String::new(),
+            reason: reason.to_string(),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<NanPattern> {
+        let first = self.parse_and()?;
+        let mut alternatives = vec![first];
+        while self.peek().is_some_and(|t| t.kind == TokenKind::Or) {
+            self.next();
+            alternatives.push(self.parse_and()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            NanPattern::Or(alternatives)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<NanPattern> {
+        let mut pattern = self.parse_unary()?;
+        while let Some(token) = self.peek() {
+            match token.kind {
+                TokenKind::Or | TokenKind::RParen => break,
+                _ => pattern = pattern.and(self.parse_unary()?),
+            }
+        }
+        Ok(pattern)
+    }
+
+    fn parse_unary(&mut self) -> Result<NanPattern> {
+        let token = self
+            .next()
+            .ok_or_else(|| self.end_of_input("expected clause"))?;
+        match token.kind {
+            TokenKind::Not => {
+                Ok(NanPattern::Not(Box::new(self.parse_unary()?)))
+            }
+            TokenKind::LParen => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(t) if t.kind == TokenKind::RParen => Ok(inner),
+                    Some(t) => Err(t.error("expected `)`")),
+                    None => Err(self.end_of_input("expected `)`")),
+                }
+            }
+            TokenKind::Clause => parse_clause(token),
+            TokenKind::RParen | TokenKind::Or => {
+                Err(token.error("expected clause"))
+            }
+        }
+    }
+}
+
+fn parse_clause(token: Token<'_>) -> Result<NanPattern> {
+    let text = token.text;
+    if text == "any" {
+        return Ok(NanPattern::Any);
+    }
+    if let Some(list) = text.strip_prefix("w=") {
+        return WidthSet::parse_list(list, ',')
+            .map(NanPattern::Width)
+            .ok_or_else(|| token.error("width must be 16, 32, 64, or 128"));
+    }
+    if let Some(sign) = text.strip_prefix("sign=") {
+        return match sign {
+            "+" => Ok(NanPattern::Sign(false)),
+            "-" => Ok(NanPattern::Sign(true)),
+            _ => Err(token.error("sign must be `+` or `-`")),
+        };
+    }
+    if let Some(kind) = text.strip_prefix("kind=") {
+        return match kind {
+            "qnan" => Ok(NanPattern::Quiet(true)),
+            "snan" => Ok(NanPattern::Quiet(false)),
+            _ => Err(token.error("kind must be `qnan` or `snan`")),
+        };
+    }
+    if let Some(value) = text.strip_prefix("payload==") {
+        return Ok(NanPattern::Payload(parse_int(token, value)?));
+    }
+    if let Some(rest) = text.strip_prefix("payload&") {
+        let (mask, value) = rest
+            .split_once("==")
+            .ok_or_else(|| token.error("expected `payload&MASK==VALUE`"))?;
+        return Ok(NanPattern::PayloadMasked {
+            mask: parse_int(token, mask)?,
+            value: parse_int(token, value)?,
+        });
+    }
+    if let Some(rest) = text.strip_prefix("payload=") {
+        let (start, end) = rest
+            .split_once("..=")
+            .ok_or_else(|| token.error("expected `payload=MIN..=MAX`"))?;
+        return Ok(NanPattern::PayloadRange(
+            parse_int(token, start)?..=parse_int(token, end)?,
+        ));
+    }
+    Err(token.error("unknown clause"))
+}
+
+fn parse_int(token: Token<'_>, s: &str) -> Result<u128> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => s.parse::<u128>(),
+    };
+    parsed.map_err(|_| token.error(format!("invalid integer `{}`", s)))
+}
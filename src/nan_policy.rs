@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{NanBstr, NanWidth, WidthSet};
+
+/// A set of rules that NaNs in a document are expected to follow.
+///
+/// The default policy permits every NaN. Each restriction is relaxed or
+/// tightened independently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NanPolicy {
+    /// The widths that may appear.
+    pub allowed_widths: WidthSet,
+    /// Whether signaling NaNs may appear.
+    pub allow_signaling: bool,
+    /// Whether NaNs with the sign bit set may appear.
+    pub allow_negative: bool,
+    /// Whether NaNs with a non-zero payload may appear.
+    pub allow_payload: bool,
+}
+
+impl NanPolicy {
+    /// Returns a policy that permits every NaN.
+    pub fn permissive() -> Self {
+        Self {
+            allowed_widths: WidthSet::ALL,
+            allow_signaling: true,
+            allow_negative: true,
+            allow_payload: true,
+        }
+    }
+
+    /// Returns a policy that permits only positive quiet NaNs with a zero
+    /// payload, of any width.
+    pub fn canonical_only() -> Self {
+        Self {
+            allow_signaling: false,
+            allow_negative: false,
+            allow_payload: false,
+            ..Self::permissive()
+        }
+    }
+
+    /// Returns every rule of this policy that `nan` breaks, in a fixed
+    /// order: width, sign, signaling, payload.
+    pub fn violations(&self, nan: &NanBstr) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+        if !self.allowed_widths.contains(nan.width()) {
+            violations.push(PolicyViolation::WidthNotAllowed(nan.width()));
+        }
+        if !self.allow_negative && nan.sign() {
+            violations.push(PolicyViolation::Negative);
+        }
+        if !self.allow_signaling && nan.is_signaling() {
+            violations.push(PolicyViolation::Signaling);
+        }
+        if !self.allow_payload && nan.payload_bits() != 0 {
+            violations.push(PolicyViolation::Payload(nan.payload_bits()));
+        }
+        violations
+    }
+
+    /// Returns true if `nan` breaks none of the rules of this policy.
+    pub fn permits(&self, nan: &NanBstr) -> bool {
+        self.violations(nan).is_empty()
+    }
+}
+
+impl Default for NanPolicy {
+    fn default() -> Self {
+        Self::permissive()
+    }
+}
+
+/// A rule of a [`NanPolicy`] broken by a particular NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PolicyViolation {
+    /// The NaN's width is not among the allowed widths.
+    WidthNotAllowed(NanWidth),
+    /// The NaN has its sign bit set.
+    Negative,
+    /// The NaN is signaling.
+    Signaling,
+    /// The NaN carries the given non-zero payload.
+    Payload(u128),
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WidthNotAllowed(width) => {
+                write!(f, "width {} not allowed", width)
+            }
+            Self::Negative => write!(f, "negative NaN not allowed"),
+            Self::Signaling => write!(f, "signaling NaN not allowed"),
+            Self::Payload(payload) => {
+                write!(f, "payload 0x{:x} not allowed", payload)
+            }
+        }
+    }
+}
@@ -0,0 +1,813 @@
+use core::fmt;
+
+use ciborium::tag::Required;
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::{NanBstr, NanWidth};
+
+/// What [`NanDeserializer`] does when a tag-102 item is narrower than the
+/// float being deserialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WideningPolicy {
+    /// Fail unless the pattern's width is exactly the requested width.
+    #[default]
+    Reject,
+    /// Widen narrower patterns as [`NanBstr::widen_to`] does. This is
+    /// lossless.
+    Widen,
+}
+
+/// A `Serializer` adapter that writes NaN `f64` and `f32` values as tag-102
+/// items and delegates everything else to the inner serializer unchanged.
+///
+/// Tags are emitted with the `ciborium` tag convention, so the inner
+/// serializer is expected to be a `ciborium` serializer or one that follows
+/// the same convention. Non-NaN values, including the infinities, are
+/// passed through, so their encoding is identical to the unwrapped one.
+pub struct NanSerializer<S>(S);
+
+impl<S> NanSerializer<S> {
+    /// Wraps `inner`.
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+/// A `Deserializer` adapter that accepts tag-102 items wherever an `f64` or
+/// `f32` is requested and delegates everything else to the inner
+/// deserializer.
+///
+/// A binary64 pattern deserializes to `f64` and a binary32 pattern to `f32`
+/// with every bit preserved; narrower patterns are accepted or rejected
+/// according to the [`WideningPolicy`], and wider ones are always
+/// rejected. Plain floats and items under other tags are handled as the
+/// inner deserializer handles them.
+pub struct NanDeserializer<D> {
+    inner: D,
+    policy: WideningPolicy,
+}
+
+impl<D> NanDeserializer<D> {
+    /// Wraps `inner`, rejecting patterns narrower than the requested float.
+    pub fn new(inner: D) -> Self {
+        Self::with_policy(inner, WideningPolicy::Reject)
+    }
+
+    /// Wraps `inner`, handling narrower patterns as `policy` directs.
+    pub fn with_policy(inner: D, policy: WideningPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+/// Serializes and deserializes `T` through [`NanSerializer`] and
+/// [`NanDeserializer`], for backends such as `ciborium` whose entry points
+/// take a value rather than a serializer:
+///
+/// ```
+/// use cbor_nan_bstr::WithNans;
+///
+/// let value = vec![1.5, f64::from_bits(0x7FF8_0000_0000_0001)];
+/// let mut data = Vec::new();
+/// ciborium::into_writer(&WithNans::<_>(&value), &mut data).unwrap();
+/// let WithNans(decoded): WithNans<Vec<f64>> =
+///     ciborium::from_reader(data.as_slice()).unwrap();
+/// assert_eq!(decoded[1].to_bits(), 0x7FF8_0000_0000_0001);
+/// ```
+///
+/// Deserialization uses [`WideningPolicy::Reject`], or
+/// [`WideningPolicy::Widen`] when `WIDEN` is `true`. Serialization is the
+/// same for either, so a bare `WithNans(value)` leaves `WIDEN` to inference;
+/// `WithNans::<_>(value)` takes the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WithNans<T, const WIDEN: bool = false>(pub T);
+
+impl<T: Serialize, const WIDEN: bool> Serialize for WithNans<T, WIDEN> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize(NanSerializer::new(serializer))
+    }
+}
+
+impl<'de, T: de::Deserialize<'de>, const WIDEN: bool> de::Deserialize<'de>
+    for WithNans<T, WIDEN>
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let policy = if WIDEN {
+            WideningPolicy::Widen
+        } else {
+            WideningPolicy::Reject
+        };
+        T::deserialize(NanDeserializer::with_policy(deserializer, policy))
+            .map(WithNans)
+    }
+}
+
+// ───────────────────────────── Serialization ──────────────────────────────
+
+/// Serializes a value through [`NanSerializer`].
+struct Wrap<'a, T: ?Sized>(&'a T);
+
+impl<T: ?Sized + Serialize> Serialize for Wrap<'_, T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize(NanSerializer(serializer))
+    }
+}
+
+/// Serializes a slice as a byte string rather than an array.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+fn serialize_nan<S: Serializer>(
+    nan: NanBstr,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Required::<_, { bc_tags::TAG_NAN_BSTR }>(Bytes(nan.as_bytes()))
+        .serialize(serializer)
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self $(, $arg: $ty)*) -> Result<S::Ok, S::Error> {
+                self.0.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<S: Serializer> Serializer for NanSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = NanSerializer<S::SerializeSeq>;
+    type SerializeTuple = NanSerializer<S::SerializeTuple>;
+    type SerializeTupleStruct = NanSerializer<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = NanSerializer<S::SerializeTupleVariant>;
+    type SerializeMap = NanSerializer<S::SerializeMap>;
+    type SerializeStruct = NanSerializer<S::SerializeStruct>;
+    type SerializeStructVariant = NanSerializer<S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(
+            name: &'static str,
+            index: u32,
+            variant: &'static str
+        );
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<S::Ok, S::Error> {
+        match NanBstr::try_from(v) {
+            Ok(nan) => serialize_nan(nan, self.0),
+            Err(_) => self.0.serialize_f32(v),
+        }
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<S::Ok, S::Error> {
+        match NanBstr::try_from(v) {
+            Ok(nan) => serialize_nan(nan, self.0),
+            Err(_) => self.0.serialize_f64(v),
+        }
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(
+        self,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_some(&Wrap(value))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0.serialize_newtype_struct(name, &Wrap(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.0
+            .serialize_newtype_variant(name, index, variant, &Wrap(value))
+    }
+
+    fn serialize_seq(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeSeq, S::Error> {
+        self.0.serialize_seq(len).map(NanSerializer)
+    }
+
+    fn serialize_tuple(
+        self,
+        len: usize,
+    ) -> Result<Self::SerializeTuple, S::Error> {
+        self.0.serialize_tuple(len).map(NanSerializer)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.0.serialize_tuple_struct(name, len).map(NanSerializer)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.0
+            .serialize_tuple_variant(name, index, variant, len)
+            .map(NanSerializer)
+    }
+
+    fn serialize_map(
+        self,
+        len: Option<usize>,
+    ) -> Result<Self::SerializeMap, S::Error> {
+        self.0.serialize_map(len).map(NanSerializer)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        self.0.serialize_struct(name, len).map(NanSerializer)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        self.0
+            .serialize_struct_variant(name, index, variant, len)
+            .map(NanSerializer)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.0.is_human_readable()
+    }
+}
+
+macro_rules! impl_serialize_compound {
+    ($($trait:ident::$method:ident($($key:ident: $key_ty:ty)?);)*) => {
+        $(
+            impl<S: ser::$trait> ser::$trait for NanSerializer<S> {
+                type Ok = S::Ok;
+                type Error = S::Error;
+
+                fn $method<T: ?Sized + Serialize>(
+                    &mut self,
+                    $($key: $key_ty,)?
+                    value: &T,
+                ) -> Result<(), S::Error> {
+                    self.0.$method($($key,)? &Wrap(value))
+                }
+
+                fn end(self) -> Result<S::Ok, S::Error> {
+                    self.0.end()
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_compound! {
+    SerializeSeq::serialize_element();
+    SerializeTuple::serialize_element();
+    SerializeTupleStruct::serialize_field();
+    SerializeTupleVariant::serialize_field();
+    SerializeStruct::serialize_field(key: &'static str);
+    SerializeStructVariant::serialize_field(key: &'static str);
+}
+
+impl<S: ser::SerializeMap> ser::SerializeMap for NanSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(
+        &mut self,
+        key: &T,
+    ) -> Result<(), S::Error> {
+        self.0.serialize_key(&Wrap(key))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        self.0.serialize_value(&Wrap(value))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.0.end()
+    }
+}
+
+// ──────────────────────────── Deserialization ─────────────────────────────
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, D::Error> {
+                self.inner
+                    .$method($($arg,)* NanVisitor::new(visitor, self.policy))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for NanDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(
+            name: &'static str,
+            fields: &'static [&'static str]
+        );
+        deserialize_enum(
+            name: &'static str,
+            variants: &'static [&'static str]
+        );
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_any(FloatVisitor {
+            inner: NanVisitor::new(visitor, self.policy),
+            width: NanWidth::Binary32,
+        })
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_any(FloatVisitor {
+            inner: NanVisitor::new(visitor, self.policy),
+            width: NanWidth::Binary64,
+        })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// Wraps every deserializer a visitor is handed in [`NanDeserializer`].
+struct NanVisitor<V> {
+    inner: V,
+    policy: WideningPolicy,
+}
+
+impl<V> NanVisitor<V> {
+    fn new(inner: V, policy: WideningPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    fn wrap<T>(&self, inner: T) -> NanVisitor<T> {
+        NanVisitor::new(inner, self.policy)
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<V::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for NanVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.inner
+            .visit_some(NanDeserializer::with_policy(deserializer, self.policy))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(NanDeserializer::with_policy(
+                deserializer,
+                self.policy,
+            ))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(
+        self,
+        seq: A,
+    ) -> Result<V::Value, A::Error> {
+        let seq = self.wrap(seq);
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(
+        self,
+        map: A,
+    ) -> Result<V::Value, A::Error> {
+        let map = self.wrap(map);
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(
+        self,
+        data: A,
+    ) -> Result<V::Value, A::Error> {
+        let data = self.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, T: DeserializeSeed<'de>> DeserializeSeed<'de> for NanVisitor<T> {
+    type Value = T::Value;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<T::Value, D::Error> {
+        self.inner.deserialize(NanDeserializer::with_policy(
+            deserializer,
+            self.policy,
+        ))
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for NanVisitor<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for NanVisitor<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<T::Value, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for NanVisitor<A> {
+    type Error = A::Error;
+    type Variant = NanVisitor<A::Variant>;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<(T::Value, Self::Variant), A::Error> {
+        let policy = self.policy;
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, NanVisitor::new(variant, policy)))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for NanVisitor<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
+
+/// Visits the item in place of a requested float: a tag-102 item becomes a
+/// float of `width`, and anything else is passed to the wrapped visitor.
+struct FloatVisitor<V> {
+    inner: NanVisitor<V>,
+    width: NanWidth,
+}
+
+impl<V> FloatVisitor<V> {
+    fn visit_nan<'de, E: de::Error>(self, bytes: &[u8]) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let nan = NanBstr::from_be_bytes(bytes).map_err(E::custom)?;
+        if nan.width() != self.width
+            && (self.inner.policy == WideningPolicy::Reject
+                || nan.width().len() > self.width.len())
+        {
+            return Err(E::custom(crate::Error::WidthMismatch {
+                expected: self.width,
+                actual: nan.width(),
+            }));
+        }
+        let result = match self.width {
+            NanWidth::Binary32 => {
+                nan.to_f32_checked().map(|v| self.inner.inner.visit_f32(v))
+            }
+            _ => nan.to_f64_checked().map(|v| self.inner.inner.visit_f64(v)),
+        };
+        result.map_err(E::custom)?
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for FloatVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(
+        self,
+        seq: A,
+    ) -> Result<V::Value, A::Error> {
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(
+        self,
+        map: A,
+    ) -> Result<V::Value, A::Error> {
+        self.inner.visit_map(map)
+    }
+
+    /// Tags arrive as `ciborium`'s tag enum: a `@@TAGGED@@` tuple variant
+    /// holding the tag number and the content.
+    fn visit_enum<A: de::EnumAccess<'de>>(
+        self,
+        data: A,
+    ) -> Result<V::Value, A::Error> {
+        let (name, variant) = data.variant::<String>()?;
+        if name != "@@TAGGED@@" {
+            return Err(de::Error::invalid_type(de::Unexpected::Enum, &self));
+        }
+        de::VariantAccess::tuple_variant(variant, 2, TagVisitor(self))
+    }
+}
+
+/// Reads the tag number and content of a tagged item requested as a float.
+struct TagVisitor<V>(FloatVisitor<V>);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for TagVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expecting(f)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<V::Value, A::Error> {
+        let missing = || de::Error::custom("expected tag content");
+        let tag: u64 = seq.next_element()?.ok_or_else(missing)?;
+        if tag != bc_tags::TAG_NAN_BSTR {
+            return seq.next_element_seed(self.0)?.ok_or_else(missing);
+        }
+        let bytes = seq.next_element_seed(ByteBuf)?.ok_or_else(missing)?;
+        self.0.visit_nan(&bytes)
+    }
+}
+
+/// Content under a tag other than 102 is requested as the float again.
+impl<'de, V: Visitor<'de>> DeserializeSeed<'de> for FloatVisitor<V> {
+    type Value = V::Value;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+/// Deserializes a byte string into a `Vec<u8>`.
+struct ByteBuf;
+
+impl<'de> DeserializeSeed<'de> for ByteBuf {
+    type Value = Vec<u8>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        deserializer.deserialize_byte_buf(self)
+    }
+}
+
+impl Visitor<'_> for ByteBuf {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+}
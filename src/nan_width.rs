@@ -1,7 +1,29 @@
-use crate::{Error, Result};
+use alloc::{format, string::ToString};
+use core::{fmt, str::FromStr};
+
+use crate::{Error, PackedNanBstr, Result};
 
 /// Width of the underlying IEEE‑754 representation carried in the byte string.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Widths are ordered narrowest first. They display as `binary16` through
+/// `binary128`, and parse from that form, from `f16` through `f128`, or from
+/// the bit count alone.
+///
+/// With the `serde` feature, serializes as `"binary16"`, `"binary32"`,
+/// `"binary64"`, or `"binary128"`. With the `async-graphql` feature, it is
+/// the GraphQL enum `NanWidth`, with values `BINARY16` through `BINARY128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(
+    feature = "async-graphql",
+    derive(async_graphql::Enum),
+    graphql(rename_items = "UPPERCASE")
+)]
 pub enum NanWidth {
     /// 2-octet IEEE‑754 binary16 (aka half, f16)
     Binary16,
@@ -15,6 +37,14 @@ pub enum NanWidth {
 
 #[allow(clippy::len_without_is_empty)]
 impl NanWidth {
+    /// Every width, narrowest first.
+    pub const ALL: [NanWidth; 4] = [
+        Self::Binary16,
+        Self::Binary32,
+        Self::Binary64,
+        Self::Binary128,
+    ];
+
     pub fn from_len(len: usize) -> Result<Self> {
         match len {
             2 => Ok(Self::Binary16),
@@ -25,7 +55,7 @@ impl NanWidth {
         }
     }
 
-    pub fn len(self) -> usize {
+    pub const fn len(self) -> usize {
         match self {
             Self::Binary16 => 2,
             Self::Binary32 => 4,
@@ -33,4 +63,163 @@ impl NanWidth {
             Self::Binary128 => 16,
         }
     }
+
+    /// Returns the width for a bit count of 16, 32, 64, or 128. Fails with
+    /// `Error::InvalidWidth` for any other count.
+    pub fn from_bits(bits: u32) -> Result<Self> {
+        match bits {
+            16 => Ok(Self::Binary16),
+            32 => Ok(Self::Binary32),
+            64 => Ok(Self::Binary64),
+            128 => Ok(Self::Binary128),
+            _ => Err(Error::InvalidWidth(format!("{} bits", bits))),
+        }
+    }
+
+    /// Parses a bit count written as plain decimal digits, such as `64`: the
+    /// form in which width lists spell each width.
+    pub(crate) fn from_bits_str(s: &str) -> Option<Self> {
+        if s.starts_with('0') || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Self::from_bits(s.parse().ok()?).ok()
+    }
+
+    /// Returns the number of bits: 16, 32, 64, or 128.
+    pub const fn bits(self) -> u32 {
+        match self {
+            Self::Binary16 => 16,
+            Self::Binary32 => 32,
+            Self::Binary64 => 64,
+            Self::Binary128 => 128,
+        }
+    }
+
+    /// Returns the number of exponent bits: 5, 8, 11, or 15.
+    pub const fn exponent_bits(self) -> u32 {
+        match self {
+            Self::Binary16 => 5,
+            Self::Binary32 => 8,
+            Self::Binary64 => 11,
+            Self::Binary128 => 15,
+        }
+    }
+
+    /// Returns the number of trailing significand (fraction) bits, including
+    /// the quiet bit: 10, 23, 52, or 112.
+    pub const fn fraction_bits(self) -> u32 {
+        match self {
+            Self::Binary16 => 10,
+            Self::Binary32 => 23,
+            Self::Binary64 => 52,
+            Self::Binary128 => 112,
+        }
+    }
+
+    /// Returns the number of payload bits, the fraction bits beneath the
+    /// quiet bit: 9, 22, 51, or 111.
+    pub const fn payload_bits(self) -> u32 {
+        self.fraction_bits() - 1
+    }
+
+    /// Returns the number of bytes that hold a payload: 2, 3, 7, or 14.
+    pub const fn payload_len(self) -> usize {
+        self.payload_bits().div_ceil(8) as usize
+    }
+
+    /// Returns the index of the sign bit, counting from the least significant
+    /// bit: 15, 31, 63, or 127.
+    pub const fn sign_bit_index(self) -> u32 {
+        self.bits() - 1
+    }
+
+    /// Returns the index of the quiet bit, the most significant fraction
+    /// bit: 9, 22, 51, or 111.
+    pub const fn quiet_bit_index(self) -> u32 {
+        self.fraction_bits() - 1
+    }
+
+    /// Returns the mask of the exponent field, right-aligned in a `u128`.
+    pub const fn exponent_mask_u128(self) -> u128 {
+        ((1u128 << self.exponent_bits()) - 1) << self.fraction_bits()
+    }
+
+    /// Returns the mask of the fraction field, quiet bit included,
+    /// right-aligned in a `u128`.
+    pub const fn fraction_mask_u128(self) -> u128 {
+        (1u128 << self.fraction_bits()) - 1
+    }
+
+    /// Returns the mask of the quiet bit, right-aligned in a `u128`.
+    pub const fn quiet_mask_u128(self) -> u128 {
+        1u128 << self.quiet_bit_index()
+    }
+
+    /// Returns true if `bits`, right-aligned, encode a NaN of this width.
+    pub(crate) const fn is_nan_bits(self, bits: u128) -> bool {
+        let exponent = self.exponent_mask_u128();
+        bits & exponent == exponent && bits & self.fraction_mask_u128() != 0
+    }
+
+    /// Returns the largest payload, the fraction bits beneath the quiet
+    /// bit: 9 bits for binary16, 22 for binary32, 51 for binary64, and 111
+    /// for binary128.
+    pub const fn max_payload(self) -> u128 {
+        (1u128 << self.payload_bits()) - 1
+    }
+
+    /// Returns the positive quiet NaN with a zero payload, right-aligned in
+    /// a `u128`: `0x7E00`, `0x7FC0_0000`, `0x7FF8_0000_0000_0000`, or
+    /// `0x7FFF_8000_0000_0000_0000_0000_0000_0000`.
+    pub const fn canonical_quiet_bits(self) -> u128 {
+        self.exponent_mask_u128() | self.quiet_mask_u128()
+    }
+
+    /// Returns [`canonical_quiet_bits`](Self::canonical_quiet_bits) as a
+    /// big-endian pattern of [`len`](Self::len) bytes, right-aligned and
+    /// zero-padded.
+    pub const fn canonical_quiet_bytes(self) -> PackedNanBstr {
+        PackedNanBstr {
+            width: self.len() as u8,
+            bytes: self.canonical_quiet_bits().to_be_bytes(),
+        }
+    }
+
+    /// Returns true if `payload` is at most [`max_payload`](Self::max_payload).
+    pub const fn fits_payload(self, payload: u128) -> bool {
+        payload <= self.max_payload()
+    }
+
+    /// Returns the narrowest width that carries `payload`, the shortest
+    /// deterministic form. Fails with `Error::PayloadTooLarge` for payloads
+    /// over 111 bits.
+    pub fn for_payload(payload: u128) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|width| width.fits_payload(payload))
+            .ok_or(Error::PayloadTooLarge {
+                width: Self::Binary128,
+                payload,
+            })
+    }
+}
+
+impl fmt::Display for NanWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "binary{}", self.bits())
+    }
+}
+
+/// Parses `binary32`, `f32`, or `32`, and likewise for the other widths.
+impl FromStr for NanWidth {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let bits = s
+            .strip_prefix("binary")
+            .or_else(|| s.strip_prefix('f'))
+            .unwrap_or(s);
+        Self::from_bits_str(bits)
+            .ok_or_else(|| Error::InvalidWidth(s.to_string()))
+    }
 }
@@ -0,0 +1,41 @@
+use crate::{Error, NanBstr, NanWidth, Result};
+
+impl TryFrom<f16> for NanBstr {
+    type Error = Error;
+    fn try_from(value: f16) -> Result<Self> {
+        if !value.is_nan() {
+            return Err(Error::NotANan);
+        }
+        Self::from_binary16_bits(value.to_bits())
+    }
+}
+
+impl TryFrom<NanBstr> for f16 {
+    type Error = Error;
+    fn try_from(value: NanBstr) -> Result<Self> {
+        if value.width() != NanWidth::Binary16 {
+            return Err(Error::InvalidLength(value.as_bytes().len()));
+        }
+        Ok(f16::from_bits(value.to_bits_u128() as u16))
+    }
+}
+
+impl TryFrom<f128> for NanBstr {
+    type Error = Error;
+    fn try_from(value: f128) -> Result<Self> {
+        if !value.is_nan() {
+            return Err(Error::NotANan);
+        }
+        Self::from_binary128_bits(value.to_bits())
+    }
+}
+
+impl TryFrom<NanBstr> for f128 {
+    type Error = Error;
+    fn try_from(value: NanBstr) -> Result<Self> {
+        if value.width() != NanWidth::Binary128 {
+            return Err(Error::InvalidLength(value.as_bytes().len()));
+        }
+        Ok(f128::from_bits(value.to_bits_u128()))
+    }
+}
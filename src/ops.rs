@@ -0,0 +1,132 @@
+//! The NaN semantics of the IEEE 754-2019 §9.6 operations `minimum`,
+//! `maximum`, `minimumNumber`, and `maximumNumber`, over operands that may be
+//! NaNs of any width.
+//!
+//! When a NaN is returned, it is the first NaN operand, quieted: its width,
+//! sign, and payload are kept and its quiet bit is set. A signaling NaN
+//! operand also signals the invalid operation exception, which these
+//! functions do not model; check [`MaybeFloat::is_signaling`] on the
+//! operands.
+
+use crate::NanBstr;
+
+/// An operand or result: a number (finite or infinite) or a NaN.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaybeFloat {
+    /// A value that is not a NaN.
+    Number(f64),
+    /// A NaN, with its exact bit pattern.
+    Nan(NanBstr),
+}
+
+impl MaybeFloat {
+    /// Returns true if this is a NaN.
+    pub fn is_nan(&self) -> bool {
+        matches!(self, Self::Nan(_))
+    }
+
+    /// Returns true if this is a signaling NaN.
+    pub fn is_signaling(&self) -> bool {
+        matches!(self, Self::Nan(nan) if nan.is_signaling())
+    }
+
+    /// Returns the number, if this is not a NaN.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Nan(_) => None,
+        }
+    }
+
+    /// Returns the NaN, if this is one.
+    pub fn as_nan(&self) -> Option<&NanBstr> {
+        match self {
+            Self::Number(_) => None,
+            Self::Nan(nan) => Some(nan),
+        }
+    }
+}
+
+/// Converts an `f64`, keeping a NaN's exact bit pattern.
+impl From<f64> for MaybeFloat {
+    fn from(value: f64) -> Self {
+        match NanBstr::try_from(value) {
+            Ok(nan) => Self::Nan(nan),
+            Err(_) => Self::Number(value),
+        }
+    }
+}
+
+impl From<NanBstr> for MaybeFloat {
+    fn from(nan: NanBstr) -> Self {
+        Self::Nan(nan)
+    }
+}
+
+/// `minimum(a, b)`: the lesser operand, with −0 less than +0, or a quiet
+/// NaN if either operand is a NaN.
+pub fn minimum(a: MaybeFloat, b: MaybeFloat) -> MaybeFloat {
+    nan_propagating(a, b, lesser)
+}
+
+/// `maximum(a, b)`: the greater operand, with +0 greater than −0, or a
+/// quiet NaN if either operand is a NaN.
+pub fn maximum(a: MaybeFloat, b: MaybeFloat) -> MaybeFloat {
+    nan_propagating(a, b, greater)
+}
+
+/// `minimumNumber(a, b)`: like [`minimum`], except that a number is
+/// preferred to a NaN, quiet or signaling. A quiet NaN results only if both
+/// operands are NaNs.
+pub fn minimum_number(a: MaybeFloat, b: MaybeFloat) -> MaybeFloat {
+    number_preferring(a, b, lesser)
+}
+
+/// `maximumNumber(a, b)`: like [`maximum`], except that a number is
+/// preferred to a NaN, quiet or signaling. A quiet NaN results only if both
+/// operands are NaNs.
+pub fn maximum_number(a: MaybeFloat, b: MaybeFloat) -> MaybeFloat {
+    number_preferring(a, b, greater)
+}
+
+/// Returns true if `b` is selected over `a` by `minimum`.
+fn lesser(a: f64, b: f64) -> bool {
+    b < a || (b == a && b.is_sign_negative())
+}
+
+/// Returns true if `b` is selected over `a` by `maximum`.
+fn greater(a: f64, b: f64) -> bool {
+    b > a || (b == a && b.is_sign_positive())
+}
+
+fn nan_propagating(
+    a: MaybeFloat,
+    b: MaybeFloat,
+    picks_b: fn(f64, f64) -> bool,
+) -> MaybeFloat {
+    match (a, b) {
+        (MaybeFloat::Nan(nan), _) | (_, MaybeFloat::Nan(nan)) => quiet(nan),
+        (MaybeFloat::Number(a), MaybeFloat::Number(b)) => {
+            MaybeFloat::Number(if picks_b(a, b) { b } else { a })
+        }
+    }
+}
+
+fn number_preferring(
+    a: MaybeFloat,
+    b: MaybeFloat,
+    picks_b: fn(f64, f64) -> bool,
+) -> MaybeFloat {
+    match (a, b) {
+        (MaybeFloat::Nan(nan), MaybeFloat::Nan(_)) => quiet(nan),
+        (MaybeFloat::Nan(_), number) | (number, MaybeFloat::Nan(_)) => number,
+        (a, b) => nan_propagating(a, b, picks_b),
+    }
+}
+
+fn quiet(nan: NanBstr) -> MaybeFloat {
+    MaybeFloat::Nan(
+        NanBstr::from_parts(nan.width(), nan.sign(), true, nan.payload_bits())
+            .expect("a quiet NaN with the same payload"),
+    )
+}
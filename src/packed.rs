@@ -0,0 +1,50 @@
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// A fixed-size, C-layout record holding any [`NanBstr`], for FFI and for
+/// casting flat buffers without copying.
+///
+/// `width` is the pattern's length in bytes (2, 4, 8, or 16). The last
+/// `width` bytes of `bytes` hold the big-endian pattern, right-aligned as in
+/// [`NanBstr::to_bits_u128`] and [`NanBstr::to_fixed_record`]; the leading
+/// bytes are zero when produced by this crate and ignored when read. The
+/// record is 17 bytes
+/// with alignment 1, so it has no padding. With the `bytemuck` feature it
+/// implements `Pod` and `Zeroable`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedNanBstr {
+    /// The pattern length in bytes.
+    pub width: u8,
+    /// The big-endian pattern, right-aligned and zero-padded.
+    pub bytes: [u8; 16],
+}
+
+impl From<&NanBstr> for PackedNanBstr {
+    fn from(nan: &NanBstr) -> Self {
+        Self {
+            width: nan.width().len() as u8,
+            bytes: nan.to_bits_u128().to_be_bytes(),
+        }
+    }
+}
+
+impl TryFrom<PackedNanBstr> for NanBstr {
+    type Error = Error;
+
+    /// Fails with [`Error::InvalidLength`] if the width byte is not 2, 4, 8,
+    /// or 16, and with [`Error::NotANan`] if the pattern is not a NaN.
+    fn try_from(packed: PackedNanBstr) -> Result<Self> {
+        let width = NanWidth::from_len(packed.width as usize)?;
+        NanBstr::from_be_bytes(&packed.bytes[16 - width.len()..])
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+// SAFETY: all fields are `u8` or arrays of `u8`, so the all-zero pattern is
+// valid.
+unsafe impl bytemuck::Zeroable for PackedNanBstr {}
+
+#[cfg(feature = "bytemuck")]
+// SAFETY: `repr(C)` with only `u8` fields has alignment 1 and no padding,
+// and every bit pattern of every field is valid.
+unsafe impl bytemuck::Pod for PackedNanBstr {}
@@ -0,0 +1,89 @@
+//! Sidecar columns that carry exact NaN bit patterns alongside a Parquet
+//! `DOUBLE` column, whose writers and readers may canonicalize NaNs.
+//!
+//! # Conventions
+//!
+//! For a `DOUBLE` column named `c`, the sidecar is a column named
+//! `c__nan_bstr` with physical type `BYTE_ARRAY`, repetition `OPTIONAL`, and
+//! no logical type, in the same row group and with the same row count. Row
+//! `i` of the sidecar is null unless row `i` of `c` is a NaN other than the
+//! canonical quiet NaN `0x7ff8000000000000`, in which case it holds the
+//! deterministic CBOR encoding of that NaN as a tag-102 item. A reader in
+//! any language restores the column by replacing each such NaN with the
+//! decoded pattern, and ignores the sidecar if it does not know it.
+
+use dcbor::prelude::*;
+use parquet::{
+    basic::{Repetition, Type as PhysicalType},
+    schema::types::Type,
+};
+
+use crate::{Error, NanBstr, Result};
+
+/// The suffix appended to a column name to name its sidecar column.
+pub const SIDECAR_SUFFIX: &str = "__nan_bstr";
+
+/// Returns the name of the sidecar column for `column`.
+pub fn sidecar_column_name(column: &str) -> String {
+    format!("{}{}", column, SIDECAR_SUFFIX)
+}
+
+/// Returns the schema type of the sidecar column for `column`.
+pub fn sidecar_column_type(column: &str) -> Type {
+    Type::primitive_type_builder(
+        &sidecar_column_name(column),
+        PhysicalType::BYTE_ARRAY,
+    )
+    .with_repetition(Repetition::OPTIONAL)
+    .build()
+    .expect("valid sidecar column type")
+}
+
+/// Returns the sidecar for `values`: the tagged encoding of every NaN that
+/// is not the canonical quiet NaN, and `None` for every other value.
+pub fn encode_sidecar(values: &[f64]) -> Vec<Option<Vec<u8>>> {
+    values
+        .iter()
+        .map(|value| {
+            if !value.is_nan() || value.to_bits() == f64::NAN.to_bits() {
+                return None;
+            }
+            let nan = NanBstr::from_f64_bits(value.to_bits())
+                .expect("value is a NaN");
+            Some(nan.tagged_cbor().to_cbor_data())
+        })
+        .collect()
+}
+
+/// Restores the exact NaNs recorded in `sidecar` into `values`, as read
+/// back from storage.
+///
+/// Fails with [`Error::SidecarLength`] if the sidecar has a different
+/// length, with [`Error::SidecarMismatch`] if it has an entry for a value
+/// that is not a NaN, and with the
+/// decoding error if an entry is not a valid tag-102 item of binary64 or
+/// narrower. `values` is unchanged on failure.
+pub fn apply_sidecar(
+    values: &mut [f64],
+    sidecar: &[Option<Vec<u8>>],
+) -> Result<()> {
+    if values.len() != sidecar.len() {
+        return Err(Error::SidecarLength {
+            values: values.len(),
+            sidecar: sidecar.len(),
+        });
+    }
+    let mut restored = Vec::new();
+    for (row, entry) in sidecar.iter().enumerate() {
+        let Some(data) = entry else { continue };
+        if !values[row].is_nan() {
+            return Err(Error::SidecarMismatch(row));
+        }
+        let nan = NanBstr::from_tagged_cbor_data(data)?;
+        restored.push((row, nan.to_f64_checked()?));
+    }
+    for (row, value) in restored {
+        values[row] = value;
+    }
+    Ok(())
+}
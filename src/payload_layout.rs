@@ -0,0 +1,172 @@
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+use crate::{Error, NanBstr, NanWidth, Result};
+
+/// A named range of payload bits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayloadField {
+    /// The field name.
+    pub name: String,
+    /// The position of the field's least significant bit, counting from
+    /// bit 0 of the payload.
+    pub lsb: u32,
+    /// The number of bits, from 1 to 64.
+    pub bits: u32,
+}
+
+impl PayloadField {
+    /// Returns a field of `bits` bits starting at payload bit `lsb`.
+    pub fn new(name: impl Into<String>, lsb: u32, bits: u32) -> Self {
+        Self {
+            name: name.into(),
+            lsb,
+            bits,
+        }
+    }
+
+    fn mask(&self) -> u128 {
+        ((1u128 << self.bits) - 1) << self.lsb
+    }
+}
+
+/// A payload bit layout defined at runtime, such as from a configuration
+/// file: an ordered list of named, non-overlapping bit ranges.
+///
+/// Payload bits not covered by any field must be zero when extracting, and
+/// are zero when composing. With the `serde` feature, a layout serializes as
+/// its list of fields and is validated when deserialized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Vec<PayloadField>", into = "Vec<PayloadField>")
+)]
+pub struct PayloadLayout {
+    fields: Vec<PayloadField>,
+}
+
+impl PayloadLayout {
+    /// Returns a layout of `fields`, failing with [`Error::InvalidLayout`]
+    /// if a field is empty or wider than 64 bits, or if two fields share a
+    /// name or overlap.
+    pub fn new(fields: Vec<PayloadField>) -> Result<Self> {
+        for (i, field) in fields.iter().enumerate() {
+            if !(1..=64).contains(&field.bits) {
+                return Err(invalid_layout(field, "must be 1 to 64 bits"));
+            }
+            let max_bits = NanWidth::Binary128.fraction_bits() - 1;
+            if field.lsb.saturating_add(field.bits) > max_bits {
+                return Err(invalid_layout(field, "exceeds every width"));
+            }
+            for earlier in &fields[..i] {
+                if earlier.name == field.name {
+                    return Err(invalid_layout(field, "is defined twice"));
+                }
+                if earlier.mask() & field.mask() != 0 {
+                    return Err(invalid_layout(
+                        field,
+                        &format!("overlaps `{}`", earlier.name),
+                    ));
+                }
+            }
+        }
+        Ok(Self { fields })
+    }
+
+    /// Returns the fields, in order.
+    pub fn fields(&self) -> &[PayloadField] {
+        &self.fields
+    }
+
+    /// Checks that every field fits in the payload of `width`, failing with
+    /// [`Error::InvalidLayout`] if one does not.
+    pub fn validate_for(&self, width: NanWidth) -> Result<()> {
+        let payload_bits = width.fraction_bits() - 1;
+        match self.fields.iter().find(|f| f.lsb + f.bits > payload_bits) {
+            Some(field) => Err(invalid_layout(
+                field,
+                &format!("exceeds the {}-bit payload", payload_bits),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the value of every field of `nan`'s payload.
+    ///
+    /// Fails with [`Error::InvalidLayout`] if the layout does not fit the
+    /// NaN's width, and with [`Error::UnassignedPayloadBits`] if payload bits
+    /// outside every field are set.
+    pub fn extract(&self, nan: &NanBstr) -> Result<BTreeMap<String, u64>> {
+        self.validate_for(nan.width())?;
+        let payload = nan.payload_bits();
+        let covered = self.fields.iter().fold(0, |acc, f| acc | f.mask());
+        if payload & !covered != 0 {
+            return Err(Error::UnassignedPayloadBits(payload & !covered));
+        }
+        Ok(self
+            .fields
+            .iter()
+            .map(|f| (f.name.clone(), ((payload & f.mask()) >> f.lsb) as u64))
+            .collect())
+    }
+
+    /// Assembles a NaN of `width` whose payload holds `values`. Fields
+    /// without a value are zero.
+    ///
+    /// Fails with [`Error::InvalidLayout`] if the layout does not fit
+    /// `width`, with [`Error::InvalidField`] if a value names no field or
+    /// does not fit in its field, and with [`Error::NotANan`] for a
+    /// signaling NaN whose payload is zero.
+    pub fn compose(
+        &self,
+        values: &BTreeMap<String, u64>,
+        width: NanWidth,
+        sign: bool,
+        quiet: bool,
+    ) -> Result<NanBstr> {
+        self.validate_for(width)?;
+        let mut payload = 0u128;
+        for (name, &value) in values {
+            let Some(field) = self.fields.iter().find(|f| &f.name == name)
+            else {
+                return Err(Error::InvalidField {
+                    field: name.clone(),
+                    reason: "is not in the layout".into(),
+                });
+            };
+            if field.bits < 64 && value >> field.bits != 0 {
+                return Err(Error::InvalidField {
+                    field: name.clone(),
+                    reason: format!(
+                        "value {} does not fit in {} bits",
+                        value, field.bits
+                    ),
+                });
+            }
+            payload |= (value as u128) << field.lsb;
+        }
+        NanBstr::from_parts(width, sign, quiet, payload)
+    }
+}
+
+impl TryFrom<Vec<PayloadField>> for PayloadLayout {
+    type Error = Error;
+
+    fn try_from(fields: Vec<PayloadField>) -> Result<Self> {
+        Self::new(fields)
+    }
+}
+
+impl From<PayloadLayout> for Vec<PayloadField> {
+    fn from(layout: PayloadLayout) -> Self {
+        layout.fields
+    }
+}
+
+fn invalid_layout(field: &PayloadField, reason: &str) -> Error {
+    Error::InvalidLayout {
+        field: field.name.clone(),
+        reason: reason.into(),
+    }
+}
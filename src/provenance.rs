@@ -0,0 +1,201 @@
+use crate::{NanBstr, NanWidth};
+
+/// A well-known NaN bit pattern, with a note on where it usually comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnownPattern {
+    /// Short, stable identifier for the pattern.
+    pub name: &'static str,
+    /// Human-readable note on where the pattern is typically produced.
+    pub description: &'static str,
+    /// The width of the pattern.
+    pub width: NanWidth,
+    /// The raw bit pattern, right-aligned.
+    pub bits: u128,
+}
+
+impl KnownPattern {
+    const fn new(
+        name: &'static str,
+        description: &'static str,
+        width: NanWidth,
+        bits: u128,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            width,
+            bits,
+        }
+    }
+}
+
+/// The table of well-known NaN patterns consulted by
+/// [`NanBstr::identify`].
+pub const KNOWN_PATTERNS: &[KnownPattern] = &[
+    KnownPattern::new(
+        "canonical-qnan-16",
+        "positive quiet NaN, zero payload (IEEE 754 default, dCBOR canonical)",
+        NanWidth::Binary16,
+        0x7E00,
+    ),
+    KnownPattern::new(
+        "canonical-qnan-32",
+        "positive quiet NaN, zero payload (ARM/RISC-V default, f32::NAN)",
+        NanWidth::Binary32,
+        0x7FC0_0000,
+    ),
+    KnownPattern::new(
+        "canonical-qnan-64",
+        "positive quiet NaN, zero payload (ARM/RISC-V default, f64::NAN)",
+        NanWidth::Binary64,
+        0x7FF8_0000_0000_0000,
+    ),
+    KnownPattern::new(
+        "canonical-qnan-128",
+        "positive quiet NaN, zero payload (IEEE 754 default)",
+        NanWidth::Binary128,
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000,
+    ),
+    KnownPattern::new(
+        "x86-indefinite-32",
+        "negative quiet NaN, zero payload (x86 SSE/x87 real indefinite)",
+        NanWidth::Binary32,
+        0xFFC0_0000,
+    ),
+    KnownPattern::new(
+        "x86-indefinite-64",
+        "negative quiet NaN, zero payload (x86 SSE/x87 real indefinite)",
+        NanWidth::Binary64,
+        0xFFF8_0000_0000_0000,
+    ),
+    KnownPattern::new(
+        "r-na-real",
+        "R's NA_real_ (signaling, payload 1954)",
+        NanWidth::Binary64,
+        0x7FF0_0000_0000_07A2,
+    ),
+    KnownPattern::new(
+        "r-na-real-quieted",
+        "R's NA_real_ after hardware quieting (payload 1954)",
+        NanWidth::Binary64,
+        0x7FF8_0000_0000_07A2,
+    ),
+    KnownPattern::new(
+        "minimal-snan-16",
+        "positive signaling NaN with payload 1",
+        NanWidth::Binary16,
+        0x7C01,
+    ),
+    KnownPattern::new(
+        "minimal-snan-32",
+        "positive signaling NaN with payload 1",
+        NanWidth::Binary32,
+        0x7F80_0001,
+    ),
+    KnownPattern::new(
+        "minimal-snan-64",
+        "positive signaling NaN with payload 1",
+        NanWidth::Binary64,
+        0x7FF0_0000_0000_0001,
+    ),
+    KnownPattern::new(
+        "minimal-snan-128",
+        "positive signaling NaN with payload 1",
+        NanWidth::Binary128,
+        0x7FFF_0000_0000_0000_0000_0000_0000_0001,
+    ),
+];
+
+/// A processor architecture whose default NaN is recognized by
+/// [`NanBstr::is_default_nan_for`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Arch {
+    /// x86 and x86-64 (x87, SSE, AVX-512 FP16): the negative "real
+    /// indefinite".
+    X86,
+    /// AArch32 and AArch64 with default NaN mode: the positive quiet NaN.
+    Arm,
+    /// RISC-V (Zfh, F, D, Q): the positive canonical NaN.
+    RiscV,
+}
+
+/// The NaN each architecture produces for an invalid operation, by width.
+/// Widths an architecture has no hardware support for are absent.
+pub const DEFAULT_NANS: &[(Arch, NanWidth, u128)] = &[
+    (Arch::X86, NanWidth::Binary16, 0xFE00),
+    (Arch::X86, NanWidth::Binary32, 0xFFC0_0000),
+    (Arch::X86, NanWidth::Binary64, 0xFFF8_0000_0000_0000),
+    (Arch::Arm, NanWidth::Binary16, 0x7E00),
+    (Arch::Arm, NanWidth::Binary32, 0x7FC0_0000),
+    (Arch::Arm, NanWidth::Binary64, 0x7FF8_0000_0000_0000),
+    (Arch::RiscV, NanWidth::Binary16, 0x7E00),
+    (Arch::RiscV, NanWidth::Binary32, 0x7FC0_0000),
+    (Arch::RiscV, NanWidth::Binary64, 0x7FF8_0000_0000_0000),
+    (
+        Arch::RiscV,
+        NanWidth::Binary128,
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000,
+    ),
+];
+
+impl NanBstr {
+    /// Returns true if this NaN is the default NaN `arch` produces at its
+    /// width, as listed in [`DEFAULT_NANS`].
+    pub fn is_default_nan_for(&self, arch: Arch) -> bool {
+        let (width, bits) = (self.width(), self.to_bits_u128());
+        DEFAULT_NANS
+            .iter()
+            .any(|&(a, w, b)| a == arch && w == width && b == bits)
+    }
+
+    /// Returns the architecture whose default NaN this is, if exactly one
+    /// produces it. Arm and RISC-V share their patterns, so those give
+    /// `None` except at binary128, which only RISC-V produces.
+    pub fn likely_origin(&self) -> Option<Arch> {
+        let (width, bits) = (self.width(), self.to_bits_u128());
+        let mut archs = DEFAULT_NANS
+            .iter()
+            .filter(|&&(_, w, b)| w == width && b == bits)
+            .map(|&(arch, ..)| arch);
+        let arch = archs.next()?;
+        archs.next().is_none().then_some(arch)
+    }
+
+    /// Returns the well-known pattern this NaN matches exactly, if any.
+    pub fn identify(&self) -> Option<KnownPattern> {
+        let bits = self.to_bits_u128();
+        KNOWN_PATTERNS
+            .iter()
+            .find(|p| p.width == self.width() && p.bits == bits)
+            .copied()
+    }
+
+    /// Returns the well-known pattern of the same width that is closest to
+    /// this NaN by Hamming distance, along with that distance, provided it is
+    /// at most `max_distance` bits.
+    ///
+    /// All bits of the pattern count, including the sign bit. Exact matches
+    /// have distance 0. Ties go to the pattern listed first in
+    /// [`KNOWN_PATTERNS`].
+    pub fn nearest_known(
+        &self,
+        max_distance: u32,
+    ) -> Option<(KnownPattern, u32)> {
+        nearest_known_bits(self.width(), self.to_bits_u128(), max_distance)
+    }
+}
+
+/// Returns the well-known pattern of `width` nearest to `bits` by Hamming
+/// distance, if within `max_distance`. `bits` need not be a NaN.
+pub(crate) fn nearest_known_bits(
+    width: NanWidth,
+    bits: u128,
+    max_distance: u32,
+) -> Option<(KnownPattern, u32)> {
+    KNOWN_PATTERNS
+        .iter()
+        .filter(|p| p.width == width)
+        .map(|p| (*p, (p.bits ^ bits).count_ones()))
+        .filter(|&(_, distance)| distance <= max_distance)
+        .min_by_key(|&(_, distance)| distance)
+}
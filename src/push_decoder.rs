@@ -0,0 +1,132 @@
+#[cfg(feature = "std")]
+use crate::{NanBstr, Result};
+use crate::{
+    NanBstrRef,
+    frame::{FrameError, MAX_FRAME_LEN, TAG_HEAD, frame_len},
+};
+
+/// A push parser for a stream of back-to-back tag-102 items, fed one byte or
+/// slice at a time as input arrives.
+///
+/// Items are framed exactly as by `NanBstrCodec`: a tag head `d8 66`, a byte
+/// string head, and the pattern. The parser's state is a fixed 19-byte
+/// buffer and it never allocates: a completed item is a [`NanBstrRef`] into
+/// that buffer, valid until the next push, and errors are [`FrameError`]s.
+/// With the `std` feature, [`push_owned`](Self::push_owned) and
+/// [`push_slice_owned`](Self::push_slice_owned) copy each item into a
+/// [`NanBstr`].
+///
+/// On an error the parser resets itself. If the byte that revealed an error
+/// in the heads is `0xD8`, it is kept as the possible start of the next
+/// item, so the parser resynchronizes on its own.
+#[derive(Debug, Clone, Default)]
+pub struct NanBstrDecoder {
+    buf: [u8; MAX_FRAME_LEN],
+    len: usize,
+}
+
+impl NanBstrDecoder {
+    /// Returns a parser with nothing buffered.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; MAX_FRAME_LEN],
+            len: 0,
+        }
+    }
+
+    /// Discards any partially received item.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the number of bytes of a partially received item.
+    pub fn buffered(&self) -> usize {
+        self.len
+    }
+
+    /// Pushes one byte, returning the item it completes or the error it
+    /// reveals.
+    pub fn push(
+        &mut self,
+        byte: u8,
+    ) -> Option<core::result::Result<NanBstrRef<'_>, FrameError>> {
+        let end = self.step(byte)?;
+        Some(end.and_then(|end| self.completed(end)))
+    }
+
+    /// Pushes bytes until one completes an item or reveals an error.
+    /// Returns the number of bytes consumed and that outcome, if any; push
+    /// the remaining bytes to continue.
+    pub fn push_slice(
+        &mut self,
+        bytes: &[u8],
+    ) -> (
+        usize,
+        Option<core::result::Result<NanBstrRef<'_>, FrameError>>,
+    ) {
+        for (i, &byte) in bytes.iter().enumerate() {
+            if let Some(end) = self.step(byte) {
+                return (i + 1, Some(end.and_then(|end| self.completed(end))));
+            }
+        }
+        (bytes.len(), None)
+    }
+
+    /// Like [`push`](Self::push), but copies a completed item into an owned
+    /// [`NanBstr`] and converts an error into [`Error`](crate::Error).
+    #[cfg(feature = "std")]
+    pub fn push_owned(&mut self, byte: u8) -> Option<Result<NanBstr>> {
+        self.push(byte)
+            .map(|result| result.map(NanBstr::from).map_err(Into::into))
+    }
+
+    /// Like [`push_slice`](Self::push_slice), but copies a completed item
+    /// into an owned [`NanBstr`] and converts an error into
+    /// [`Error`](crate::Error).
+    #[cfg(feature = "std")]
+    pub fn push_slice_owned(
+        &mut self,
+        bytes: &[u8],
+    ) -> (usize, Option<Result<NanBstr>>) {
+        let (consumed, result) = self.push_slice(bytes);
+        let result =
+            result.map(|result| result.map(NanBstr::from).map_err(Into::into));
+        (consumed, result)
+    }
+
+    /// Buffers one byte. Returns the end of the item it completes within the
+    /// buffer, or the error it reveals; either way the parser is reset.
+    fn step(
+        &mut self,
+        byte: u8,
+    ) -> Option<core::result::Result<usize, FrameError>> {
+        self.buf[self.len] = byte;
+        self.len += 1;
+        let result = match frame_len(&self.buf[..self.len]) {
+            Ok(None) => return None,
+            Ok(Some(len)) if !matches!(len - 3, 2 | 4 | 8 | 16) => {
+                Err(FrameError::InvalidLength(len - 3))
+            }
+            Ok(Some(len)) if self.len < len => return None,
+            Ok(Some(len)) => Ok(len),
+            Err(err) => {
+                self.reset();
+                if byte == TAG_HEAD[0] {
+                    self.step(byte);
+                }
+                return Some(Err(err));
+            }
+        };
+        self.reset();
+        Some(result)
+    }
+
+    /// Borrows the pattern of the item that ended at `end`. The length is
+    /// already a NaN width, so the only possible error is `NotANan`.
+    fn completed(
+        &self,
+        end: usize,
+    ) -> core::result::Result<NanBstrRef<'_>, FrameError> {
+        NanBstrRef::new(&self.buf[3..end]).map_err(|_| FrameError::NotANan)
+    }
+}
@@ -0,0 +1,67 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{NanBstr, NanWidth};
+
+impl Arbitrary for NanWidth {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&NanWidth::ALL).unwrap()
+    }
+
+    /// Shrinks toward binary16.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let width = *self;
+        Box::new(NanWidth::ALL.into_iter().take_while(move |w| *w != width))
+    }
+}
+
+/// Always produces a valid NaN of any width; a signaling NaN with a zero
+/// payload (an infinity) gets payload 1 instead.
+impl Arbitrary for NanBstr {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let width = NanWidth::arbitrary(g);
+        let sign = bool::arbitrary(g);
+        let quiet = bool::arbitrary(g);
+        let mut payload = u128::arbitrary(g) & width.max_payload();
+        if !quiet && payload == 0 {
+            payload = 1;
+        }
+        nan(width, sign, quiet, payload)
+    }
+
+    /// Shrinks toward the positive quiet binary16 NaN with a zero payload:
+    /// first the sign, then the quiet bit, then the payload toward zero, then
+    /// the width downward while the payload still fits. Every candidate is a
+    /// valid NaN.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (width, sign, quiet) = (self.width(), self.sign(), self.is_quiet());
+        let payload = self.payload_bits();
+        let floor = if quiet { 0 } else { 1 };
+
+        let mut candidates = Vec::new();
+        if sign {
+            candidates.push(nan(width, false, quiet, payload));
+        }
+        if !quiet {
+            candidates.push(nan(width, sign, true, payload));
+        }
+        if payload > floor {
+            candidates.push(nan(width, sign, quiet, floor));
+            if payload / 2 > floor {
+                candidates.push(nan(width, sign, quiet, payload / 2));
+            }
+            candidates.push(nan(width, sign, quiet, payload - 1));
+        }
+        candidates.extend(
+            width
+                .shrink()
+                .filter(|w| w.fits_payload(payload))
+                .map(|w| nan(w, sign, quiet, payload)),
+        );
+        Box::new(candidates.into_iter())
+    }
+}
+
+fn nan(width: NanWidth, sign: bool, quiet: bool, payload: u128) -> NanBstr {
+    NanBstr::from_parts(width, sign, quiet, payload)
+        .expect("fields are in range")
+}
@@ -0,0 +1,76 @@
+use rand::{Rng, RngExt, distr::Distribution};
+
+use crate::{NanBstr, NanWidth};
+
+/// Whether a [`NanDistribution`] draws quiet NaNs, signaling NaNs, or both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Quietness {
+    /// Quiet and signaling NaNs, each half the time.
+    #[default]
+    Any,
+    /// Quiet NaNs only.
+    Quiet,
+    /// Signaling NaNs only.
+    Signaling,
+}
+
+/// Draws NaNs of one width.
+///
+/// The sign and the payload are uniform; a signaling NaN's payload is
+/// drawn from `1..=max_payload`, since a zero payload would be an infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NanDistribution {
+    /// The width of the NaNs drawn.
+    pub width: NanWidth,
+    /// Which NaNs are drawn.
+    pub quietness: Quietness,
+}
+
+impl NanDistribution {
+    /// Returns the distribution of `quietness` NaNs of `width`.
+    pub fn new(width: NanWidth, quietness: Quietness) -> Self {
+        Self { width, quietness }
+    }
+}
+
+impl Distribution<NanBstr> for NanDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NanBstr {
+        let sign = rng.random();
+        let quiet = match self.quietness {
+            Quietness::Any => rng.random(),
+            Quietness::Quiet => true,
+            Quietness::Signaling => false,
+        };
+        let min_payload = if quiet { 0 } else { 1 };
+        let payload = rng.random_range(min_payload..=self.width.max_payload());
+        NanBstr::from_parts(self.width, sign, quiet, payload)
+            .expect("fields are in range")
+    }
+}
+
+impl NanBstr {
+    /// Draws a NaN of `width` with a uniform sign, quiet bit, and payload.
+    pub fn random<R: Rng + ?Sized>(width: NanWidth, rng: &mut R) -> Self {
+        NanDistribution::new(width, Quietness::Any).sample(rng)
+    }
+
+    /// Draws a quiet NaN of `width` with a uniform sign and payload.
+    pub fn random_quiet<R: Rng + ?Sized>(width: NanWidth, rng: &mut R) -> Self {
+        NanDistribution::new(width, Quietness::Quiet).sample(rng)
+    }
+
+    /// Draws a signaling NaN of `width` with a uniform sign and non-zero
+    /// payload.
+    pub fn random_signaling<R: Rng + ?Sized>(
+        width: NanWidth,
+        rng: &mut R,
+    ) -> Self {
+        NanDistribution::new(width, Quietness::Signaling).sample(rng)
+    }
+
+    /// Draws a NaN as [`NanBstr::random`] does, of a uniformly chosen width.
+    pub fn random_any_width<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let width = NanWidth::ALL[rng.random_range(0..NanWidth::ALL.len())];
+        Self::random(width, rng)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    NanBstr, Result,
+    head::{Head, read_byte_string},
+};
+
+/// Scans raw CBOR bytes for tag-102 items without building a tree,
+/// yielding the offset of each item's tag head and the decoded NaN or the
+/// reason it failed to decode.
+///
+/// `data` may hold one data item or a CBOR sequence. The scanner reads each
+/// head in turn and skips over string contents, so bytes that merely look
+/// like a tag-102 item inside a byte or text string are never reported,
+/// and tag 102 is recognized in any head encoding, not only `d8 66`. It
+/// keeps no state beyond the current offset, so it runs in constant memory
+/// over input of any size.
+///
+/// Compared with [`find_nan_bstrs`](crate::find_nan_bstrs), it reports
+/// offsets rather than paths, and it does not check that the data is
+/// well-formed beyond what skipping requires: unbalanced arrays and maps,
+/// invalid UTF-8, and non-deterministic encodings go unnoticed. A head that
+/// is truncated or uses a reserved value cannot be skipped, so it is
+/// yielded as an error and the scan ends there.
+pub fn scan_cbor_bytes(
+    data: &[u8],
+) -> impl Iterator<Item = (usize, Result<NanBstr>)> + '_ {
+    let mut offset = Some(0);
+    core::iter::from_fn(move || {
+        loop {
+            let start = offset.filter(|&o| o < data.len())?;
+            match scan_item(data, start) {
+                Ok((next, None)) => offset = Some(next),
+                Ok((next, Some(nan))) => {
+                    offset = Some(next);
+                    return Some((start, nan));
+                }
+                Err(e) => {
+                    offset = None;
+                    return Some((start, Err(e.into())));
+                }
+            }
+        }
+    })
+}
+
+/// Reads the head at `offset` and returns the offset of the next head,
+/// along with the decoded NaN if the head is tag 102.
+fn scan_item(
+    data: &[u8],
+    offset: usize,
+) -> dcbor::Result<(usize, Option<Result<NanBstr>>)> {
+    let head = Head::read(data, offset)?;
+    match (head.major, head.argument) {
+        (2 | 3, Some(len)) => {
+            let end = usize::try_from(len)
+                .ok()
+                .and_then(|len| head.end.checked_add(len))
+                .filter(|&end| end <= data.len())
+                .ok_or(dcbor::Error::Underrun)?;
+            Ok((end, None))
+        }
+        (6, Some(tag)) if tag == bc_tags::TAG_NAN_BSTR => {
+            let content = Head::read(data, head.end)?;
+            if content.major != 2 {
+                let error = dcbor::Error::WrongType.into();
+                return Ok((head.end, Some(Err(error))));
+            }
+            let (bytes, end) = read_byte_string(data, &content)
+                .ok_or(dcbor::Error::Underrun)?;
+            Ok((end, Some(NanBstr::from_be_bytes(bytes))))
+        }
+        _ => Ok((head.end, None)),
+    }
+}
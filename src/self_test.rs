@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::hint::black_box;
+
+/// Returns true if signaling NaNs survive being passed to and returned from
+/// a function by value as `f32` and `f64` on this host.
+///
+/// On targets whose calling convention moves floats through x87 registers
+/// (or otherwise performs a floating-point load/store), a signaling NaN may
+/// be quieted in transit. When this returns false, capture NaNs with the
+/// bits-first constructors such as [`NanBstr::from_f32_bits`] or
+/// [`NanBstr::from_f32_ref`] rather than `NanBstr::try_from(f32)`.
+///
+/// [`NanBstr::from_f32_bits`]: crate::NanBstr::from_f32_bits
+/// [`NanBstr::from_f32_ref`]: crate::NanBstr::from_f32_ref
+pub fn float_arg_passing_preserves_snan() -> bool {
+    const SNAN_32: u32 = 0x7F80_0123;
+    const SNAN_64: u64 = 0x7FF0_0000_0000_0123;
+    let f32_ok =
+        pass_f32(black_box(f32::from_bits(SNAN_32))).to_bits() == SNAN_32;
+    let f64_ok =
+        pass_f64(black_box(f64::from_bits(SNAN_64))).to_bits() == SNAN_64;
+    f32_ok && f64_ok
+}
+
+#[inline(never)]
+fn pass_f32(value: f32) -> f32 {
+    black_box(value)
+}
+
+#[inline(never)]
+fn pass_f64(value: f64) -> f64 {
+    black_box(value)
+}
+
+/// A floating-point operation exercised by [`propagation_self_test`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropagationOp {
+    /// `nan + 1.0`
+    Add,
+    /// `nan * 2.0`
+    Mul,
+    /// `nan.min(nan)`
+    Min,
+    /// `nan.max(nan)`
+    Max,
+    /// `nan.mul_add(2.0, 1.0)`
+    MulAdd,
+}
+
+impl PropagationOp {
+    /// Every operation, in the order they appear in a report.
+    pub const ALL: [PropagationOp; 5] =
+        [Self::Add, Self::Mul, Self::Min, Self::Max, Self::MulAdd];
+
+    fn apply(self, nan: f64) -> f64 {
+        let nan = black_box(nan);
+        match self {
+            Self::Add => nan + black_box(1.0),
+            Self::Mul => nan * black_box(2.0),
+            Self::Min => nan.min(black_box(nan)),
+            Self::Max => nan.max(black_box(nan)),
+            Self::MulAdd => nan.mul_add(black_box(2.0), black_box(1.0)),
+        }
+    }
+}
+
+/// Which attributes of a NaN survived one operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpPropagation {
+    /// The operation performed.
+    pub op: PropagationOp,
+    /// The payload of a quiet NaN operand survived.
+    pub payload: bool,
+    /// The sign of a negative quiet NaN operand survived.
+    pub sign: bool,
+    /// A signaling NaN operand was still signaling afterwards. IEEE 754
+    /// requires arithmetic to quiet signaling NaNs, so this is normally
+    /// false.
+    pub quietness: bool,
+}
+
+/// The result of [`propagation_self_test`], one entry per operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropagationReport {
+    /// The per-operation results, in the order of [`PropagationOp::ALL`].
+    pub ops: Vec<OpPropagation>,
+}
+
+impl PropagationReport {
+    /// Returns the result for `op`.
+    pub fn get(&self, op: PropagationOp) -> Option<&OpPropagation> {
+        self.ops.iter().find(|r| r.op == op)
+    }
+
+    /// Returns true if the payload and sign of quiet NaNs survived every
+    /// operation, in which case guarding NaNs through arithmetic on this
+    /// host is unnecessary.
+    pub fn preserves_quiet_nans(&self) -> bool {
+        self.ops.iter().all(|r| r.payload && r.sign)
+    }
+}
+
+/// Runs payload-carrying binary64 NaNs through `+`, `*`, `min`, `max`, and
+/// `mul_add` on this host and reports which NaN attributes survived each
+/// operation.
+///
+/// Operands pass through `black_box` so the compiler cannot fold the
+/// operations at compile time; the report reflects the hardware and math
+/// library actually in use. Use it to decide whether [`GuardedF64`] is
+/// needed in a deployment.
+///
+/// [`GuardedF64`]: crate::GuardedF64
+pub fn propagation_self_test() -> PropagationReport {
+    const QUIET_NEGATIVE: u64 = 0xFFF8_0000_0000_0123;
+    const SIGNALING: u64 = 0x7FF0_0000_0000_0123;
+    const SIGN: u64 = 1 << 63;
+    const QUIET: u64 = 1 << 51;
+    const PAYLOAD: u64 = QUIET - 1;
+
+    let ops = PropagationOp::ALL
+        .iter()
+        .map(|&op| {
+            let q = op.apply(f64::from_bits(QUIET_NEGATIVE));
+            let s = op.apply(f64::from_bits(SIGNALING));
+            let (q, s) = (q.is_nan().then(|| q.to_bits()), s.to_bits());
+            OpPropagation {
+                op,
+                payload: q
+                    .is_some_and(|q| q & PAYLOAD == QUIET_NEGATIVE & PAYLOAD),
+                sign: q.is_some_and(|q| q & SIGN == QUIET_NEGATIVE & SIGN),
+                quietness: f64::from_bits(s).is_nan() && s & QUIET == 0,
+            }
+        })
+        .collect();
+    PropagationReport { ops }
+}
@@ -0,0 +1,84 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::iter::FusedIterator;
+
+use crate::{Error, NanBstr, Result, frame::encode_frame, head::item_end};
+
+/// Decodes the items of a CBOR sequence (RFC 8742) as tag-102 items, in
+/// order.
+///
+/// Each item must be deterministic CBOR, as
+/// [`NanBstr::from_cbor_data`] requires. An item that is well-formed but
+/// not a valid tag-102 item yields an [`Error::SeqItem`] and the iteration
+/// moves on to the next item; one that is truncated or cannot be skipped
+/// yields an error and ends the iteration, since the next item cannot be
+/// found.
+#[derive(Debug, Clone)]
+pub struct NanBstrSeqIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> NanBstrSeqIter<'a> {
+    /// Returns an iterator over the items of `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// Returns the offset of the next item in the data.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Iterator for NanBstrSeqIter<'_> {
+    type Item = Result<NanBstr>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset >= self.data.len() {
+            return None;
+        }
+        let start = self.offset;
+        let result = match item_end(self.data, start) {
+            Ok(end) => {
+                self.offset = end;
+                NanBstr::from_cbor_data(&self.data[start..end])
+            }
+            Err(err) => {
+                self.done = true;
+                Err(err.into())
+            }
+        };
+        Some(result.map_err(|err| Error::SeqItem {
+            offset: start,
+            source: Box::new(err),
+        }))
+    }
+}
+
+impl FusedIterator for NanBstrSeqIter<'_> {}
+
+impl NanBstr {
+    /// Decodes a CBOR sequence whose items are all tag-102 items. Empty
+    /// data is an empty sequence. Fails with [`Error::SeqItem`] for the
+    /// first item that is not; see [`NanBstrSeqIter`].
+    pub fn decode_seq(data: &[u8]) -> Result<Vec<Self>> {
+        NanBstrSeqIter::new(data).collect()
+    }
+
+    /// Encodes `nans` as a CBOR sequence: their deterministic encodings,
+    /// concatenated.
+    pub fn encode_seq(nans: &[Self]) -> Vec<u8> {
+        let mut data =
+            Vec::with_capacity(nans.iter().map(Self::encoded_len).sum());
+        for nan in nans {
+            let (frame, len) = encode_frame(nan);
+            data.extend_from_slice(&frame[..len]);
+        }
+        data
+    }
+}
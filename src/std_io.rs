@@ -0,0 +1,31 @@
+use std::io::{Read, Write};
+
+use crate::{
+    NanBstr, Result,
+    frame::{MAX_FRAME_LEN, checked_frame_len, encode_frame},
+};
+
+impl NanBstr {
+    /// Reads one deterministically encoded tag-102 item from `r`, reading
+    /// no further than its end, so whatever follows stays in the stream.
+    ///
+    /// A stream that ends within the item fails with `Error::Io` of kind
+    /// `UnexpectedEof`; a wrong tag or content type with `Error::Cbor`; and a
+    /// pattern that is not a NaN with `Error::InvalidLength` or
+    /// `Error::NotANan`.
+    pub fn read_cbor_from(r: &mut impl Read) -> Result<Self> {
+        let mut frame = [0; MAX_FRAME_LEN];
+        r.read_exact(&mut frame[..3])?;
+        let len = checked_frame_len(&frame[..3])?;
+        r.read_exact(&mut frame[3..len])?;
+        Self::from_be_bytes(&frame[3..len])
+    }
+
+    /// Writes this NaN to `w` as a deterministic tag-102 item. Returns the
+    /// number of bytes written, [`NanBstr::encoded_len`].
+    pub fn write_cbor_to(&self, w: &mut impl Write) -> Result<usize> {
+        let (frame, len) = encode_frame(self);
+        w.write_all(&frame[..len])?;
+        Ok(len)
+    }
+}
@@ -0,0 +1,62 @@
+//! [`proptest`] strategies producing valid [`NanBstr`]s.
+//!
+//! Every strategy shrinks toward the positive quiet NaN with a zero payload,
+//! and those ranging over widths shrink toward binary16.
+
+use proptest::prelude::*;
+
+use crate::{NanBstr, NanPattern, NanWidth};
+
+/// Any width, shrinking toward binary16.
+pub fn any_width() -> impl Strategy<Value = NanWidth> {
+    (0..NanWidth::ALL.len()).prop_map(|i| NanWidth::ALL[i])
+}
+
+/// Any valid NaN of any width.
+pub fn any_nan() -> impl Strategy<Value = NanBstr> {
+    any_width().prop_flat_map(nan_of_width)
+}
+
+/// Any valid NaN of the given width.
+pub fn nan_of_width(width: NanWidth) -> impl Strategy<Value = NanBstr> {
+    (any::<bool>(), any::<bool>(), 0..=width.max_payload()).prop_map(
+        move |(sign, signaling, payload)| {
+            // A signaling NaN needs a non-zero payload.
+            let payload = if signaling { payload.max(1) } else { payload };
+            nan(width, sign, !signaling, payload)
+        },
+    )
+}
+
+/// Any quiet NaN of any width.
+pub fn quiet_nan() -> impl Strategy<Value = NanBstr> {
+    any_width().prop_flat_map(|width| {
+        (any::<bool>(), 0..=width.max_payload())
+            .prop_map(move |(sign, payload)| nan(width, sign, true, payload))
+    })
+}
+
+/// Any signaling NaN of any width.
+pub fn signaling_nan() -> impl Strategy<Value = NanBstr> {
+    any_width().prop_flat_map(|width| {
+        (any::<bool>(), 1..=width.max_payload())
+            .prop_map(move |(sign, payload)| nan(width, sign, false, payload))
+    })
+}
+
+/// Any valid NaN that `pattern` matches.
+///
+/// Candidates are drawn from [`any_nan`] and filtered, so a pattern that
+/// matches only a tiny fraction of NaNs (such as an exact binary128
+/// payload) will exhaust proptest's rejection budget.
+pub fn nan_matching(pattern: &NanPattern) -> impl Strategy<Value = NanBstr> {
+    let pattern = pattern.clone();
+    any_nan().prop_filter("NaN does not match pattern", move |nan| {
+        pattern.matches(nan)
+    })
+}
+
+fn nan(width: NanWidth, sign: bool, quiet: bool, payload: u128) -> NanBstr {
+    NanBstr::from_parts(width, sign, quiet, payload)
+        .expect("fields are in range")
+}
@@ -0,0 +1,83 @@
+use alloc::{format, string::{String, ToString}, vec::Vec};
+
+use crate::NanBstr;
+
+const HEADERS: [&str; 6] = ["label", "width", "sign", "q/s", "payload", "raw"];
+
+/// Renders labeled NaNs as an aligned text table, one row per NaN, with
+/// columns for the label, width in bits, sign, quiet (`q`) or signaling
+/// (`s`), payload, and raw pattern, both in hex.
+///
+/// When rows disagree on a field, a final marker row puts `^` under each
+/// such column, so the differences stand out when lining up observations of
+/// the same value. Rows of mixed widths compare by value: payloads `0x1` of
+/// binary32 and binary64 agree, while their raw patterns differ.
+///
+/// ```
+/// use cbor_nan_bstr::{NanBstr, render_table};
+///
+/// let sent = NanBstr::from_binary32_bits(0x7F80_0001).unwrap();
+/// let received = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+/// let table = render_table(&[("sent", &sent), ("received", &received)]);
+/// assert_eq!(
+///     table,
+///     "label     width  sign  q/s  payload  raw\n\
+///      --------  -----  ----  ---  -------  --------\n\
+///      sent      32     +     s    0x1      7f800001\n\
+///      received  32     +     q    0x1      7fc00001\n\
+///      \x20                      ^^^           ^^^^^^^^\n"
+/// );
+/// ```
+pub fn render_table(items: &[(&str, &NanBstr)]) -> String {
+    let rows: Vec<[String; 6]> = items
+        .iter()
+        .map(|(label, nan)| {
+            [
+                label.to_string(),
+                nan.width().bits().to_string(),
+                if nan.sign() { "-" } else { "+" }.to_string(),
+                if nan.is_quiet() { "q" } else { "s" }.to_string(),
+                format!("0x{:x}", nan.payload_bits()),
+                hex::encode(nan.as_bytes()),
+            ]
+        })
+        .collect();
+    let widths: [usize; 6] = core::array::from_fn(|col| {
+        rows.iter()
+            .map(|row| row[col].len())
+            .fold(HEADERS[col].len(), usize::max)
+    });
+    // The label column is not a field, so it never differs.
+    let differs: [bool; 6] = core::array::from_fn(|col| {
+        col > 0 && rows.iter().any(|row| row[col] != rows[0][col])
+    });
+
+    let mut out = String::new();
+    let mut line = |cells: [&str; 6]| {
+        let text = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        out.push_str(text.trim_end());
+        out.push('\n');
+    };
+    line(HEADERS);
+    let rules = widths.map(|width| "-".repeat(width));
+    line(rules.each_ref().map(String::as_str));
+    for row in &rows {
+        line(row.each_ref().map(String::as_str));
+    }
+    if differs.contains(&true) {
+        let markers: [String; 6] = core::array::from_fn(|col| {
+            if differs[col] {
+                "^".repeat(widths[col])
+            } else {
+                String::new()
+            }
+        });
+        line(markers.each_ref().map(String::as_str));
+    }
+    out
+}
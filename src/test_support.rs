@@ -0,0 +1,386 @@
+//! Helpers for testing and fuzzing code that handles tag 102.
+
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use dcbor::{HexFormatOpts, TagsStore, TagsStoreOpt, prelude::*};
+
+use crate::{MaybeNan, NanBstr, NanWidth};
+
+/// Returns the tokens of a fuzzing dictionary for tag-102 decoders: the tag
+/// head, the byte string head for each valid length, and for each width the
+/// canonical quiet NaN, the minimal and maximal signaling NaNs, and the
+/// all-ones NaN, both bare and as complete tagged items.
+///
+/// The dictionary also holds the classic near misses: the positive and
+/// negative infinities of each width, which differ from a NaN only in having
+/// a zero fraction.
+pub fn fuzz_dictionary() -> Vec<Vec<u8>> {
+    dictionary_entries()
+        .into_iter()
+        .map(|(_, bytes)| bytes)
+        .collect()
+}
+
+/// Writes [`fuzz_dictionary`] in AFL dictionary syntax, one
+/// `name="\xNN..."` line per token.
+pub fn write_afl_dictionary(w: &mut impl Write) -> io::Result<()> {
+    for (name, bytes) in dictionary_entries() {
+        write!(w, "{}=\"", name)?;
+        for b in bytes {
+            write!(w, "\\x{:02x}", b)?;
+        }
+        writeln!(w, "\"")?;
+    }
+    Ok(())
+}
+
+fn dictionary_entries() -> Vec<(String, Vec<u8>)> {
+    let mut entries = vec![("tag_head".to_string(), vec![0xD8, 0x66])];
+    for width in NanWidth::ALL {
+        entries.push((
+            format!("bstr_head_{}", width.len()),
+            vec![0x40 | width.len() as u8],
+        ));
+    }
+    for width in NanWidth::ALL {
+        let bits = width.bits();
+        let nans = [
+            ("qnan", NanBstr::from_parts(width, false, true, 0)),
+            ("snan_min", NanBstr::from_parts(width, false, false, 1)),
+            (
+                "snan_max",
+                NanBstr::from_parts(width, false, false, width.max_payload()),
+            ),
+            (
+                "nan_all_ones",
+                NanBstr::from_parts(width, true, true, width.max_payload()),
+            ),
+        ];
+        for (name, nan) in nans {
+            let nan = nan.expect("fields are in range");
+            entries
+                .push((format!("{}_{}", name, bits), nan.as_bytes().to_vec()));
+            entries.push((
+                format!("{}_{}_tagged", name, bits),
+                nan.to_cbor_data(),
+            ));
+        }
+        for (name, sign) in [("pos_inf", 0u128), ("neg_inf", 1)] {
+            let exponent = (1u128 << width.exponent_bits()) - 1;
+            let pattern =
+                sign << (bits - 1) | exponent << width.fraction_bits();
+            entries.push((
+                format!("{}_{}", name, bits),
+                pattern.to_be_bytes()[16 - width.len()..].to_vec(),
+            ));
+        }
+    }
+    entries
+}
+
+/// Asserts that a value's deterministic CBOR encoding is the given hex,
+/// which may contain whitespace. Works for any `CBOREncodable`.
+///
+/// On failure, panics with a diff of the annotated encodings (see
+/// [`annotated_hex`](crate::test_support::annotated_hex)).
+///
+/// ```
+/// use cbor_nan_bstr::{NanBstr, assert_encodes_as};
+///
+/// let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+/// assert_encodes_as!(nan, "d866 42 7e00");
+/// ```
+#[macro_export]
+macro_rules! assert_encodes_as {
+    ($value:expr, $hex:expr $(,)?) => {
+        if let Err(message) =
+            $crate::test_support::check_encodes_as(&$value, $hex)
+        {
+            panic!("{}", message);
+        }
+    };
+}
+
+/// Asserts that the given CBOR hex, which may contain whitespace, decodes
+/// to a value equal to `expected`. Works for any type that is both
+/// `CBORDecodable` and `CBOREncodable`.
+///
+/// On failure, panics with the decoding error or a diff of the annotated
+/// encodings.
+///
+/// ```
+/// use cbor_nan_bstr::{NanBstr, assert_decodes_to};
+///
+/// let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+/// assert_decodes_to!("d866427e00", nan);
+/// ```
+#[macro_export]
+macro_rules! assert_decodes_to {
+    ($hex:expr, $expected:expr $(,)?) => {
+        if let Err(message) =
+            $crate::test_support::check_decodes_to($hex, &$expected)
+        {
+            panic!("{}", message);
+        }
+    };
+}
+
+/// The check behind [`assert_encodes_as!`](crate::assert_encodes_as),
+/// returning the failure message instead of panicking.
+pub fn check_encodes_as<T: CBOREncodable>(
+    value: &T,
+    expected_hex: &str,
+) -> Result<(), String> {
+    let expected = parse_hex(expected_hex)?;
+    let actual = value.to_cbor_data();
+    if actual == expected {
+        return Ok(());
+    }
+    Err(format!(
+        "encoding mismatch\nexpected: {}\n  actual: {}\n{}",
+        hex::encode(&expected),
+        hex::encode(&actual),
+        annotated_diff(&expected, &actual)
+    ))
+}
+
+/// The check behind [`assert_decodes_to!`](crate::assert_decodes_to),
+/// returning the failure message instead of panicking.
+pub fn check_decodes_to<T>(hex: &str, expected: &T) -> Result<(), String>
+where
+    T: CBORDecodable + CBOREncodable + PartialEq + fmt::Debug,
+{
+    let data = parse_hex(hex)?;
+    let decoded = CBOR::try_from_data(&data).and_then(T::try_from);
+    let actual = match decoded {
+        Ok(actual) => actual,
+        Err(err) => {
+            return Err(format!(
+                "decoding failed: {}\ninput: {}\n{}",
+                err,
+                hex::encode(&data),
+                annotated_hex(&data)
+            ));
+        }
+    };
+    if actual == *expected {
+        return Ok(());
+    }
+    Err(format!(
+        "decoded value differs from expected\nexpected: {:?}\n  actual: \
+         {:?}\n{}",
+        expected,
+        actual,
+        annotated_diff(&expected.to_cbor_data(), &data)
+    ))
+}
+
+/// Returns dcbor's annotated hex dump of the CBOR `data`, with tag 102
+/// named and the content of each tag-102 item described as a NaN, or as
+/// invalid NaN content. Data that is not deterministic CBOR is dumped as
+/// plain hex.
+pub fn annotated_hex(data: &[u8]) -> String {
+    let Ok(cbor) = CBOR::try_from_data(data) else {
+        return format!("{} # not deterministic CBOR", hex::encode(data));
+    };
+    let tags = TagsStore::new([Tag::new(bc_tags::TAG_NAN_BSTR, "nan-bstr")]);
+    let opts = HexFormatOpts::default()
+        .annotate(true)
+        .context(TagsStoreOpt::Custom(&tags));
+    let mut lines: Vec<String> =
+        cbor.hex_opt(&opts).lines().map(String::from).collect();
+    for i in 0..lines.len().saturating_sub(2) {
+        let tag_note = format!("# tag({})", bc_tags::TAG_NAN_BSTR);
+        if !lines[i].contains(&tag_note) || !lines[i + 1].contains("# bytes(") {
+            continue;
+        }
+        let column = lines[i].find('#').expect("annotated line");
+        let content = &lines[i + 2];
+        let hex_part = content.split('#').next().unwrap_or_default();
+        let digits: String = hex_part.split_whitespace().collect();
+        let Ok(bytes) = hex::decode(&digits) else {
+            continue;
+        };
+        let note = MaybeNan::from_be_bytes(&bytes).to_string();
+        lines[i + 2] = format!(
+            "{:<column$}# {}",
+            hex_part.trim_end().to_string() + " ",
+            note,
+            column = column
+        );
+    }
+    lines.join("\n")
+}
+
+fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits: String = hex.split_whitespace().collect();
+    hex::decode(&digits)
+        .map_err(|err| format!("invalid hex `{}`: {}", hex, err))
+}
+
+/// Diffs the annotated dumps of two encodings line by line, marking lines
+/// only in `expected` with `-` and lines only in `actual` with `+`.
+fn annotated_diff(expected: &[u8], actual: &[u8]) -> String {
+    let expected = annotated_hex(expected);
+    let actual = annotated_hex(actual);
+    let (a, b): (Vec<&str>, Vec<&str>) =
+        (expected.lines().collect(), actual.lines().collect());
+    // Longest common subsequence, filled from the end.
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut out = String::from("diff (- expected, + actual):");
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            out += &format!("\n  {}", a[i]);
+            i += 1;
+            j += 1;
+        } else if j == b.len()
+            || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1])
+        {
+            out += &format!("\n- {}", a[i]);
+            i += 1;
+        } else {
+            out += &format!("\n+ {}", b[j]);
+            j += 1;
+        }
+    }
+    out
+}
+
+/// Asserts that two native floats have identical bits, so `0.0` and `-0.0`
+/// differ and NaNs are equal only with the same pattern. Works for `f32`
+/// and `f64`.
+///
+/// On failure, a NaN operand is shown decomposed, and when both are NaNs
+/// the message includes a [`render_table`](crate::render_table) of the two.
+///
+/// ```
+/// use cbor_nan_bstr::assert_float_bits_eq;
+///
+/// assert_float_bits_eq!(f64::from_bits(0x7FF8_0000_0000_0001), {
+///     f64::from_bits(0x7FF8_0000_0000_0001)
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_float_bits_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Err(message) =
+            $crate::test_support::check_float_bits_eq($left, $right)
+        {
+            panic!("{}", message);
+        }
+    };
+}
+
+/// Asserts that two native floats are equal as numbers, treating every NaN
+/// as equal to every other and `0.0` as equal to `-0.0`. Works for `f32`
+/// and `f64`.
+///
+/// ```
+/// use cbor_nan_bstr::assert_float_semantic_eq;
+///
+/// assert_float_semantic_eq!(f64::NAN, -f64::NAN);
+/// assert_float_semantic_eq!(0.0f32, -0.0f32);
+/// ```
+#[macro_export]
+macro_rules! assert_float_semantic_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Err(message) =
+            $crate::test_support::check_float_semantic_eq($left, $right)
+        {
+            panic!("{}", message);
+        }
+    };
+}
+
+/// A native float accepted by the float assertions.
+pub trait NativeFloat: Copy + fmt::Debug + PartialEq {
+    /// Returns the bits of the value, zero-extended.
+    fn bits(self) -> u64;
+
+    /// Returns the value as a `NanBstr`, if it is a NaN.
+    fn to_nan_bstr(self) -> Option<NanBstr>;
+}
+
+impl NativeFloat for f32 {
+    fn bits(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn to_nan_bstr(self) -> Option<NanBstr> {
+        NanBstr::try_from(self).ok()
+    }
+}
+
+impl NativeFloat for f64 {
+    fn bits(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn to_nan_bstr(self) -> Option<NanBstr> {
+        NanBstr::try_from(self).ok()
+    }
+}
+
+/// The check behind [`assert_float_bits_eq!`](crate::assert_float_bits_eq),
+/// returning the failure message instead of panicking.
+pub fn check_float_bits_eq<F: NativeFloat>(
+    left: F,
+    right: F,
+) -> Result<(), String> {
+    if left.bits() == right.bits() {
+        return Ok(());
+    }
+    Err(float_mismatch("float bits differ", left, right))
+}
+
+/// The check behind
+/// [`assert_float_semantic_eq!`](crate::assert_float_semantic_eq), returning
+/// the failure message instead of panicking.
+pub fn check_float_semantic_eq<F: NativeFloat>(
+    left: F,
+    right: F,
+) -> Result<(), String> {
+    let both_nan =
+        left.to_nan_bstr().is_some() && right.to_nan_bstr().is_some();
+    if both_nan || left == right {
+        return Ok(());
+    }
+    Err(float_mismatch("floats differ", left, right))
+}
+
+fn float_mismatch<F: NativeFloat>(title: &str, left: F, right: F) -> String {
+    let describe = |value: F| {
+        let digits = core::mem::size_of::<F>() * 2;
+        let bits = format!("0x{:0digits$x}", value.bits(), digits = digits);
+        match value.to_nan_bstr() {
+            Some(nan) => format!("{} ({})", nan, bits),
+            None => format!("{:?} ({})", value, bits),
+        }
+    };
+    let mut message = format!(
+        "{}\n  left: {}\n right: {}",
+        title,
+        describe(left),
+        describe(right)
+    );
+    if let (Some(l), Some(r)) = (left.to_nan_bstr(), right.to_nan_bstr()) {
+        message.push('\n');
+        message.push_str(
+            crate::render_table(&[("left", &l), ("right", &r)]).trim_end(),
+        );
+    }
+    message
+}
@@ -0,0 +1,78 @@
+use core::cmp::Ordering;
+
+use crate::{NanBstr, NanWidth};
+
+impl NanBstr {
+    /// Compares two NaNs by the IEEE 754 `totalOrder` predicate, extended
+    /// across widths.
+    ///
+    /// Both NaNs are first widened to binary128 as
+    /// [`widen_to`](Self::widen_to) does, which preserves sign, quietness,
+    /// and payload. Positive NaNs then order signaling before quiet and by
+    /// ascending payload; negative NaNs come before all positive NaNs, in
+    /// the reverse order. NaNs that widen to the same pattern are ordered by
+    /// width, narrowest first, so the result is `Equal` only for identical
+    /// patterns.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        total_order_key(self)
+            .cmp(&total_order_key(other))
+            .then_with(|| self.width().len().cmp(&other.width().len()))
+    }
+}
+
+/// Maps the widened pattern to an integer whose signed order is
+/// `totalOrder`, as `f64::total_cmp` does.
+fn total_order_key(nan: &NanBstr) -> i128 {
+    let bits = nan
+        .widen_to(NanWidth::Binary128)
+        .expect("binary128 is the widest width")
+        .to_bits_u128() as i128;
+    bits ^ (((bits >> 127) as u128) >> 1) as i128
+}
+
+/// A [`NanBstr`] ordered by [`NanBstr::total_cmp`] rather than by its
+/// bytes, for choosing the ordering of a collection such as
+/// `BTreeMap<ByTotalOrder, usize>`.
+///
+/// Equality and hashing are those of the inner `NanBstr`, which agree with
+/// this ordering. `Borrow<NanBstr>` is deliberately not implemented: lookups
+/// by `&NanBstr` would use the byte ordering and so disagree with this one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ByTotalOrder(pub NanBstr);
+
+impl ByTotalOrder {
+    /// Returns the wrapped NaN.
+    pub fn into_inner(self) -> NanBstr {
+        self.0
+    }
+}
+
+impl Ord for ByTotalOrder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for ByTotalOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<NanBstr> for ByTotalOrder {
+    fn from(nan: NanBstr) -> Self {
+        Self(nan)
+    }
+}
+
+impl From<ByTotalOrder> for NanBstr {
+    fn from(nan: ByTotalOrder) -> Self {
+        nan.0
+    }
+}
+
+impl AsRef<NanBstr> for ByTotalOrder {
+    fn as_ref(&self) -> &NanBstr {
+        &self.0
+    }
+}
@@ -0,0 +1,11 @@
+/// What to do when a NaN's payload does not fit a narrower width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TruncationPolicy {
+    /// Fail with [`Error::PayloadTooLarge`](crate::Error::PayloadTooLarge).
+    #[default]
+    Reject,
+    /// Keep the low payload bits that fit, preserving sign and quietness. A
+    /// signaling NaN whose kept bits are all zero would become an infinity,
+    /// so it is made quiet instead.
+    Truncate,
+}
@@ -0,0 +1,63 @@
+use alloc::{format, string::String};
+
+use crate::{NanBstr, NanWidth};
+
+/// Handles a NaN according to its width, receiving the bit pattern as the
+/// native unsigned integer of that width.
+///
+/// Pass a visitor to [`NanBstr::visit`], which calls exactly one method.
+pub trait NanVisitor {
+    /// The result of visiting.
+    type Output;
+
+    /// Visits a binary16 NaN.
+    fn visit_b16(self, bits: u16) -> Self::Output;
+
+    /// Visits a binary32 NaN.
+    fn visit_b32(self, bits: u32) -> Self::Output;
+
+    /// Visits a binary64 NaN.
+    fn visit_b64(self, bits: u64) -> Self::Output;
+
+    /// Visits a binary128 NaN.
+    fn visit_b128(self, bits: u128) -> Self::Output;
+}
+
+impl NanBstr {
+    /// Calls the method of `visitor` for this NaN's width with its bit
+    /// pattern.
+    pub fn visit<V: NanVisitor>(&self, visitor: V) -> V::Output {
+        let bits = self.to_bits_u128();
+        match self.width() {
+            NanWidth::Binary16 => visitor.visit_b16(bits as u16),
+            NanWidth::Binary32 => visitor.visit_b32(bits as u32),
+            NanWidth::Binary64 => visitor.visit_b64(bits as u64),
+            NanWidth::Binary128 => visitor.visit_b128(bits),
+        }
+    }
+}
+
+/// A [`NanVisitor`] that formats the bit pattern as a suffixed Rust integer
+/// literal, such as `0x7fc00001_u32`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BitsLiteral;
+
+impl NanVisitor for BitsLiteral {
+    type Output = String;
+
+    fn visit_b16(self, bits: u16) -> String {
+        format!("0x{:04x}_u16", bits)
+    }
+
+    fn visit_b32(self, bits: u32) -> String {
+        format!("0x{:08x}_u32", bits)
+    }
+
+    fn visit_b64(self, bits: u64) -> String {
+        format!("0x{:016x}_u64", bits)
+    }
+
+    fn visit_b128(self, bits: u128) -> String {
+        format!("0x{:032x}_u128", bits)
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+use core::{fmt, ops::ControlFlow};
+
+use dcbor::prelude::*;
+
+use crate::{NanBstr, NanBstrRef, Result};
+
+/// One step from a CBOR item to one of its children.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PathElement {
+    /// The element at the given index of an array.
+    Index(usize),
+    /// The value stored under the given key of a map.
+    Value(CBOR),
+    /// The given key of a map (itself a CBOR item that may contain NaNs).
+    Key(CBOR),
+    /// The content of a tagged item with the given tag value.
+    Tagged(TagValue),
+}
+
+/// The location of an item within a CBOR tree, as a sequence of steps from
+/// the root.
+///
+/// Displayed as `$` followed by one segment per step: `[3]` for an array
+/// index, `[k]` for the value under map key `k`, `{k}` for the map key `k`
+/// itself, and `#t` for the content of tag `t`, with keys rendered in flat
+/// diagnostic notation. For example, `$["readings"][2]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CborPath(Vec<PathElement>);
+
+impl CborPath {
+    /// Returns the path to the root item.
+    pub fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns the steps of this path, from the root.
+    pub fn elements(&self) -> &[PathElement] {
+        &self.0
+    }
+
+    /// Returns true if this is the path to the root item.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn push(&mut self, element: PathElement) {
+        self.0.push(element);
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.0.pop();
+    }
+}
+
+impl From<Vec<PathElement>> for CborPath {
+    fn from(elements: Vec<PathElement>) -> Self {
+        Self(elements)
+    }
+}
+
+impl fmt::Display for CborPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "$")?;
+        for element in &self.0 {
+            match element {
+                PathElement::Index(i) => write!(f, "[{}]", i)?,
+                PathElement::Value(k) => {
+                    write!(f, "[{}]", k.diagnostic_flat())?
+                }
+                PathElement::Key(k) => {
+                    write!(f, "{{{}}}", k.diagnostic_flat())?
+                }
+                PathElement::Tagged(t) => write!(f, "#{}", t)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns every valid tag-102 item in `cbor`, with its path, in document
+/// order.
+///
+/// Map keys are searched as well as values. Tag-102 items whose content is
+/// not a valid NaN byte string are skipped.
+pub fn find_nan_bstrs(cbor: &CBOR) -> Vec<(CborPath, NanBstr)> {
+    let mut found = Vec::new();
+    for_each_nan_bstr(cbor, |path, nan| {
+        found.push((path.clone(), nan.to_owned()));
+        ControlFlow::Continue(())
+    });
+    found
+}
+
+/// Returns every valid tag-102 item in `cbor`, with its path, in document
+/// order, as views borrowing the document's bytes.
+///
+/// Like [`find_nan_bstrs`], but without copying any NaN.
+pub fn find_nan_bstr_refs(cbor: &CBOR) -> Vec<(CborPath, NanBstrRef<'_>)> {
+    let mut found = Vec::new();
+    for_each_nan_bstr(cbor, |path, nan| {
+        found.push((path.clone(), nan));
+        ControlFlow::Continue(())
+    });
+    found
+}
+
+/// Calls `f` for every valid tag-102 item in `cbor`, with its path, in
+/// document order, until `f` returns [`ControlFlow::Break`]. Returns true if
+/// the traversal completed.
+///
+/// Like [`find_nan_bstr_refs`], but nothing is collected and nothing more
+/// is visited once `f` breaks. Each NaN is passed as a view borrowing the
+/// document's bytes; call [`NanBstrRef::to_owned`] to keep one.
+pub fn for_each_nan_bstr<'a>(
+    cbor: &'a CBOR,
+    mut f: impl FnMut(&CborPath, NanBstrRef<'a>) -> ControlFlow<()>,
+) -> bool {
+    walk(cbor, &mut CborPath::root(), &mut |path, content| {
+        match NanBstrRef::from_untagged_cbor(content) {
+            Ok(nan) => f(path, nan),
+            Err(_) => ControlFlow::Continue(()),
+        }
+    })
+    .is_continue()
+}
+
+/// Returns true if `cbor` holds a valid tag-102 item that is not a positive
+/// quiet NaN with a zero payload, stopping at the first one found.
+pub fn contains_noncanonical_nan(cbor: &CBOR) -> bool {
+    walk(cbor, &mut CborPath::root(), &mut |_, content| {
+        match NanBstrRef::from_untagged_cbor(content) {
+            Ok(nan)
+                if nan.sign() || nan.is_signaling() || nan.payload_bits() != 0 =>
+            {
+                ControlFlow::Break(())
+            }
+            _ => ControlFlow::Continue(()),
+        }
+    })
+    .is_break()
+}
+
+/// Calls `visit` for every tag-102 item in `cbor`, in document order, with
+/// the item's raw content and the decoded value or the reason it failed to
+/// decode.
+pub(crate) fn walk_nan_bstrs(
+    cbor: &CBOR,
+    visit: &mut impl FnMut(&CborPath, &CBOR, Result<NanBstr>),
+) {
+    let _ = walk(cbor, &mut CborPath::root(), &mut |path, content| {
+        let nan = NanBstrRef::from_untagged_cbor(content).map(|n| n.to_owned());
+        visit(path, content, nan);
+        ControlFlow::Continue(())
+    });
+}
+
+/// Calls `visit` with the content of every tag-102 item in `cbor`, until it
+/// breaks.
+fn walk<'a>(
+    cbor: &'a CBOR,
+    path: &mut CborPath,
+    visit: &mut impl FnMut(&CborPath, &'a CBOR) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    match cbor.as_case() {
+        CBORCase::Tagged(tag, content) => {
+            if tag.value() == bc_tags::TAG_NAN_BSTR {
+                visit(path, content)
+            } else {
+                walk_child(content, PathElement::Tagged(tag.value()), path, visit)
+            }
+        }
+        CBORCase::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                walk_child(item, PathElement::Index(i), path, visit)?;
+            }
+            ControlFlow::Continue(())
+        }
+        CBORCase::Map(map) => {
+            for (key, value) in map.iter() {
+                walk_child(key, PathElement::Key(key.clone()), path, visit)?;
+                walk_child(value, PathElement::Value(key.clone()), path, visit)?;
+            }
+            ControlFlow::Continue(())
+        }
+        _ => ControlFlow::Continue(()),
+    }
+}
+
+fn walk_child<'a>(
+    child: &'a CBOR,
+    element: PathElement,
+    path: &mut CborPath,
+    visit: &mut impl FnMut(&CborPath, &'a CBOR) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    path.push(element);
+    let flow = walk(child, path, visit);
+    path.pop();
+    flow
+}
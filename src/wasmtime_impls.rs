@@ -0,0 +1,80 @@
+use wasmtime::{AsContextMut, Instance, Val, ValType};
+
+use crate::{Error, NanBstr, PayloadVerdict, Result};
+
+/// The binary32 NaN [`check_roundtrip_through`] sends: signaling, with a
+/// payload of alternating bits.
+pub const WASM_PROBE_F32: u32 = 0x7FA5_A5A5;
+
+/// The binary64 NaN [`check_roundtrip_through`] sends: signaling, with a
+/// payload of alternating bits.
+pub const WASM_PROBE_F64: u64 = 0x7FF5_A5A5_A5A5_A5A5;
+
+impl NanBstr {
+    /// Construct from a wasmtime `f32` value, keeping its exact bits.
+    /// Returns `Error::WasmValType` for a value of any other type and
+    /// `Error::NotANan` for an `f32` that is not a NaN.
+    pub fn from_wasm_f32(val: &Val) -> Result<Self> {
+        match val {
+            Val::F32(bits) => Self::from_binary32_bits(*bits),
+            _ => Err(Error::WasmValType("f32")),
+        }
+    }
+
+    /// Construct from a wasmtime `f64` value, keeping its exact bits.
+    /// Returns `Error::WasmValType` for a value of any other type and
+    /// `Error::NotANan` for an `f64` that is not a NaN.
+    pub fn from_wasm_f64(val: &Val) -> Result<Self> {
+        match val {
+            Val::F64(bits) => Self::from_binary64_bits(*bits),
+            _ => Err(Error::WasmValType("f64")),
+        }
+    }
+}
+
+/// Calls the exported function `func_name` of `instance` with a signaling,
+/// payloaded NaN ([`WASM_PROBE_F32`] or [`WASM_PROBE_F64`]) and reports what
+/// happened to its bit pattern.
+///
+/// The function must have type `[f32] -> [f32]` or `[f64] -> [f64]` and
+/// should return its argument, possibly after arithmetic that the Wasm
+/// specification allows to canonicalize NaNs, such as `f64.add` with `-0`.
+/// Fails with `Error::Wasm` if the function is missing, has another type,
+/// or traps.
+pub fn check_roundtrip_through(
+    mut store: impl AsContextMut,
+    instance: &Instance,
+    func_name: &str,
+) -> Result<PayloadVerdict> {
+    let func = instance
+        .get_func(&mut store, func_name)
+        .ok_or_else(|| Error::Wasm(format!("no function `{}`", func_name)))?;
+    let ty = func.ty(&store);
+    let params: Vec<ValType> = ty.params().collect();
+    let results: Vec<ValType> = ty.results().collect();
+    let probe = match (params.as_slice(), results.as_slice()) {
+        ([ValType::F32], [ValType::F32]) => Val::F32(WASM_PROBE_F32),
+        ([ValType::F64], [ValType::F64]) => Val::F64(WASM_PROBE_F64),
+        _ => {
+            return Err(Error::Wasm(format!(
+                "`{}` must have type [f32] -> [f32] or [f64] -> [f64]",
+                func_name
+            )));
+        }
+    };
+    let original = match probe {
+        Val::F32(_) => NanBstr::from_wasm_f32(&probe)?,
+        _ => NanBstr::from_wasm_f64(&probe)?,
+    };
+    let mut returned = [Val::I32(0)];
+    func.call(&mut store, &[probe], &mut returned)
+        .map_err(|err| {
+            Error::Wasm(format!("`{}` failed: {}", func_name, err))
+        })?;
+    let current = match returned[0] {
+        Val::F32(bits) => bits.to_be_bytes().to_vec(),
+        Val::F64(bits) => bits.to_be_bytes().to_vec(),
+        _ => unreachable!("result type was checked"),
+    };
+    Ok(PayloadVerdict::between(&original, &current))
+}
@@ -0,0 +1,218 @@
+use alloc::{string::{String, ToString}, vec::Vec};
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub},
+    str::FromStr,
+};
+
+use crate::{Error, NanWidth, Result};
+
+/// A set of [`NanWidth`]s, stored as bit flags.
+///
+/// Sets combine with `|` (union), `&` (intersection), and `-` (difference),
+/// and iterate narrowest first. The text form, produced by `Display` and
+/// accepted by `FromStr`, lists the widths in bits separated by `|`, such as
+/// `16|32|64`; the empty set is `none`. With the `serde` feature, a set
+/// serializes as its text form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "String", try_from = "String")
+)]
+pub struct WidthSet(u8);
+
+impl WidthSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+    /// Only binary16.
+    pub const BINARY16: Self = Self::of(NanWidth::Binary16);
+    /// Only binary32.
+    pub const BINARY32: Self = Self::of(NanWidth::Binary32);
+    /// Only binary64.
+    pub const BINARY64: Self = Self::of(NanWidth::Binary64);
+    /// Only binary128.
+    pub const BINARY128: Self = Self::of(NanWidth::Binary128);
+    /// The widths narrower than binary128, which an `f64` holds exactly.
+    pub const NARROW: Self =
+        Self(Self::BINARY16.0 | Self::BINARY32.0 | Self::BINARY64.0);
+    /// Every width.
+    pub const ALL: Self = Self(Self::NARROW.0 | Self::BINARY128.0);
+
+    /// Returns the set holding only `width`.
+    pub const fn of(width: NanWidth) -> Self {
+        Self(1 << width as u8)
+    }
+
+    /// Returns true if `width` is in the set.
+    pub const fn contains(self, width: NanWidth) -> bool {
+        self.0 & Self::of(width).0 != 0
+    }
+
+    /// Returns true if every width of `other` is in the set.
+    pub const fn is_superset(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of widths in the set.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the widths in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the widths in both sets.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the widths in this set but not `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Adds `width` to the set.
+    pub fn insert(&mut self, width: NanWidth) {
+        *self |= Self::of(width);
+    }
+
+    /// Removes `width` from the set.
+    pub fn remove(&mut self, width: NanWidth) {
+        *self = self.difference(Self::of(width));
+    }
+
+    /// Returns the widths in the set, narrowest first.
+    pub fn iter(self) -> impl Iterator<Item = NanWidth> {
+        NanWidth::ALL.into_iter().filter(move |&w| self.contains(w))
+    }
+
+    /// Writes the widths in bits separated by `separator`, or `none`.
+    pub(crate) fn fmt_list(
+        self,
+        f: &mut fmt::Formatter<'_>,
+        separator: char,
+    ) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "none");
+        }
+        for (i, width) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", separator)?;
+            }
+            write!(f, "{}", width.bits())?;
+        }
+        Ok(())
+    }
+
+    /// Parses what [`fmt_list`](Self::fmt_list) writes, allowing whitespace
+    /// around each width.
+    pub(crate) fn parse_list(s: &str, separator: char) -> Option<Self> {
+        if s.trim() == "none" {
+            return Some(Self::EMPTY);
+        }
+        s.split(separator)
+            .map(|w| NanWidth::from_bits_str(w.trim()))
+            .collect()
+    }
+}
+
+impl From<NanWidth> for WidthSet {
+    fn from(width: NanWidth) -> Self {
+        Self::of(width)
+    }
+}
+
+impl FromIterator<NanWidth> for WidthSet {
+    fn from_iter<I: IntoIterator<Item = NanWidth>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::EMPTY, |set, w| set | Self::of(w))
+    }
+}
+
+impl Extend<NanWidth> for WidthSet {
+    fn extend<I: IntoIterator<Item = NanWidth>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|width| self.insert(width));
+    }
+}
+
+impl IntoIterator for WidthSet {
+    type Item = NanWidth;
+    type IntoIter = alloc::vec::IntoIter<NanWidth>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().collect::<Vec<_>>().into_iter()
+    }
+}
+
+impl BitOr for WidthSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for WidthSet {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for WidthSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl BitAndAssign for WidthSet {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = self.intersection(other);
+    }
+}
+
+impl Sub for WidthSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl fmt::Display for WidthSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_list(f, '|')
+    }
+}
+
+impl FromStr for WidthSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_list(s, '|')
+            .ok_or_else(|| Error::InvalidWidthSet(s.to_string()))
+    }
+}
+
+impl From<WidthSet> for String {
+    fn from(set: WidthSet) -> Self {
+        set.to_string()
+    }
+}
+
+impl TryFrom<String> for WidthSet {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "arbitrary")]
+
+use std::collections::HashSet;
+
+use arbitrary::{Arbitrary, Unstructured};
+use cbor_nan_bstr::{NanBstr, NanWidth};
+use dcbor::prelude::*;
+
+/// Deterministic pseudo-random bytes (xorshift64).
+fn noise(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_nans_are_valid_and_cover_all_widths() {
+    let mut widths = HashSet::new();
+    let (mut quiet, mut signaling, mut negative) = (0, 0, 0);
+    for seed in 0..4000 {
+        let bytes = noise(seed, 32);
+        let mut u = Unstructured::new(&bytes);
+        let nan = NanBstr::arbitrary(&mut u).unwrap();
+
+        let reparsed = NanBstr::from_be_bytes(nan.as_bytes()).unwrap();
+        assert_eq!(reparsed, nan);
+        let decoded = NanBstr::try_from(CBOR::from(nan.clone())).unwrap();
+        assert_eq!(decoded, nan);
+
+        widths.insert(nan.width());
+        quiet += nan.is_quiet() as usize;
+        signaling += nan.is_signaling() as usize;
+        negative += nan.sign() as usize;
+    }
+    assert_eq!(widths.len(), 4);
+    assert!(quiet > 1000 && signaling > 1000 && negative > 1000);
+}
+
+#[test]
+fn arbitrary_from_exhausted_input_is_still_valid() {
+    // With no bytes left, every draw takes its minimum: a signaling NaN with
+    // the smallest payload it can have, one.
+    let mut u = Unstructured::new(&[]);
+    let nan = NanBstr::arbitrary(&mut u).unwrap();
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7C01).unwrap());
+}
+
+#[test]
+fn arbitrary_width_uses_every_variant() {
+    let widths: HashSet<NanWidth> = (0..=3u8)
+        .map(|b| NanWidth::arbitrary(&mut Unstructured::new(&[b])).unwrap())
+        .collect();
+    assert_eq!(widths.len(), 4);
+    assert_eq!(NanBstr::size_hint(0), (4, Some(18)));
+}
+
+#[test]
+fn arbitrary_consumes_within_size_hint() {
+    let (min, max) = NanBstr::size_hint(0);
+    let max = max.unwrap();
+    let mut widest = 0;
+    for seed in 0..4000 {
+        let bytes = noise(seed, 64);
+        let mut u = Unstructured::new(&bytes);
+        NanBstr::arbitrary(&mut u).unwrap();
+        let consumed = bytes.len() - u.len();
+        assert!((min..=max).contains(&consumed), "consumed {consumed}");
+        widest = widest.max(consumed);
+    }
+    assert_eq!(widest, max);
+}
+
+#[test]
+fn arbitrary_reaches_payload_edges() {
+    let (mut quiet_zero, mut signaling_one, mut max, mut negative) =
+        (0, 0, 0, 0);
+    for seed in 0..4000 {
+        let bytes = noise(seed, 32);
+        let nan = NanBstr::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let payload = nan.payload_bits();
+        quiet_zero += (nan.is_quiet() && payload == 0) as usize;
+        signaling_one += (nan.is_signaling() && payload == 1) as usize;
+        max += (payload == nan.width().max_payload()) as usize;
+        negative += nan.sign() as usize;
+    }
+    // Each edge is chosen a quarter of the time the quiet bit matches.
+    assert!(
+        quiet_zero > 300 && signaling_one > 300,
+        "{quiet_zero} {signaling_one}"
+    );
+    assert!(max > 600, "{max}");
+    assert!(negative > 1000, "{negative}");
+}
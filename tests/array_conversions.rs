@@ -0,0 +1,46 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+use hex_literal::hex;
+
+#[test]
+fn arrays_of_each_width() {
+    let nan = NanBstr::try_from(hex!("7e00")).unwrap();
+    assert_eq!(nan.width(), NanWidth::Binary16);
+    let bytes: [u8; 2] = nan.as_bytes().try_into().unwrap();
+    assert_eq!(NanBstr::try_from(bytes).unwrap(), nan);
+
+    let nan = NanBstr::try_from(0x7F80_0001u32.to_be_bytes()).unwrap();
+    assert!(nan.is_signaling());
+    let bytes: [u8; 4] = nan.as_bytes().try_into().unwrap();
+    assert_eq!(NanBstr::try_from(&bytes).unwrap(), nan);
+
+    let nan = NanBstr::try_from(f64::NAN.to_bits().to_be_bytes()).unwrap();
+    assert_eq!(nan.width(), NanWidth::Binary64);
+    let bytes: [u8; 8] = nan.as_bytes().try_into().unwrap();
+    assert_eq!(NanBstr::try_from(bytes).unwrap(), nan);
+
+    let bits = 0x7FFF_8000_0000_0000_0000_0000_0000_0001u128;
+    let nan = NanBstr::try_from(&bits.to_be_bytes()).unwrap();
+    assert_eq!(nan, NanBstr::from_binary128_bits(bits).unwrap());
+    let bytes: [u8; 16] = nan.as_bytes().try_into().unwrap();
+    assert_eq!(bytes, bits.to_be_bytes());
+}
+
+#[test]
+fn arrays_that_are_not_nans() {
+    let infinities: [Result<NanBstr, Error>; 4] = [
+        NanBstr::try_from(hex!("7c00")),
+        NanBstr::try_from(&hex!("ff800000")),
+        NanBstr::try_from(f64::INFINITY.to_bits().to_be_bytes()),
+        NanBstr::try_from([
+            0x7F, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]),
+    ];
+    for result in infinities {
+        assert!(matches!(result, Err(Error::NotANan)));
+    }
+    assert!(matches!(
+        NanBstr::try_from(1.5f64.to_bits().to_be_bytes()),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(NanBstr::try_from([0u8; 4]), Err(Error::NotANan)));
+}
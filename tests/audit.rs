@@ -0,0 +1,249 @@
+use cbor_nan_bstr::{
+    CborPath, NanBstr, NanPolicy, NanWidth, PathElement, PolicyViolation,
+    WidthSet, audit_report, find_nan_bstrs,
+};
+use dcbor::prelude::*;
+use indoc::indoc;
+
+fn fixture() -> CBOR {
+    let readings: Vec<CBOR> = vec![
+        1.5.into(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap().into(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap().into(),
+        NanBstr::from_binary32_bits(0x7FC0_0123).unwrap().into(),
+    ];
+    let na = NanBstr::from_binary64_bits(0x7FF0_0000_0000_07A2).unwrap();
+
+    let mut map = Map::new();
+    map.insert("sensor", "a");
+    map.insert("readings", readings);
+    map.insert("meta", CBOR::to_tagged_value(40000, na));
+    map.insert(
+        "bad",
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0x7C, 0x00])),
+    );
+    map.into()
+}
+
+#[test]
+fn find_nan_bstrs_reports_paths_in_document_order() {
+    let found = find_nan_bstrs(&fixture());
+    let paths: Vec<String> = found.iter().map(|(p, _)| p.to_string()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            r#"$["meta"]#40000"#,
+            r#"$["readings"][1]"#,
+            r#"$["readings"][2]"#,
+            r#"$["readings"][3]"#,
+        ]
+    );
+    assert_eq!(
+        found[1].0,
+        CborPath::from(vec![
+            PathElement::Value("readings".into()),
+            PathElement::Index(1)
+        ])
+    );
+    assert_eq!(found[1].1, NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
+
+#[test]
+fn find_nan_bstrs_searches_map_keys() {
+    let mut map = Map::new();
+    map.insert(NanBstr::from_binary16_bits(0x7E01).unwrap(), "key is a NaN");
+    let found = find_nan_bstrs(&map.into());
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0.to_string(), "${102(h'7e01')}");
+}
+
+#[test]
+fn root_nan_has_root_path() {
+    let cbor = CBOR::from(NanBstr::from_binary16_bits(0x7E00).unwrap());
+    let found = find_nan_bstrs(&cbor);
+    assert!(found[0].0.is_root());
+    assert_eq!(found[0].0.to_string(), "$");
+}
+
+#[test]
+fn policy_violations() {
+    let policy = NanPolicy {
+        allowed_widths: WidthSet::BINARY16 | WidthSet::BINARY64,
+        ..NanPolicy::canonical_only()
+    };
+    let ok = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert!(policy.permits(&ok));
+
+    let bad = NanBstr::from_binary32_bits(0xFF80_0001).unwrap();
+    assert_eq!(
+        policy.violations(&bad),
+        vec![
+            PolicyViolation::WidthNotAllowed(NanWidth::Binary32),
+            PolicyViolation::Negative,
+            PolicyViolation::Signaling,
+            PolicyViolation::Payload(1),
+        ]
+    );
+    assert!(NanPolicy::default().permits(&bad));
+}
+
+#[test]
+fn identify_known_patterns() {
+    let nan = NanBstr::try_from(f64::NAN).unwrap();
+    assert_eq!(nan.identify().unwrap().name, "canonical-qnan-64");
+    let x86 = NanBstr::from_binary32_bits(0xFFC0_0000).unwrap();
+    assert_eq!(x86.identify().unwrap().name, "x86-indefinite-32");
+    let odd = NanBstr::from_binary32_bits(0x7FC0_1234).unwrap();
+    assert!(odd.identify().is_none());
+}
+
+#[test]
+fn nearest_known_counts_differing_bits() {
+    let exact = NanBstr::from_binary32_bits(0xFFC0_0000).unwrap();
+    let (pattern, distance) = exact.nearest_known(0).unwrap();
+    assert_eq!((pattern.name, distance), ("x86-indefinite-32", 0));
+
+    // Differs from x86-indefinite-32 only in the low payload bit.
+    let near = NanBstr::from_binary32_bits(0xFFC0_0001).unwrap();
+    let (pattern, distance) = near.nearest_known(4).unwrap();
+    assert_eq!((pattern.name, distance), ("x86-indefinite-32", 1));
+
+    // The sign bit counts: 0xFF80_0001 is minimal-snan-32 with sign set.
+    let signed = NanBstr::from_binary32_bits(0xFF80_0001).unwrap();
+    let (pattern, distance) = signed.nearest_known(4).unwrap();
+    assert_eq!((pattern.name, distance), ("minimal-snan-32", 1));
+}
+
+#[test]
+fn nearest_known_respects_cutoff() {
+    // 0x123 has four bits set, so canonical-qnan-32 is 4 bits away. So is
+    // minimal-snan-32, but ties go to the earlier table entry.
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0123).unwrap();
+    assert!(nan.nearest_known(3).is_none());
+    let (pattern, distance) = nan.nearest_known(4).unwrap();
+    assert_eq!((pattern.name, distance), ("canonical-qnan-32", 4));
+}
+
+#[test]
+fn nearest_known_compares_same_width_only() {
+    // Every binary16 pattern is far from this, but the binary64 table is not
+    // consulted even with an unlimited cutoff.
+    let nan = NanBstr::from_binary16_bits(0xFFFF).unwrap();
+    let (pattern, _) = nan.nearest_known(u32::MAX).unwrap();
+    assert_eq!(pattern.width, NanWidth::Binary16);
+}
+
+#[test]
+fn audit_report_markdown_without_policy() {
+    let report = audit_report(&fixture(), None);
+    assert_eq!(report.count(), 4);
+    assert_eq!(report.passes(), None);
+    assert_eq!(
+        report.to_string(),
+        indoc! {r#"
+        # NaN Audit Report
+
+        ## Summary
+
+        | Metric | Count |
+        | --- | ---: |
+        | total | 4 |
+        | binary16 | 1 |
+        | binary32 | 2 |
+        | binary64 | 1 |
+        | binary128 | 0 |
+        | quiet | 2 |
+        | signaling | 2 |
+        | payload | 3 |
+        | negative | 1 |
+        | malformed | 1 |
+
+        ## NaNs
+
+        | Path | Width | Class | Sign | Payload | Provenance |
+        | --- | --- | --- | --- | --- | --- |
+        | `$["meta"]#40000` | binary64 | signaling | + | 0x7a2 | r-na-real |
+        | `$["readings"][1]` | binary16 | quiet | + | 0x0 | canonical-qnan-16 |
+        | `$["readings"][2]` | binary32 | signaling | - | 0x1 | ~minimal-snan-32 (1 bit) |
+        | `$["readings"][3]` | binary32 | quiet with payload | + | 0x123 | unknown |
+
+        ## Malformed tag-102 items
+
+        | Path | Reason | Nearest known |
+        | --- | --- | --- |
+        | `$["bad"]` | not a NaN bit pattern | ~canonical-qnan-16 (1 bit) |
+        "#}
+    );
+}
+
+#[test]
+fn audit_report_markdown_with_policy() {
+    let report = audit_report(&fixture(), Some(&NanPolicy::canonical_only()));
+    assert_eq!(report.violation_count(), 3);
+    assert_eq!(report.passes(), Some(false));
+    assert_eq!(
+        report.to_string(),
+        indoc! {r#"
+        # NaN Audit Report
+
+        ## Summary
+
+        | Metric | Count |
+        | --- | ---: |
+        | total | 4 |
+        | binary16 | 1 |
+        | binary32 | 2 |
+        | binary64 | 1 |
+        | binary128 | 0 |
+        | quiet | 2 |
+        | signaling | 2 |
+        | payload | 3 |
+        | negative | 1 |
+        | malformed | 1 |
+        | violations | 3 |
+
+        Policy verdict: **FAIL**
+
+        ## NaNs
+
+        | Path | Width | Class | Sign | Payload | Provenance | Policy |
+        | --- | --- | --- | --- | --- | --- | --- |
+        | `$["meta"]#40000` | binary64 | signaling | + | 0x7a2 | r-na-real | signaling NaN not allowed; payload 0x7a2 not allowed |
+        | `$["readings"][1]` | binary16 | quiet | + | 0x0 | canonical-qnan-16 | ok |
+        | `$["readings"][2]` | binary32 | signaling | - | 0x1 | ~minimal-snan-32 (1 bit) | negative NaN not allowed; signaling NaN not allowed; payload 0x1 not allowed |
+        | `$["readings"][3]` | binary32 | quiet with payload | + | 0x123 | unknown | payload 0x123 not allowed |
+
+        ## Malformed tag-102 items
+
+        | Path | Reason | Nearest known |
+        | --- | --- | --- |
+        | `$["bad"]` | not a NaN bit pattern | ~canonical-qnan-16 (1 bit) |
+        "#}
+    );
+}
+
+#[test]
+fn audit_report_cbor() {
+    let mut map = Map::new();
+    map.insert("x", NanBstr::from_binary16_bits(0x7E00).unwrap());
+    let report = audit_report(&map.into(), Some(&NanPolicy::canonical_only()));
+    let cbor = CBOR::from(report);
+    assert_eq!(
+        cbor.diagnostic_flat(),
+        r#"{"nans": [{"nan": 102(h'7e00'), "path": "$[\"x\"]", "class": "quiet", "provenance": "canonical-qnan-16", "violations": []}], "counts": {"quiet": 1, "total": 1, "payload": 0, "binary16": 1, "binary32": 0, "binary64": 0, "negative": 0, "binary128": 0, "malformed": 0, "signaling": 0, "violations": 0}, "verdict": "pass", "malformed": []}"#
+    );
+}
+
+#[test]
+fn audit_report_cbor_nearest() {
+    let mut map = Map::new();
+    map.insert("x", NanBstr::from_binary16_bits(0x7E01).unwrap());
+    let report = audit_report(&map.into(), None);
+    let (pattern, distance) = report.entries[0].nearest.unwrap();
+    assert_eq!((pattern.name, distance), ("canonical-qnan-16", 1));
+    let cbor = CBOR::from(report);
+    assert_eq!(
+        cbor.diagnostic_flat(),
+        r#"{"nans": [{"nan": 102(h'7e01'), "path": "$[\"x\"]", "class": "quiet with payload", "nearest": {"name": "canonical-qnan-16", "distance": 1}}], "counts": {"quiet": 1, "total": 1, "payload": 1, "binary16": 1, "binary32": 0, "binary64": 0, "negative": 0, "binary128": 0, "malformed": 0, "signaling": 0}, "malformed": []}"#
+    );
+}
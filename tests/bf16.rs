@@ -0,0 +1,76 @@
+#![cfg(feature = "bf16")]
+
+use cbor_nan_bstr::{Bf16Nan, Error, NanBstr, NanWidth};
+
+#[test]
+fn validation_matches_half_exhaustively() {
+    for bits in 0..=u16::MAX {
+        let is_nan = half::bf16::from_bits(bits).is_nan();
+        assert_eq!(Bf16Nan::from_bits(bits).is_ok(), is_nan, "0x{bits:04x}");
+    }
+}
+
+#[test]
+fn widening_round_trips_every_nan() {
+    for sign in [0u16, 0x8000] {
+        for fraction in 1..=0x7Fu16 {
+            let bits = sign | 0x7F80 | fraction;
+            let nan = Bf16Nan::from_bits(bits).unwrap();
+            assert_eq!(nan.fraction_bits(), fraction);
+
+            let wide = nan.to_nan_bstr();
+            assert_eq!(wide.width(), NanWidth::Binary32);
+            assert_eq!(wide.sign(), nan.sign());
+            assert_eq!(wide.is_quiet(), nan.is_quiet());
+            assert_eq!(wide.fraction_bits(), (fraction as u128) << 16);
+            assert_eq!(
+                f32::try_from(wide.clone()).unwrap().to_bits() >> 16,
+                bits as u32
+            );
+
+            assert_eq!(Bf16Nan::from_nan_bstr(&wide).unwrap(), nan);
+        }
+    }
+}
+
+#[test]
+fn accessors() {
+    let nan = Bf16Nan::from_bits(0xFFC5).unwrap();
+    assert!(nan.sign());
+    assert!(nan.is_quiet());
+    assert_eq!(nan.payload_bits(), 0x05);
+    assert_eq!(nan.to_string(), "NaN[bf16]: - quiet frac=0x45 payload=0x5");
+    let snan = Bf16Nan::from_bits(0x7F81).unwrap();
+    assert!(snan.is_signaling());
+}
+
+#[test]
+fn narrowing_rejects_low_bits_and_other_widths() {
+    let wide = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert!(matches!(
+        Bf16Nan::from_nan_bstr(&wide),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary32,
+            payload: 1
+        })
+    ));
+    let narrow = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert!(matches!(
+        Bf16Nan::try_from(&narrow),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary32,
+            actual: NanWidth::Binary16
+        })
+    ));
+}
+
+#[test]
+fn half_conversions() {
+    let value = half::bf16::NAN;
+    let nan = Bf16Nan::try_from(value).unwrap();
+    assert_eq!(half::bf16::from(nan).to_bits(), value.to_bits());
+    assert!(matches!(
+        Bf16Nan::try_from(half::bf16::INFINITY),
+        Err(Error::NotANan)
+    ));
+}
@@ -0,0 +1,56 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn widening_round_trips_every_nan() {
+    for sign in [0u16, 0x8000] {
+        for fraction in 1..=0x7Fu16 {
+            let bits = sign | 0x7F80 | fraction;
+            let nan = NanBstr::from_bfloat16_bits(bits).unwrap();
+            assert_eq!(nan.width(), NanWidth::Binary32);
+            assert_eq!(nan.sign(), sign != 0);
+            assert_eq!(nan.is_quiet(), fraction & 0x40 != 0);
+            assert_eq!(nan.fraction_bits(), (fraction as u128) << 16);
+            assert_eq!(nan.to_bfloat16_bits(), Some(bits));
+        }
+    }
+}
+
+#[test]
+fn payload_is_left_aligned() {
+    let nan = NanBstr::from_bfloat16_bits(0x7F81).unwrap();
+    assert_eq!(nan, NanBstr::from_binary32_bits(0x7F81_0000).unwrap());
+    assert!(nan.is_signaling());
+    assert_eq!(nan.payload_bits(), 0x1_0000);
+}
+
+#[test]
+fn rejects_non_nans() {
+    for bits in [0x0000, 0x3F80, 0x7F80, 0xFF80, 0x7F00, 0x8001] {
+        assert!(
+            matches!(NanBstr::from_bfloat16_bits(bits), Err(Error::NotANan)),
+            "0x{bits:04x}"
+        );
+    }
+}
+
+#[test]
+fn narrowing_needs_zero_low_bits() {
+    let lossy = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(lossy.to_bfloat16_bits(), None);
+    let lossy = NanBstr::from_binary32_bits(0x7F80_8000).unwrap();
+    assert_eq!(lossy.to_bfloat16_bits(), None);
+
+    let exact = NanBstr::from_binary32_bits(0xFFC1_0000).unwrap();
+    assert_eq!(exact.to_bfloat16_bits(), Some(0xFFC1));
+}
+
+#[test]
+fn narrowing_needs_binary32() {
+    for nan in [
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap(),
+        NanBstr::from_binary128_bits(u128::MAX).unwrap(),
+    ] {
+        assert_eq!(nan.to_bfloat16_bits(), None);
+    }
+}
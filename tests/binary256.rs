@@ -0,0 +1,110 @@
+use cbor_nan_bstr::{Error, NanBstr};
+use dcbor::prelude::*;
+
+fn tagged_32_bytes() -> CBOR {
+    let mut bytes = [0u8; 32];
+    bytes[..3].copy_from_slice(&[0x7F, 0xFF, 0xF8]);
+    CBOR::to_tagged_value(102, CBOR::to_byte_string(bytes))
+}
+
+#[test]
+fn nan_bstr_always_rejects_32_bytes() {
+    assert!(matches!(
+        NanBstr::try_from(tagged_32_bytes()),
+        Err(dcbor::Error::Custom(_))
+    ));
+    assert!(matches!(
+        NanBstr::from_be_bytes([0xFF; 32]),
+        Err(Error::InvalidLength(32))
+    ));
+}
+
+#[cfg(feature = "binary256")]
+mod binary256 {
+    use cbor_nan_bstr::{Binary256Nan, Error, NanTagProfile};
+    use dcbor::prelude::*;
+    use hex_literal::hex;
+
+    use super::tagged_32_bytes;
+
+    const QUIET: [u8; 32] = hex!(
+        "7ffff800 00000000 00000000 00000000 00000000 00000000 00000000 00000000"
+    );
+    const SIGNALING: [u8; 32] = hex!(
+        "fffff000 00000000 00000000 00000000 00000000 00000000 00000000 00000123"
+    );
+
+    #[test]
+    fn validation() {
+        assert!(Binary256Nan::from_be_bytes(QUIET).is_ok());
+        assert!(Binary256Nan::from_be_bytes(SIGNALING).is_ok());
+
+        // Infinity: exponent all ones, zero significand.
+        let mut infinity = [0u8; 32];
+        infinity[..3].copy_from_slice(&hex!("7ffff0"));
+        assert!(matches!(
+            Binary256Nan::from_be_bytes(infinity),
+            Err(Error::NotANan)
+        ));
+
+        // The lowest exponent bit clear.
+        let mut finite = QUIET;
+        finite[2] = 0xE8;
+        assert!(matches!(
+            Binary256Nan::from_be_bytes(finite),
+            Err(Error::NotANan)
+        ));
+    }
+
+    #[test]
+    fn accessors() {
+        let quiet = Binary256Nan::from_be_bytes(QUIET).unwrap();
+        assert!(!quiet.sign());
+        assert!(quiet.is_quiet());
+        assert!(quiet.is_payload_zero());
+        assert_eq!(quiet.fraction_bytes()[2], 0x08);
+        assert_eq!(quiet.to_string(), "NaN[256]: + quiet payload=0x0");
+
+        let signaling = Binary256Nan::from_be_bytes(SIGNALING).unwrap();
+        assert!(signaling.sign());
+        assert!(signaling.is_signaling());
+        assert!(!signaling.is_payload_zero());
+        assert_eq!(signaling.payload_bytes()[30..], [0x01, 0x23]);
+        assert_eq!(signaling.payload_bytes()[..30], [0u8; 30]);
+        assert_eq!(signaling.as_bytes(), &SIGNALING);
+        assert_eq!(
+            signaling.to_string(),
+            "NaN[256]: - signaling payload=0x123"
+        );
+    }
+
+    #[test]
+    fn cbor_requires_opt_in() {
+        let nan = Binary256Nan::from_be_bytes(QUIET).unwrap();
+        assert!(matches!(
+            nan.to_cbor(NanTagProfile::default()),
+            Err(Error::InvalidLength(32))
+        ));
+        assert!(matches!(
+            Binary256Nan::from_cbor(&tagged_32_bytes(), NanTagProfile::Strict),
+            Err(Error::InvalidLength(32))
+        ));
+
+        let cbor = nan.to_cbor(NanTagProfile::AllowBinary256).unwrap();
+        assert_eq!(cbor, tagged_32_bytes());
+        assert_eq!(
+            Binary256Nan::from_cbor(&cbor, NanTagProfile::AllowBinary256)
+                .unwrap(),
+            nan
+        );
+    }
+
+    #[test]
+    fn permissive_profile_still_requires_32_bytes() {
+        let cbor = CBOR::to_tagged_value(102, CBOR::to_byte_string([0x7E, 0]));
+        assert!(matches!(
+            Binary256Nan::from_cbor(&cbor, NanTagProfile::AllowBinary256),
+            Err(Error::InvalidLength(2))
+        ));
+    }
+}
@@ -0,0 +1,67 @@
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+fn samples() -> Vec<NanBstr> {
+    NanWidth::ALL
+        .into_iter()
+        .flat_map(|width| {
+            let max = width.max_payload();
+            [(false, true, 0), (true, false, 1), (true, true, max)].map(
+                |(sign, quiet, payload)| {
+                    NanBstr::from_parts(width, sign, quiet, payload).unwrap()
+                },
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn bit_zero_is_the_sign() {
+    for nan in samples() {
+        assert_eq!(nan.bit(0), Some(nan.sign()));
+    }
+}
+
+#[test]
+fn quiet_bit_matches_is_quiet() {
+    for nan in samples() {
+        let width = nan.width();
+        let index = width.bits() - 1 - width.quiet_bit_index();
+        assert_eq!(nan.bit(index), Some(nan.is_quiet()));
+    }
+}
+
+#[test]
+fn bits_past_the_width_are_none() {
+    for nan in samples() {
+        let bits = nan.width().bits();
+        assert!(nan.bit(bits - 1).is_some());
+        assert_eq!(nan.bit(bits), None);
+        assert_eq!(nan.bit(u32::MAX), None);
+    }
+}
+
+#[test]
+fn iterator_reassembles_the_bytes() {
+    for nan in samples() {
+        let bits: Vec<bool> = nan.bits().collect();
+        assert_eq!(bits.len() as u32, nan.width().bits());
+        let bytes: Vec<u8> = bits
+            .chunks(8)
+            .map(|chunk| {
+                chunk.iter().fold(0, |acc, &bit| (acc << 1) | bit as u8)
+            })
+            .collect();
+        assert_eq!(bytes, nan.as_bytes());
+        for (index, bit) in bits.into_iter().enumerate() {
+            assert_eq!(nan.bit(index as u32), Some(bit));
+        }
+    }
+}
+
+#[test]
+fn bits_of_a_known_pattern() {
+    let nan = NanBstr::from_binary16_bits(0xFE01).unwrap();
+    let text: String =
+        nan.bits().map(|bit| if bit { '1' } else { '0' }).collect();
+    assert_eq!(text, "1111111000000001");
+}
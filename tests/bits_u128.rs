@@ -0,0 +1,81 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+fn samples(width: NanWidth) -> Vec<NanBstr> {
+    let max = width.max_payload();
+    [
+        (false, true, 0),
+        (true, true, max),
+        (false, false, 1),
+        (true, false, 0x2A),
+    ]
+    .into_iter()
+    .map(|(sign, quiet, payload)| {
+        NanBstr::from_parts(width, sign, quiet, payload).unwrap()
+    })
+    .collect()
+}
+
+#[test]
+fn zero_extends_the_pattern() {
+    let nan = NanBstr::from_binary16_bits(0xFE01).unwrap();
+    assert_eq!(nan.to_bits_u128(), 0xFE01);
+    let nan = NanBstr::from_binary32_bits(0xFFC0_0001).unwrap();
+    assert_eq!(nan.to_bits_u128(), 0xFFC0_0001);
+    let nan = NanBstr::from_binary64_bits(0xFFF8_0000_0000_0123).unwrap();
+    assert_eq!(nan.to_bits_u128(), 0xFFF8_0000_0000_0123);
+    let quad = 0xFFFF_8000_0000_0000_0000_0000_0000_0001;
+    let nan = NanBstr::from_binary128_bits(quad).unwrap();
+    assert_eq!(nan.to_bits_u128(), quad);
+    assert_eq!(nan.to_binary128_bits(), Some(quad));
+}
+
+#[test]
+fn round_trips_for_every_width() {
+    for width in NanWidth::ALL {
+        for nan in samples(width) {
+            let bits = nan.to_bits_u128();
+            assert!(width.bits() == 128 || bits >> width.bits() == 0);
+            assert_eq!(NanBstr::from_bits_u128(width, bits).unwrap(), nan);
+        }
+    }
+}
+
+#[test]
+fn rejects_bits_above_the_width() {
+    for width in [NanWidth::Binary16, NanWidth::Binary32, NanWidth::Binary64] {
+        let canonical = width.canonical_quiet_bits();
+        for garbage in
+            [1u128 << width.bits(), 1 << 127, u128::MAX << width.bits()]
+        {
+            let bits = canonical | garbage;
+            assert!(matches!(
+                NanBstr::from_bits_u128(width, bits),
+                Err(Error::BitsExceedWidth { width: w, bits: b })
+                    if w == width && b == bits
+            ));
+        }
+    }
+    let err =
+        NanBstr::from_bits_u128(NanWidth::Binary16, 0x1_7E00).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "bit pattern 0x17e00 has bits set above binary16"
+    );
+}
+
+#[test]
+fn rejects_patterns_that_are_not_nans() {
+    assert!(matches!(
+        NanBstr::from_bits_u128(NanWidth::Binary32, 0x7F80_0000),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_bits_u128(NanWidth::Binary128, 0),
+        Err(Error::NotANan)
+    ));
+    // A binary32 NaN is not a binary64 NaN.
+    assert!(matches!(
+        NanBstr::from_bits_u128(NanWidth::Binary64, 0x7FC0_0000),
+        Err(Error::NotANan)
+    ));
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "bitvec")]
+
+use bitvec::prelude::*;
+use cbor_nan_bstr::{Error, NanBstr};
+
+#[test]
+fn payload_view_is_msb_first() {
+    // binary16 payload 0b1_0000_0011: the top payload bit and the low two.
+    let nan = NanBstr::from_binary16_bits(0x7F03).unwrap();
+    let view = nan.payload_bits_view();
+    assert_eq!(view.len(), 9);
+    assert_eq!(view, bits![u8, Msb0; 1, 0, 0, 0, 0, 0, 0, 1, 1]);
+
+    let double = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap();
+    let view = double.payload_bits_view();
+    assert_eq!(view.len(), 51);
+    assert_eq!(view.count_ones(), 1);
+    assert!(view[50]);
+
+    let quad = NanBstr::from_binary128_words(0x7FFF_4000_0000_0000, 0).unwrap();
+    let view = quad.payload_bits_view();
+    assert_eq!(view.len(), 111);
+    assert!(view[0]);
+}
+
+#[test]
+fn payload_round_trips_through_bits() {
+    let nan = NanBstr::from_binary32_bits(0xFFC1_2345).unwrap();
+    let mut view = nan.payload_bits_view();
+    assert_eq!(nan.with_payload_from_bits(&view).unwrap(), nan);
+
+    view.fill(false);
+    view.set(21, true);
+    let replaced = nan.with_payload_from_bits(&view).unwrap();
+    assert_eq!(replaced, NanBstr::from_binary32_bits(0xFFC0_0001).unwrap());
+}
+
+#[test]
+fn with_payload_rejects_wrong_length() {
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    let too_long = bitvec![u8, Msb0; 0; 10];
+    assert!(matches!(
+        nan.with_payload_from_bits(&too_long),
+        Err(Error::InvalidPayloadLength {
+            expected: 9,
+            actual: 10
+        })
+    ));
+    let too_short = bitvec![u8, Msb0; 1; 8];
+    assert!(matches!(
+        nan.with_payload_from_bits(&too_short),
+        Err(Error::InvalidPayloadLength {
+            expected: 9,
+            actual: 8
+        })
+    ));
+}
+
+#[test]
+fn with_payload_rejects_signaling_infinity() {
+    let snan = NanBstr::from_binary16_bits(0x7C01).unwrap();
+    let zeros = bitvec![u8, Msb0; 0; 9];
+    assert!(matches!(
+        snan.with_payload_from_bits(&zeros),
+        Err(Error::NotANan)
+    ));
+}
@@ -0,0 +1,173 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+use dcbor::prelude::*;
+use hex_literal::hex;
+
+/// The examples from the draft, as encoded items and their patterns.
+const DRAFT_EXAMPLES: [(&[u8], &[u8]); 4] = [
+    (&hex!("D866427E00"), &hex!("7E00")),
+    (&hex!("D866447FC00001"), &hex!("7FC00001")),
+    (&hex!("D86648FFF0000000000001"), &hex!("FFF0000000000001")),
+    (
+        &hex!("D866507FFF8000000000000000000000000001"),
+        &hex!("7FFF8000000000000000000000000001"),
+    ),
+];
+
+#[test]
+fn decodes_draft_examples() {
+    for (data, pattern) in DRAFT_EXAMPLES {
+        let nan = NanBstr::from_cbor_data(data).unwrap();
+        assert_eq!(nan.as_bytes(), pattern);
+        assert_eq!(nan.tagged_cbor().to_cbor_data(), data);
+    }
+}
+
+#[test]
+fn rejects_truncated_input() {
+    for (data, _) in DRAFT_EXAMPLES {
+        for len in 0..data.len() {
+            assert!(
+                matches!(
+                    NanBstr::from_cbor_data(&data[..len]),
+                    Err(Error::Cbor(_))
+                ),
+                "{:02x?}",
+                &data[..len]
+            );
+        }
+    }
+}
+
+#[test]
+fn rejects_trailing_data() {
+    for (data, _) in DRAFT_EXAMPLES {
+        let mut long = data.to_vec();
+        long.push(0x00);
+        assert!(matches!(
+            NanBstr::from_cbor_data(&long),
+            Err(Error::Cbor(dcbor::Error::UnusedData(1)))
+        ));
+    }
+}
+
+#[test]
+fn rejects_malformed_cbor() {
+    // Reserved additional information, and a byte string head longer than
+    // needed, which deterministic CBOR forbids.
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 5C")),
+        Err(Error::Cbor(_))
+    ));
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 5802 7E00")),
+        Err(Error::Cbor(dcbor::Error::NonCanonicalNumeric))
+    ));
+}
+
+#[test]
+fn rejects_wrong_tag() {
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D867 427E00")),
+        Err(Error::Cbor(dcbor::Error::WrongTag(..)))
+    ));
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("427E00")),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+}
+
+#[test]
+fn rejects_wrong_content_type() {
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 F97E00")),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 627E00")),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+}
+
+#[test]
+fn rejects_invalid_length() {
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 437E0000")),
+        Err(Error::InvalidLength(3))
+    ));
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 40")),
+        Err(Error::InvalidLength(0))
+    ));
+}
+
+#[test]
+fn rejects_non_nan_patterns() {
+    // binary16 infinity and one.
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 427C00")),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_cbor_data(hex!("D866 423C00")),
+        Err(Error::NotANan)
+    ));
+}
+
+#[test]
+fn encodes_draft_examples_exactly() {
+    for (data, pattern) in DRAFT_EXAMPLES {
+        let nan = NanBstr::from_be_bytes(pattern).unwrap();
+        assert_eq!(nan.to_cbor_data(), data);
+        assert_eq!(nan.encoded_len(), data.len());
+        assert_eq!(CBOR::from(nan.clone()).to_cbor_data(), data);
+    }
+}
+
+#[test]
+fn encoding_pins_every_width() {
+    let cases = [
+        (
+            NanBstr::from_binary16_bits(0x7E00),
+            &hex!("d8 66 42 7e 00")[..],
+        ),
+        (
+            NanBstr::from_binary32_bits(0xFF80_0001),
+            &hex!("d8 66 44 ff 80 00 01"),
+        ),
+        (
+            NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000),
+            &hex!("d8 66 48 7f f8 00 00 00 00 00 00"),
+        ),
+        (
+            NanBstr::from_binary128_bits(
+                0x7FFF_0000_0000_0000_0000_0000_0000_0001,
+            ),
+            &hex!("d8 66 50 7f ff 00 00 00 00 00 00 00 00 00 00 00 00 00 01"),
+        ),
+    ];
+    for (nan, data) in cases {
+        let nan = nan.unwrap();
+        assert_eq!(nan.to_cbor_data(), data);
+        assert_eq!(nan.encoded_len(), data.len());
+        assert_eq!(NanBstr::from_cbor_data(data).unwrap(), nan);
+    }
+}
+
+#[test]
+fn encoded_cbor_size_per_width() {
+    for (width, size) in [
+        (NanWidth::Binary16, 5),
+        (NanWidth::Binary32, 7),
+        (NanWidth::Binary64, 11),
+        (NanWidth::Binary128, 19),
+    ] {
+        for nan in [
+            NanBstr::canonical_quiet(width),
+            NanBstr::minimal_signaling(width),
+        ] {
+            assert_eq!(nan.encoded_cbor_size(), size);
+            assert_eq!(nan.to_cbor_data().len(), size);
+            assert_eq!(CBOR::from(nan.clone()).to_cbor_data().len(), size);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use cbor_nan_bstr::NanBstr;
+use dcbor::prelude::*;
+use hex_literal::hex;
+
+/// Decodes `cbor` through the borrowed and owned paths, checking that they
+/// agree, down to the error message.
+fn decode_both(cbor: &CBOR) -> dcbor::Result<NanBstr> {
+    let borrowed = NanBstr::try_from(cbor);
+    let owned = NanBstr::try_from(cbor.clone());
+    match (&borrowed, &owned) {
+        (Ok(a), Ok(b)) => assert_eq!(a, b),
+        (Err(a), Err(b)) => {
+            assert_eq!(
+                std::mem::discriminant(a),
+                std::mem::discriminant(b),
+                "{a:?} vs {b:?}"
+            );
+            assert_eq!(a.to_string(), b.to_string());
+        }
+        _ => panic!("paths disagree: {borrowed:?} vs {owned:?}"),
+    }
+    assert_eq!(
+        NanBstr::from_cbor_ref(cbor).map_err(|err| err.to_string()),
+        borrowed.as_ref().map_err(|err| err.to_string()).cloned()
+    );
+    borrowed
+}
+
+#[test]
+fn borrowed_decode_matches_owned() {
+    for nan in [
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF0_0000_0000_0001).unwrap(),
+        NanBstr::from_binary128_bits(0xFFFF_8000_0000_0000_0000_0000_0000_0001)
+            .unwrap(),
+    ] {
+        let cbor = CBOR::from(nan.clone());
+        assert_eq!(decode_both(&cbor).unwrap(), nan);
+    }
+}
+
+#[test]
+fn borrowed_decode_leaves_the_tree_intact() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    let mut map = Map::new();
+    map.insert("reading", nan.clone());
+    let doc = CBOR::from(map);
+    let original = doc.clone();
+
+    let CBORCase::Map(map) = doc.as_case() else {
+        unreachable!()
+    };
+    let (_, value) = map.iter().next().unwrap();
+    assert_eq!(NanBstr::try_from(value).unwrap(), nan);
+    assert_eq!(NanBstr::from_cbor_ref(value).unwrap(), nan);
+    assert_eq!(doc, original);
+}
+
+#[test]
+fn borrowed_decode_fails_as_owned() {
+    let cases = [
+        // Wrong tag.
+        CBOR::to_tagged_value(103, ByteString::from(hex!("7E00"))),
+        // Untagged byte string.
+        CBOR::from(ByteString::from(hex!("7E00"))),
+        // Content is a float, not a byte string.
+        CBOR::to_tagged_value(102, 1.5),
+        // Content is text.
+        CBOR::to_tagged_value(102, "7E00"),
+        // Wrong length.
+        CBOR::to_tagged_value(102, ByteString::from(hex!("7E0000"))),
+        // An infinity, not a NaN.
+        CBOR::to_tagged_value(102, ByteString::from(hex!("7C00"))),
+        // Not tagged at all.
+        CBOR::from(42),
+    ];
+    for cbor in cases {
+        assert!(decode_both(&cbor).is_err(), "{cbor}");
+    }
+}
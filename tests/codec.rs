@@ -0,0 +1,86 @@
+#![cfg(feature = "tokio")]
+
+use bytes::BytesMut;
+use cbor_nan_bstr::{DecodeRecovery, Error, NanBstr, NanBstrCodec};
+use hex_literal::hex;
+use tokio_util::codec::{Decoder, Encoder};
+
+mod common;
+use common::nans;
+
+fn encode_all(items: &[NanBstr]) -> BytesMut {
+    let mut codec = NanBstrCodec::new();
+    let mut buf = BytesMut::new();
+    for nan in items {
+        codec.encode(nan, &mut buf).unwrap();
+    }
+    buf
+}
+
+#[test]
+fn encodes_deterministic_cbor() {
+    let buf = encode_all(&nans()[..1]);
+    assert_eq!(&buf[..], hex!("d866 42 7e00"));
+}
+
+#[test]
+fn decodes_many_items_from_one_chunk() {
+    let mut buf = encode_all(&nans());
+    let mut codec = NanBstrCodec::new();
+    let mut decoded = Vec::new();
+    while let Some(nan) = codec.decode(&mut buf).unwrap() {
+        decoded.push(nan);
+    }
+    assert_eq!(decoded, nans());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn decodes_byte_at_a_time() {
+    let stream = encode_all(&nans());
+    let mut codec = NanBstrCodec::new();
+    let mut buf = BytesMut::new();
+    let mut decoded = Vec::new();
+    for &byte in stream.iter() {
+        buf.extend_from_slice(&[byte]);
+        if let Some(nan) = codec.decode(&mut buf).unwrap() {
+            decoded.push(nan);
+        }
+    }
+    assert_eq!(decoded, nans());
+}
+
+#[test]
+fn fails_on_bad_frame_by_default() {
+    let mut buf = BytesMut::from(&hex!("d866 42 7c00")[..]);
+    assert!(matches!(
+        NanBstrCodec::new().decode(&mut buf),
+        Err(Error::NotANan)
+    ));
+
+    // A wrong tag is detected as soon as its head arrives.
+    let mut buf = BytesMut::from(&hex!("d8 67")[..]);
+    assert!(matches!(
+        NanBstrCodec::new().decode(&mut buf),
+        Err(Error::Cbor(dcbor::Error::WrongTag(..)))
+    ));
+}
+
+#[test]
+fn skip_recovery_drops_bad_frames() {
+    let mut buf = BytesMut::new();
+    // Infinity: heads intact, so exactly this frame is skipped.
+    buf.extend_from_slice(&hex!("d866 42 7c00"));
+    buf.extend_from_slice(&encode_all(&nans()[..1]));
+    // Garbage with no tag head, then a wrong-length byte string.
+    buf.extend_from_slice(&hex!("0102 03 d866 43 7fc000"));
+    buf.extend_from_slice(&encode_all(&nans()[1..2]));
+
+    let mut codec = NanBstrCodec::with_recovery(DecodeRecovery::Skip);
+    let mut decoded = Vec::new();
+    while let Some(nan) = codec.decode(&mut buf).unwrap() {
+        decoded.push(nan);
+    }
+    assert_eq!(decoded, nans()[..2]);
+    assert_eq!(codec.skipped(), 3);
+}
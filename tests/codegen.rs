@@ -0,0 +1,134 @@
+#![cfg(feature = "codegen")]
+
+use cbor_nan_bstr::{Error, NanBstr, codegen::nan_consts_source};
+use indoc::indoc;
+
+mod patterns {
+    include!("data/patterns.toml.rs");
+}
+
+#[test]
+fn generated_table_is_current() {
+    let source =
+        nan_consts_source(include_str!("data/patterns.toml"), "patterns.toml")
+            .unwrap();
+    assert_eq!(source, include_str!("data/patterns.toml.rs"));
+}
+
+#[test]
+fn generated_table() {
+    assert_eq!(
+        patterns::NAMES,
+        [
+            "MISSING",
+            "SENSOR_FAULT",
+            "CALIBRATION_EXPIRED",
+            "SIGNALING_TRAP"
+        ]
+    );
+    assert_eq!(
+        *patterns::SENSOR_FAULT,
+        NanBstr::from_binary32_bits(0x7FC0_0101).unwrap()
+    );
+    assert!(patterns::SIGNALING_TRAP.is_signaling());
+    for name in patterns::NAMES {
+        assert!(patterns::by_name(name).is_some());
+    }
+    assert_eq!(
+        patterns::by_name("CALIBRATION_EXPIRED"),
+        Some(&*patterns::CALIBRATION_EXPIRED)
+    );
+    assert_eq!(patterns::by_name("sensor_fault"), None);
+}
+
+fn error(text: &str) -> (usize, String) {
+    match nan_consts_source(text, "patterns.toml") {
+        Err(Error::InvalidNanConst { file, line, reason }) => {
+            assert_eq!(file, "patterns.toml");
+            (line, reason)
+        }
+        other => panic!("expected InvalidNanConst, got {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_entries_cite_their_line() {
+    let (line, reason) = error(indoc! {r#"
+        [[nan]]
+        name = "OK"
+        width = 16
+        pattern = "7e00"
+
+        [[nan]]
+        name = "INFINITY"
+        width = 32
+        pattern = "7f800000"
+    "#});
+    assert_eq!(line, 9);
+    assert!(reason.contains("not a NaN"), "{}", reason);
+
+    let (line, reason) = error(indoc! {r#"
+        [[nan]]
+        name = "SHORT"
+        width = 64
+        pattern = "7fc00001"
+    "#});
+    assert_eq!(line, 4);
+    assert!(
+        reason.contains("4 bytes, but width 64 needs 8"),
+        "{}",
+        reason
+    );
+
+    let (line, _) = error(indoc! {r#"
+        [[nan]]
+        name = "WIDE"
+        width = 80
+        pattern = "7fc00001"
+    "#});
+    assert_eq!(line, 3);
+
+    let (line, _) = error(indoc! {r#"
+        [[nan]]
+        name = "odd name"
+        width = 16
+        pattern = "7e00"
+    "#});
+    assert_eq!(line, 2);
+
+    let (line, reason) = error(indoc! {r#"
+        [[nan]]
+        name = "TWICE"
+        width = 16
+        pattern = "7e00"
+        [[nan]]
+        name = "TWICE"
+        width = 16
+        pattern = "7e01"
+    "#});
+    assert_eq!(line, 6);
+    assert!(reason.contains("already defined"), "{}", reason);
+
+    let (line, _) = error(indoc! {r#"
+        [[nan]]
+        name = "BAD_HEX"
+        width = 16
+        pattern = "7g00"
+    "#});
+    assert_eq!(line, 4);
+}
+
+#[test]
+fn malformed_toml_cites_its_line() {
+    let (line, _) = error("[[nan]]\nname = \"A\"\nwidth = \n");
+    assert_eq!(line, 3);
+    let (line, reason) = error(indoc! {r#"
+        [[nan]]
+        name = "A"
+        width = 16
+        pattern = "7e00"
+        colour = "red"
+    "#});
+    assert_eq!(line, 5);
+    assert!(reason.contains("colour"), "{}", reason);
+}
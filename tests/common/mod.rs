@@ -0,0 +1,13 @@
+use cbor_nan_bstr::NanBstr;
+
+/// One NaN of each width, for the stream tests. The binary128 payload ends
+/// in `d8`, the first byte of a tag-102 head, so framing must not be fooled
+/// by pattern bytes.
+pub fn nans() -> Vec<NanBstr> {
+    vec![
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+        NanBstr::from_binary128_words(0x7FFF_0000_0000_0000, 0xD8).unwrap(),
+    ]
+}
@@ -0,0 +1,110 @@
+use cbor_nan_bstr::conformance::{self, REQUIREMENTS};
+use hex_literal::hex;
+
+/// Returns the ids of the findings, each prefixed with `+` if it passed or
+/// `-` if it failed.
+fn outcomes(data: &[u8]) -> Vec<String> {
+    conformance::check(data)
+        .findings()
+        .iter()
+        .map(|f| {
+            format!("{}{}", if f.passed { "+" } else { "-" }, f.requirement.id)
+        })
+        .collect()
+}
+
+#[test]
+fn valid_item_conforms() {
+    let report = conformance::check(&hex!("d866 42 7e00"));
+    assert!(report.conforms());
+    assert_eq!(report.findings().len(), REQUIREMENTS.len());
+    assert_eq!(
+        report.to_string(),
+        "PASS §2 [tag-102]: a NaN bit pattern is carried under tag 102 \
+         (offset 0: d866)\n\
+         PASS §2 [byte-string]: the tag content MUST be a byte string \
+         (offset 2: 42)\n\
+         PASS RFC 8949 §3 [well-formed]: the data MUST be exactly one \
+         well-formed CBOR data item (offset 0: d866427e00)\n\
+         PASS §2 [length]: enclosed byte string MUST be 2, 4, 8, or 16 \
+         bytes (offset 2: 427e00)\n\
+         PASS §2 [nan]: the bytes, in network byte order, MUST encode a NaN \
+         of the corresponding width: exponent all ones, fraction non-zero \
+         (offset 3: 7e00)\n"
+    );
+}
+
+#[test]
+fn any_serialization_conforms() {
+    // Tag head in two bytes, length head in one extra byte.
+    assert!(conformance::check(&hex!("d90066 5802 7e00")).conforms());
+    // Indefinite-length byte string in two chunks.
+    assert!(conformance::check(&hex!("d866 5f 41 7f 43 c00001 ff")).conforms());
+}
+
+#[test]
+fn wrong_tag() {
+    assert_eq!(outcomes(&hex!("d867 42 7e00")), ["-tag-102"]);
+    assert_eq!(outcomes(&hex!("f97e00")), ["-tag-102"]);
+}
+
+#[test]
+fn content_not_a_byte_string() {
+    assert_eq!(outcomes(&hex!("d866 f97e00")), ["+tag-102", "-byte-string"]);
+}
+
+#[test]
+fn invalid_length() {
+    assert_eq!(
+        outcomes(&hex!("d866 43 7fc000")),
+        ["+tag-102", "+byte-string", "+well-formed", "-length"]
+    );
+}
+
+#[test]
+fn not_a_nan() {
+    let report = conformance::check(&hex!("d866 42 7c00"));
+    assert_eq!(
+        outcomes(&hex!("d866 42 7c00")),
+        [
+            "+tag-102",
+            "+byte-string",
+            "+well-formed",
+            "+length",
+            "-nan"
+        ]
+    );
+    let failure = report.failures().next().unwrap();
+    assert_eq!(
+        (failure.offset, failure.evidence.as_slice()),
+        (3, &hex!("7c00")[..])
+    );
+}
+
+#[test]
+fn ill_formed() {
+    // Truncated content.
+    assert_eq!(
+        outcomes(&hex!("d866 44 7fc0")),
+        ["+tag-102", "+byte-string", "-well-formed"]
+    );
+    // Trailing bytes after the item.
+    assert_eq!(
+        outcomes(&hex!("d866 42 7e00 00")),
+        [
+            "+tag-102",
+            "+byte-string",
+            "-well-formed",
+            "+length",
+            "+nan"
+        ]
+    );
+    // Reserved additional information.
+    assert_eq!(outcomes(&hex!("dc")), ["-well-formed"]);
+    assert_eq!(outcomes(&hex!("")), ["-well-formed"]);
+    // Indefinite-length string with a chunk of another type.
+    assert_eq!(
+        outcomes(&hex!("d866 5f 61 7e ff")),
+        ["+tag-102", "+byte-string", "-well-formed"]
+    );
+}
@@ -0,0 +1,51 @@
+use cbor_nan_bstr::{NanBstr, nan_bstr_cbor, tagged_cbor_bytes_const};
+use dcbor::prelude::*;
+
+static BINARY16: [u8; 5] = nan_bstr_cbor!(binary16, 0xFE01);
+static BINARY32: [u8; 7] = nan_bstr_cbor!(binary32, 0x7F80_0001);
+static BINARY64: [u8; 11] = nan_bstr_cbor!(binary64, 0x7FF8_0000_0000_0123);
+static BINARY128: [u8; 19] =
+    nan_bstr_cbor!(binary128, 0x7FFF_8000_0000_0000_0000_0000_0000_002A);
+
+const FROM_BYTES: [u8; 7] = tagged_cbor_bytes_const(&[0x7F, 0xC0, 0x00, 0x01]);
+
+#[test]
+fn macro_matches_runtime_encoding() {
+    let cases: [(&[u8], NanBstr); 4] = [
+        (&BINARY16, NanBstr::from_binary16_bits(0xFE01).unwrap()),
+        (&BINARY32, NanBstr::from_binary32_bits(0x7F80_0001).unwrap()),
+        (
+            &BINARY64,
+            NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+        ),
+        (
+            &BINARY128,
+            NanBstr::from_binary128_bits(
+                0x7FFF_8000_0000_0000_0000_0000_0000_002A,
+            )
+            .unwrap(),
+        ),
+    ];
+    for (encoded, nan) in cases {
+        assert_eq!(encoded, nan.tagged_cbor().to_cbor_data());
+        assert_eq!(NanBstr::from_tagged_cbor_data(encoded).unwrap(), nan);
+    }
+}
+
+#[test]
+fn function_matches_runtime_encoding() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(FROM_BYTES.to_vec(), nan.tagged_cbor().to_cbor_data());
+}
+
+#[test]
+#[should_panic(expected = "not a NaN bit pattern")]
+fn function_panics_at_runtime_on_infinity() {
+    let _: [u8; 5] = tagged_cbor_bytes_const(&[0x7C, 0x00]);
+}
+
+#[test]
+#[should_panic(expected = "N must be the pattern length plus 3")]
+fn function_panics_at_runtime_on_wrong_output_length() {
+    let _: [u8; 6] = tagged_cbor_bytes_const(&[0x7E, 0x00]);
+}
@@ -0,0 +1,114 @@
+use cbor_nan_bstr::{ConstNan, NanBstr, NanWidth, nan_bstr, nan_bstr_cbor};
+use dcbor::prelude::*;
+
+const QNAN16: ConstNan = ConstNan::from_binary16_bits(0x7E00);
+const SNAN32: ConstNan = ConstNan::from_binary32_bits(0xFF80_0001);
+static SENTINEL: ConstNan = ConstNan::from_binary64_bits(0x7FF8_0000_0000_0042);
+const QNAN128: ConstNan =
+    ConstNan::from_binary128_bits(0x7FFF_8000_0000_0000_0000_0000_0000_0000);
+
+#[test]
+fn accessors() {
+    assert_eq!(QNAN16.width(), NanWidth::Binary16);
+    assert_eq!(QNAN16.to_bits(), 0x7E00);
+    assert_eq!(SNAN32.width(), NanWidth::Binary32);
+    assert_eq!(SNAN32.to_bits(), 0xFF80_0001);
+    assert_eq!(SENTINEL.width(), NanWidth::Binary64);
+    assert_eq!(QNAN128.width(), NanWidth::Binary128);
+}
+
+#[test]
+fn converts_to_nan_bstr() {
+    assert_eq!(
+        NanBstr::from(QNAN16),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+    assert_eq!(
+        SNAN32.to_nan_bstr(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from(SENTINEL),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0042).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from(QNAN128),
+        NanBstr::from_binary128_bits(0x7FFF_8000_0000_0000_0000_0000_0000_0000)
+            .unwrap()
+    );
+    assert_eq!(
+        CBOR::from(NanBstr::from(QNAN16)).to_cbor_data(),
+        nan_bstr_cbor!(binary16, 0x7E00)
+    );
+}
+
+#[test]
+fn compares_with_nan_bstr() {
+    let nan = NanBstr::from_binary32_bits(0xFF80_0001).unwrap();
+    assert_eq!(nan, SNAN32);
+    assert_eq!(SNAN32, nan);
+    assert_ne!(nan, QNAN16);
+    assert_ne!(NanBstr::from_binary32_bits(0x7FC0_7E00).unwrap(), QNAN16);
+}
+
+#[test]
+fn canonical_constants() {
+    const QUIET: ConstNan = ConstNan::canonical_quiet(NanWidth::Binary32);
+    const SIGNALING: ConstNan = ConstNan::minimal_signaling(NanWidth::Binary64);
+    assert_eq!(QUIET.to_bits(), 0x7FC0_0000);
+    assert_eq!(SIGNALING.to_bits(), 0x7FF0_0000_0000_0001);
+    assert_eq!(ConstNan::canonical_quiet(NanWidth::Binary16), QNAN16);
+    for width in [
+        NanWidth::Binary16,
+        NanWidth::Binary32,
+        NanWidth::Binary64,
+        NanWidth::Binary128,
+    ] {
+        assert_eq!(
+            NanBstr::canonical_quiet(width),
+            ConstNan::canonical_quiet(width)
+        );
+        assert_eq!(
+            NanBstr::minimal_signaling(width),
+            ConstNan::minimal_signaling(width)
+        );
+    }
+}
+
+#[test]
+fn from_be_bytes() {
+    const FROM_BYTES: ConstNan =
+        ConstNan::from_be_bytes(&0x7FF8_0000_0000_0042u64.to_be_bytes());
+    assert_eq!(FROM_BYTES, SENTINEL);
+    assert_eq!(ConstNan::from_be_bytes(&[0x7E, 0x00]), QNAN16);
+}
+
+#[test]
+fn nan_bstr_macro() {
+    assert_eq!(nan_bstr!(binary16, 0x7E00), QNAN16);
+    assert_eq!(nan_bstr!(f16: 0x7E00), QNAN16);
+    assert_eq!(nan_bstr!(0x7E00u16), QNAN16);
+    assert_eq!(nan_bstr!(binary32, 0xFF80_0001), SNAN32);
+    assert_eq!(nan_bstr!(f32: 0xFF80_0001), SNAN32);
+    assert_eq!(nan_bstr!(0xFF80_0001u32), SNAN32);
+    assert_eq!(nan_bstr!(binary64, 0x7FF8_0000_0000_0042), SENTINEL);
+    assert_eq!(nan_bstr!(f64: 0x7FF8_0000_0000_0042), SENTINEL);
+    assert_eq!(nan_bstr!(0x7FF8_0000_0000_0042u64), SENTINEL);
+    const BITS: u128 = 0x7FFF_8000_0000_0000_0000_0000_0000_0000;
+    assert_eq!(nan_bstr!(binary128, BITS), QNAN128);
+    assert_eq!(nan_bstr!(f128: BITS), QNAN128);
+    assert_eq!(nan_bstr!(BITS), QNAN128);
+}
+
+#[test]
+#[should_panic(expected = "invalid NaN length")]
+fn from_be_bytes_panics_on_bad_lengths() {
+    ConstNan::from_be_bytes(std::hint::black_box(&[0x7E, 0x00, 0x00]));
+}
+
+#[test]
+#[should_panic(expected = "not a NaN bit pattern")]
+fn panics_at_runtime_on_non_nans() {
+    let bits = std::hint::black_box(0x7FF0_0000_0000_0000);
+    ConstNan::from_binary64_bits(bits);
+}
@@ -0,0 +1,54 @@
+// @generated by cbor_nan_bstr::codegen from patterns.toml. Do not edit.
+
+/// No reading was taken.
+pub static MISSING: ::std::sync::LazyLock<::cbor_nan_bstr::NanBstr> =
+    ::std::sync::LazyLock::new(|| {
+        const PATTERN: [u8; 2] = [0x7e, 0x00];
+        const _: [u8; 5] =
+            ::cbor_nan_bstr::tagged_cbor_bytes_const(&PATTERN);
+        ::cbor_nan_bstr::NanBstr::from_be_bytes(PATTERN).unwrap()
+    });
+
+/// The sensor reported a fault.
+/// Subsystem 1, code 1.
+pub static SENSOR_FAULT: ::std::sync::LazyLock<::cbor_nan_bstr::NanBstr> =
+    ::std::sync::LazyLock::new(|| {
+        const PATTERN: [u8; 4] = [0x7f, 0xc0, 0x01, 0x01];
+        const _: [u8; 7] =
+            ::cbor_nan_bstr::tagged_cbor_bytes_const(&PATTERN);
+        ::cbor_nan_bstr::NanBstr::from_be_bytes(PATTERN).unwrap()
+    });
+
+/// The sensor's calibration has expired.
+pub static CALIBRATION_EXPIRED: ::std::sync::LazyLock<::cbor_nan_bstr::NanBstr> =
+    ::std::sync::LazyLock::new(|| {
+        const PATTERN: [u8; 8] = [0x7f, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02];
+        const _: [u8; 11] =
+            ::cbor_nan_bstr::tagged_cbor_bytes_const(&PATTERN);
+        ::cbor_nan_bstr::NanBstr::from_be_bytes(PATTERN).unwrap()
+    });
+
+/// binary64 NaN `h'7ff0000000000001'`.
+pub static SIGNALING_TRAP: ::std::sync::LazyLock<::cbor_nan_bstr::NanBstr> =
+    ::std::sync::LazyLock::new(|| {
+        const PATTERN: [u8; 8] = [0x7f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        const _: [u8; 11] =
+            ::cbor_nan_bstr::tagged_cbor_bytes_const(&PATTERN);
+        ::cbor_nan_bstr::NanBstr::from_be_bytes(PATTERN).unwrap()
+    });
+
+/// The names of the patterns, in file order.
+pub const NAMES: [&str; 4] = ["MISSING", "SENSOR_FAULT", "CALIBRATION_EXPIRED", "SIGNALING_TRAP"];
+
+/// Returns the pattern named `name`.
+pub fn by_name(
+    name: &str,
+) -> ::core::option::Option<&'static ::cbor_nan_bstr::NanBstr> {
+    match name {
+        "MISSING" => Some(&*MISSING),
+        "SENSOR_FAULT" => Some(&*SENSOR_FAULT),
+        "CALIBRATION_EXPIRED" => Some(&*CALIBRATION_EXPIRED),
+        "SIGNALING_TRAP" => Some(&*SIGNALING_TRAP),
+        _ => None,
+    }
+}
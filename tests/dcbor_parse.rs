@@ -0,0 +1,72 @@
+#![cfg(feature = "dcbor-parse")]
+
+use cbor_nan_bstr::{Error, NanBstr};
+use dcbor::prelude::*;
+
+#[test]
+fn parses_tagged_and_bare_forms() {
+    for nan in [
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0000).unwrap(),
+        NanBstr::from_binary64_bits(0xFFF0_0000_0000_0001).unwrap(),
+        NanBstr::from_binary128_bits(u128::MAX).unwrap(),
+    ] {
+        let diagnostic = CBOR::from(nan.clone()).diagnostic();
+        assert_eq!(NanBstr::from_diagnostic(&diagnostic).unwrap(), nan);
+        let bare = format!("h'{}'", nan.to_hex());
+        assert_eq!(NanBstr::from_diagnostic(&bare).unwrap(), nan);
+    }
+    assert_eq!(
+        NanBstr::from_diagnostic("  102( h'7ff8000000000123' ) ").unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap()
+    );
+}
+
+#[test]
+fn rejects_wrong_tags() {
+    assert!(matches!(
+        NanBstr::from_diagnostic("103(h'7e00')"),
+        Err(Error::Cbor(dcbor::Error::WrongTag(expected, actual)))
+            if expected.value() == 102 && actual.value() == 103
+    ));
+}
+
+#[test]
+fn rejects_other_content() {
+    for text in ["102(1.5)", "102(\"7e00\")", "102([h'7e00'])", "1.5", "[]"] {
+        assert!(
+            matches!(
+                NanBstr::from_diagnostic(text),
+                Err(Error::Cbor(dcbor::Error::WrongType))
+            ),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn rejects_bytes_that_are_not_nans() {
+    assert!(matches!(
+        NanBstr::from_diagnostic("102(h'7c00')"),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_diagnostic("h'3ff0000000000000'"),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_diagnostic("102(h'7e0000')"),
+        Err(Error::InvalidLength(3))
+    ));
+}
+
+#[test]
+fn rejects_malformed_text() {
+    for text in ["", "102(h'7e00'", "102(h'7e0')", "h'7e00' h'7e00'"] {
+        let result = NanBstr::from_diagnostic(text);
+        let Err(Error::DiagnosticNotation(err)) = result else {
+            panic!("{text:?}: expected DiagnosticNotation, got {result:?}");
+        };
+        assert!(!err.full_message(text).is_empty());
+    }
+}
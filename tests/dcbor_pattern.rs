@@ -0,0 +1,159 @@
+#![cfg(feature = "dcbor-pattern")]
+
+use cbor_nan_bstr::{NanBstr, NanPattern, NanWidth};
+use dcbor::prelude::*;
+use dcbor_pattern::{
+    ArrayPattern, MapPattern, Matcher, Pattern, StructurePattern,
+};
+
+fn nans() -> Vec<NanBstr> {
+    vec![
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary16_bits(0xFC01).unwrap(),
+        NanBstr::from_binary32_bits(0x7F80_0001).unwrap(),
+        NanBstr::from_binary32_bits(0xFFC0_00FF).unwrap(),
+        NanBstr::from_binary32_bits(0x7FBF_FFFF).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0007).unwrap(),
+        NanBstr::from_binary64_bits(0xFFF0_0000_0000_1000).unwrap(),
+        NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0x42).unwrap(),
+        NanBstr::from_binary128_words(0xFFFF_0000_0000_0001, 0).unwrap(),
+    ]
+}
+
+fn patterns() -> Vec<NanPattern> {
+    vec![
+        NanPattern::any(),
+        NanPattern::any().width(NanWidth::Binary32),
+        NanPattern::any().widths([NanWidth::Binary16, NanWidth::Binary128]),
+        NanPattern::any().negative(),
+        NanPattern::any().positive().quiet(),
+        NanPattern::any().signaling(),
+        NanPattern::any().payload(0),
+        NanPattern::any().payload(7),
+        NanPattern::any().payload_masked(0xFF, 0xFF),
+        NanPattern::any().payload_masked(0x1000, 0x1000),
+        NanPattern::any().payload_range(1..=0xFF),
+        NanPattern::any().payload_range(0x40..=0x3F_FFFF),
+        NanPattern::any().payload_range(0x1_0000_0000..=u128::MAX),
+        NanPattern::any()
+            .width(NanWidth::Binary64)
+            .or(NanPattern::any().negative()),
+        !NanPattern::any().quiet(),
+        "w=32,64 sign=- | kind=snan !payload==1".parse().unwrap(),
+    ]
+}
+
+#[test]
+fn compiled_patterns_agree_with_matches() {
+    for pattern in patterns() {
+        let compiled = pattern.to_dcbor_pattern();
+        for nan in nans() {
+            assert_eq!(
+                compiled.matches(&nan.clone().into()),
+                pattern.matches(&nan),
+                "{} on {}",
+                pattern,
+                nan
+            );
+        }
+    }
+}
+
+#[test]
+fn non_nan_items_never_match() {
+    let compiled = NanPattern::any().to_dcbor_pattern();
+    let infinity =
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0x7C, 0x00]));
+    let finite = CBOR::to_tagged_value(102, CBOR::to_byte_string([0x3C, 0x00]));
+    let wrong_tag =
+        CBOR::to_tagged_value(103, CBOR::to_byte_string([0x7E, 0x00]));
+    let untagged = CBOR::to_byte_string([0x7E, 0x00]);
+    for cbor in [infinity, finite, wrong_tag, untagged, CBOR::from(f64::NAN)] {
+        assert!(!compiled.matches(&cbor), "{}", cbor.diagnostic());
+    }
+    let not_quiet = (!NanPattern::any().quiet()).to_dcbor_pattern();
+    assert!(!not_quiet.matches(&CBOR::to_tagged_value(
+        102,
+        CBOR::to_byte_string([0x7C, 0x00])
+    )));
+}
+
+fn fixture() -> CBOR {
+    let mut sensor = Map::new();
+    sensor.insert("name", "inlet");
+    sensor.insert("fault", NanBstr::from_binary32_bits(0x7F80_0001).unwrap());
+    sensor.insert(
+        "last",
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0007).unwrap(),
+    );
+    let mut doc = Map::new();
+    doc.insert("sensor", sensor);
+    doc.insert(
+        "samples",
+        vec![
+            CBOR::from(1.5),
+            NanBstr::from_binary64_bits(0xFFF0_0000_0000_1000)
+                .unwrap()
+                .into(),
+            NanBstr::from_binary16_bits(0x7E00).unwrap().into(),
+        ],
+    );
+    doc.insert("reset", NanBstr::from_binary16_bits(0xFC01).unwrap());
+    doc.into()
+}
+
+fn map_entry_with_value(value: Pattern) -> Pattern {
+    Pattern::Structure(StructurePattern::Map(
+        MapPattern::with_key_value_constraints(vec![(Pattern::any(), value)]),
+    ))
+}
+
+#[test]
+fn query_map_entries_holding_signaling_nans() {
+    let signaling = NanPattern::any().signaling().to_dcbor_pattern();
+    let query = Pattern::search(map_entry_with_value(signaling.clone()));
+    // The top-level map ("reset") and the sensor map ("fault").
+    assert_eq!(query.paths(&fixture()).len(), 2);
+
+    let leaves = Pattern::search(signaling).paths(&fixture());
+    assert_eq!(leaves.len(), 3);
+}
+
+#[test]
+fn query_arrays_containing_negative_nans() {
+    let negative = NanPattern::any().negative().to_dcbor_pattern();
+    let query = Pattern::search(Pattern::and(vec![
+        Pattern::Structure(StructurePattern::Array(ArrayPattern::any())),
+        Pattern::search(negative),
+    ]));
+    let paths = query.paths(&fixture());
+    assert_eq!(paths.len(), 1);
+    assert_eq!(
+        paths[0]
+            .last()
+            .unwrap()
+            .clone()
+            .try_into_array()
+            .unwrap()
+            .len(),
+        3
+    );
+}
+
+#[test]
+fn query_with_captures() {
+    let quiet_payload = NanPattern::any().quiet().payload_range(1..=0xFF);
+    let query = Pattern::search(Pattern::capture(
+        "nan",
+        quiet_payload.to_dcbor_pattern(),
+    ));
+    let (_, captures) = query.paths_with_captures(&fixture());
+    let found: Vec<NanBstr> = captures["nan"]
+        .iter()
+        .map(|path| path.last().unwrap().clone().try_into().unwrap())
+        .collect();
+    assert_eq!(
+        found,
+        vec![NanBstr::from_binary64_bits(0x7FF8_0000_0000_0007).unwrap()]
+    );
+}
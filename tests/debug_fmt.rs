@@ -0,0 +1,65 @@
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+#[test]
+fn one_value_per_width() {
+    let cases = [
+        (
+            NanBstr::from_binary16_bits(0xFC01).unwrap(),
+            r#"NanBstr { width: Binary16, sign: true, quiet: false, payload: 0x1, bytes: "fc01" }"#,
+        ),
+        (
+            NanBstr::from_binary32_bits(0x7FC0_0001).unwrap(),
+            r#"NanBstr { width: Binary32, sign: false, quiet: true, payload: 0x1, bytes: "7fc00001" }"#,
+        ),
+        (
+            NanBstr::from_binary64_bits(0x7FF8_0000_0000_4E41).unwrap(),
+            r#"NanBstr { width: Binary64, sign: false, quiet: true, payload: 0x4e41, bytes: "7ff8000000004e41" }"#,
+        ),
+        (
+            NanBstr::from_binary128_words(0xFFFF_0000_0000_0000, 0x2A).unwrap(),
+            r#"NanBstr { width: Binary128, sign: true, quiet: false, payload: 0x2a, bytes: "ffff000000000000000000000000002a" }"#,
+        ),
+    ];
+    for (nan, expected) in cases {
+        assert_eq!(format!("{nan:?}"), expected);
+    }
+}
+
+#[test]
+fn pretty_prints() {
+    let nan = NanBstr::canonical_quiet(NanWidth::Binary32);
+    assert_eq!(
+        format!("{nan:#?}"),
+        "NanBstr {\n    \
+             width: Binary32,\n    \
+             sign: false,\n    \
+             quiet: true,\n    \
+             payload: 0x0,\n    \
+             bytes: \"7fc00000\",\n\
+         }"
+    );
+}
+
+#[test]
+fn nests_in_other_debug_output() {
+    let nans = [NanBstr::canonical_quiet(NanWidth::Binary16)];
+    assert_eq!(
+        format!("{nans:?}"),
+        r#"[NanBstr { width: Binary16, sign: false, quiet: true, payload: 0x0, bytes: "7e00" }]"#
+    );
+    let some = Some(NanBstr::canonical_quiet(NanWidth::Binary16));
+    assert!(format!("{some:?}").starts_with("Some(NanBstr { width: Binary16"));
+}
+
+#[test]
+fn views_debug_like_owned_values() {
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_4E41).unwrap();
+    assert_eq!(
+        format!("{:?}", nan.as_nan_ref()),
+        r#"NanBstrRef { width: Binary64, sign: false, quiet: true, payload: 0x4e41, bytes: "7ff8000000004e41" }"#
+    );
+    assert_eq!(
+        format!("{:#?}", nan.as_nan_ref()),
+        format!("{nan:#?}").replacen("NanBstr", "NanBstrRef", 1)
+    );
+}
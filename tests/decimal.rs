@@ -0,0 +1,105 @@
+#![cfg(feature = "decimal")]
+
+use cbor_nan_bstr::{DecimalEncoding, DecimalFormat, DecimalNan, Error};
+use hex_literal::hex;
+
+use DecimalEncoding::{Bid, Dpd};
+
+#[test]
+fn canonical_decimal64_nans() {
+    // 754-2008 §3.5.2: combination field 11111 0 is quiet, 11111 1 is
+    // signaling.
+    let qnan =
+        DecimalNan::from_decimal64_bits(0x7C00_0000_0000_0000, Dpd).unwrap();
+    assert!(qnan.is_quiet());
+    assert!(!qnan.sign());
+    assert_eq!(qnan.payload(), 0);
+    assert_eq!(qnan.format(), DecimalFormat::Decimal64);
+    assert_eq!(qnan.to_string(), "NaN[decimal64/dpd]: + quiet payload=0");
+
+    let snan =
+        DecimalNan::from_decimal64_bits(0xFE00_0000_0000_0000, Bid).unwrap();
+    assert!(snan.is_signaling());
+    assert!(snan.sign());
+    assert_eq!(
+        snan.to_string(),
+        "NaN[decimal64/bid]: - signaling payload=0"
+    );
+}
+
+#[test]
+fn decimal64_payloads() {
+    // NaN123: declet 0x0A3 under DPD, integer 0x7B under BID.
+    let dpd =
+        DecimalNan::from_decimal64_bits(0x7C00_0000_0000_00A3, Dpd).unwrap();
+    assert_eq!(dpd.payload(), 123);
+    let bid =
+        DecimalNan::from_decimal64_bits(0x7C00_0000_0000_007B, Bid).unwrap();
+    assert_eq!(bid.payload(), 123);
+    assert_eq!(bid.trailing_significand_bits(), 0x7B);
+
+    // The largest payload, 15 nines: declets 0x0FF under DPD.
+    let dpd =
+        DecimalNan::from_decimal64_bits(0x7C00_FF3F_CFF3_FCFF, Dpd).unwrap();
+    assert_eq!(dpd.payload(), 999_999_999_999_999);
+}
+
+#[test]
+fn dpd_declets_decode_every_digit_shape() {
+    // One declet per decoding case, from the DPD table.
+    let cases: [(u16, u128); 8] = [
+        (0x0A3, 123),
+        (0x0A9, 129),
+        (0x0BB, 193),
+        (0x2AD, 925),
+        (0x18F, 983),
+        (0x23F, 859),
+        (0x25E, 498),
+        (0x0FF, 999),
+    ];
+    for (declet, value) in cases {
+        let bits = 0x7C00_0000_0000_0000 | declet as u64;
+        let nan = DecimalNan::from_decimal64_bits(bits, Dpd).unwrap();
+        assert_eq!(nan.payload(), value, "declet 0x{declet:03x}");
+    }
+}
+
+#[test]
+fn decimal128_payloads() {
+    let bytes = hex!("7c000000 00000000 00000000 000000a3");
+    let dpd = DecimalNan::from_be_bytes(bytes, Dpd).unwrap();
+    assert_eq!(dpd.format(), DecimalFormat::Decimal128);
+    assert_eq!(dpd.payload(), 123);
+    assert_eq!(dpd.to_string(), "NaN[decimal128/dpd]: + quiet payload=123");
+
+    let snan =
+        DecimalNan::from_decimal128_bits(0x7E00 << 112 | 0x7B, Bid).unwrap();
+    assert!(snan.is_signaling());
+    assert_eq!(snan.payload(), 123);
+}
+
+#[test]
+fn non_canonical_bid_payload_reads_as_zero() {
+    // 10^15 exceeds the 15-digit decimal64 payload.
+    let bits = 0x7C00_0000_0000_0000 | 1_000_000_000_000_000;
+    let nan = DecimalNan::from_decimal64_bits(bits, Bid).unwrap();
+    assert_eq!(nan.payload(), 0);
+}
+
+#[test]
+fn rejects_non_nans() {
+    // Infinity: combination field 11110.
+    assert!(matches!(
+        DecimalNan::from_decimal64_bits(0x7800_0000_0000_0000, Dpd),
+        Err(Error::NotANan)
+    ));
+    // 0E+0 under DPD.
+    assert!(matches!(
+        DecimalNan::from_decimal64_bits(0x2238_0000_0000_0000, Dpd),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        DecimalNan::from_be_bytes([0x7C, 0, 0, 0], Dpd),
+        Err(Error::InvalidLength(4))
+    ));
+}
@@ -0,0 +1,112 @@
+use cbor_nan_bstr::{Arch, DEFAULT_NANS, NanBstr, NanWidth};
+
+const ARCHS: [Arch; 3] = [Arch::X86, Arch::Arm, Arch::RiscV];
+
+fn nan(width: NanWidth, bits: u128) -> NanBstr {
+    NanBstr::from_be_bytes(&bits.to_be_bytes()[16 - width.len()..]).unwrap()
+}
+
+/// The expected default NaN for every architecture and width, `None` where
+/// the architecture has no hardware for the width.
+fn expected(arch: Arch, width: NanWidth) -> Option<u128> {
+    use NanWidth::*;
+    match (arch, width) {
+        (Arch::X86, Binary16) => Some(0xFE00),
+        (Arch::X86, Binary32) => Some(0xFFC0_0000),
+        (Arch::X86, Binary64) => Some(0xFFF8_0000_0000_0000),
+        (Arch::X86, Binary128) => None,
+        (Arch::Arm, Binary16) => Some(0x7E00),
+        (Arch::Arm, Binary32) => Some(0x7FC0_0000),
+        (Arch::Arm, Binary64) => Some(0x7FF8_0000_0000_0000),
+        (Arch::Arm, Binary128) => None,
+        (Arch::RiscV, Binary16) => Some(0x7E00),
+        (Arch::RiscV, Binary32) => Some(0x7FC0_0000),
+        (Arch::RiscV, Binary64) => Some(0x7FF8_0000_0000_0000),
+        (Arch::RiscV, Binary128) => {
+            Some(0x7FFF_8000_0000_0000_0000_0000_0000_0000)
+        }
+    }
+}
+
+#[test]
+fn table_matches_every_arch_and_width() {
+    for arch in ARCHS {
+        for width in NanWidth::ALL {
+            let listed = DEFAULT_NANS
+                .iter()
+                .find(|&&(a, w, _)| a == arch && w == width)
+                .map(|&(.., bits)| bits);
+            assert_eq!(listed, expected(arch, width), "{arch:?} {width}");
+        }
+    }
+    assert_eq!(DEFAULT_NANS.len(), 10);
+}
+
+#[test]
+fn default_nans_are_recognized() {
+    for arch in ARCHS {
+        for width in NanWidth::ALL {
+            let Some(bits) = expected(arch, width) else {
+                continue;
+            };
+            let nan = nan(width, bits);
+            assert!(nan.is_default_nan_for(arch), "{arch:?} {width}");
+            assert!(nan.is_quiet() && nan.payload_bits() == 0);
+        }
+    }
+}
+
+#[test]
+fn unsupported_widths_are_never_defaults() {
+    for bits in [
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000,
+        0xFFFF_8000_0000_0000_0000_0000_0000_0000,
+    ] {
+        let quad = nan(NanWidth::Binary128, bits);
+        assert!(!quad.is_default_nan_for(Arch::X86));
+        assert!(!quad.is_default_nan_for(Arch::Arm));
+    }
+    let x86_quad = nan(
+        NanWidth::Binary128,
+        0xFFFF_8000_0000_0000_0000_0000_0000_0000,
+    );
+    assert_eq!(x86_quad.likely_origin(), None);
+}
+
+#[test]
+fn other_nans_are_not_defaults() {
+    for nan in [
+        NanBstr::from_binary32_bits(0x7FC0_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF0_0000_0000_0001).unwrap(),
+        NanBstr::from_binary16_bits(0xFE01).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_07A2).unwrap(),
+    ] {
+        assert!(ARCHS.iter().all(|&arch| !nan.is_default_nan_for(arch)));
+        assert_eq!(nan.likely_origin(), None);
+    }
+}
+
+#[test]
+fn likely_origin_needs_a_single_arch() {
+    for width in [NanWidth::Binary16, NanWidth::Binary32, NanWidth::Binary64] {
+        let x86 = nan(width, expected(Arch::X86, width).unwrap());
+        assert_eq!(x86.likely_origin(), Some(Arch::X86));
+        assert!(!x86.is_default_nan_for(Arch::Arm));
+        // Arm and RISC-V produce the same pattern.
+        let positive = NanBstr::canonical_quiet(width);
+        assert!(positive.is_default_nan_for(Arch::Arm));
+        assert!(positive.is_default_nan_for(Arch::RiscV));
+        assert!(!positive.is_default_nan_for(Arch::X86));
+        assert_eq!(positive.likely_origin(), None);
+    }
+    let quad = NanBstr::canonical_quiet(NanWidth::Binary128);
+    assert_eq!(quad.likely_origin(), Some(Arch::RiscV));
+}
+
+#[test]
+fn native_floats_match() {
+    let x86 = NanBstr::from_f64_bits(0xFFF8_0000_0000_0000).unwrap();
+    assert_eq!(x86.likely_origin(), Some(Arch::X86));
+    let positive = NanBstr::try_from(f64::NAN).unwrap();
+    assert!(positive.is_default_nan_for(Arch::Arm));
+}
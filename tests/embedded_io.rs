@@ -0,0 +1,127 @@
+#![cfg(feature = "embedded-io")]
+
+use cbor_nan_bstr::{EmbeddedIoError, Error, NanBstr};
+use embedded_io::SliceWriteError;
+use hex_literal::hex;
+
+mod common;
+use common::nans;
+
+#[test]
+fn round_trips_through_a_slice() {
+    let mut buf = [0u8; 64];
+    let mut w: &mut [u8] = &mut buf;
+    for nan in nans() {
+        nan.encode_to(&mut w).unwrap();
+    }
+    let written = 64 - w.len();
+
+    let expected: Vec<u8> =
+        nans().iter().flat_map(|nan| nan.to_cbor_data()).collect();
+    assert_eq!(&buf[..written], expected);
+
+    let mut r: &[u8] = &buf[..written];
+    for nan in nans() {
+        assert_eq!(NanBstr::decode_from(&mut r).unwrap(), nan);
+    }
+    assert!(r.is_empty());
+}
+
+#[test]
+fn short_write_is_reported() {
+    let mut buf = [0u8; 4];
+    let mut w: &mut [u8] = &mut buf;
+    assert!(matches!(
+        nans()[1].encode_to(&mut w),
+        Err(SliceWriteError::Full)
+    ));
+}
+
+#[test]
+fn short_reads_are_unexpected_eof() {
+    let data = nans()[2].to_cbor_data();
+    for len in 0..data.len() {
+        let mut r: &[u8] = &data[..len];
+        assert!(
+            matches!(
+                NanBstr::decode_from(&mut r),
+                Err(EmbeddedIoError::UnexpectedEof)
+            ),
+            "length {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn bad_items_are_decode_errors() {
+    let mut r: &[u8] = &hex!("d867 427e00");
+    assert!(matches!(
+        NanBstr::decode_from(&mut r),
+        Err(EmbeddedIoError::Decode(Error::Cbor(
+            dcbor::Error::WrongTag(_, _)
+        )))
+    ));
+
+    let mut r: &[u8] = &hex!("d866 57 00000000000000000000000000000000000000");
+    assert!(matches!(
+        NanBstr::decode_from(&mut r),
+        Err(EmbeddedIoError::Decode(Error::InvalidLength(23)))
+    ));
+    assert_eq!(r.len(), 19, "reading stops after the heads");
+
+    let mut r: &[u8] = &hex!("d866 427c00");
+    assert!(matches!(
+        NanBstr::decode_from(&mut r),
+        Err(EmbeddedIoError::Decode(Error::NotANan))
+    ));
+}
+
+#[cfg(feature = "embedded-io-async")]
+mod asynchronous {
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::*;
+
+    /// Polls a future that never waits, as slice readers and writers don't.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("slice I/O should not wait"),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_a_slice() {
+        let mut buf = [0u8; 64];
+        let mut w: &mut [u8] = &mut buf;
+        for nan in nans() {
+            block_on(nan.encode_to_async(&mut w)).unwrap();
+        }
+        let written = 64 - w.len();
+
+        let mut r: &[u8] = &buf[..written];
+        for nan in nans() {
+            assert_eq!(
+                block_on(NanBstr::decode_from_async(&mut r)).unwrap(),
+                nan
+            );
+        }
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn short_read_is_unexpected_eof() {
+        let mut r: &[u8] = &hex!("d866 447fc0");
+        assert!(matches!(
+            block_on(NanBstr::decode_from_async(&mut r)),
+            Err(EmbeddedIoError::UnexpectedEof)
+        ));
+    }
+}
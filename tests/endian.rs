@@ -0,0 +1,154 @@
+use cbor_nan_bstr::{Error, NanBstr};
+use hex_literal::hex;
+
+/// One NaN pattern of each width, big-endian.
+const PATTERNS: [&[u8]; 4] = [
+    &hex!("7e00"),
+    &hex!("7fc00001"),
+    &hex!("fff0000000000002"),
+    &hex!("7fff8000000000000000000000000003"),
+];
+
+fn reversed(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().rev().copied().collect()
+}
+
+#[test]
+fn le_bytes_match_be_bytes() {
+    assert_eq!(
+        NanBstr::from_le_bytes([0x00, 0x7E]).unwrap(),
+        NanBstr::from_be_bytes([0x7E, 0x00]).unwrap()
+    );
+    for be in PATTERNS {
+        let nan = NanBstr::from_be_bytes(be).unwrap();
+        assert_eq!(NanBstr::from_le_bytes(reversed(be)).unwrap(), nan);
+        assert_eq!(nan.as_bytes(), be);
+    }
+}
+
+#[test]
+fn le_bits_match_be_bits() {
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert_eq!(NanBstr::from_binary16_le_bits(0x007E).unwrap(), nan);
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(NanBstr::from_binary32_le_bits(0x0100_C07F).unwrap(), nan);
+    let bits = 0xFFF0_0000_0000_0002;
+    assert_eq!(
+        NanBstr::from_binary64_le_bits(u64::swap_bytes(bits)).unwrap(),
+        NanBstr::from_binary64_bits(bits).unwrap()
+    );
+    let bits = 0x7FFF_8000_0000_0000_0000_0000_0000_0003;
+    assert_eq!(
+        NanBstr::from_binary128_le_bits(u128::swap_bytes(bits)).unwrap(),
+        NanBstr::from_binary128_bits(bits).unwrap()
+    );
+}
+
+#[test]
+fn le_constructors_validate() {
+    assert!(matches!(
+        NanBstr::from_le_bytes([0x7E, 0x00, 0x00]),
+        Err(Error::InvalidLength(3))
+    ));
+    assert!(matches!(
+        NanBstr::from_le_bytes([0x7E]),
+        Err(Error::InvalidLength(1))
+    ));
+    // Binary32 +infinity, little-endian.
+    assert!(matches!(
+        NanBstr::from_le_bytes(hex!("0000807f")),
+        Err(Error::NotANan)
+    ));
+    // A NaN whose bytes are not reversed is not a NaN.
+    assert!(matches!(
+        NanBstr::from_le_bytes(hex!("7e00")),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_binary32_le_bits(0x7FC0_0001),
+        Err(Error::NotANan)
+    ));
+}
+
+/// Returns `be` as the host would lay it out in memory.
+fn native(be: &[u8]) -> Vec<u8> {
+    if cfg!(target_endian = "big") {
+        be.to_vec()
+    } else {
+        reversed(be)
+    }
+}
+
+/// Returns `be` as a host of the other byte order would lay it out.
+fn foreign(be: &[u8]) -> Vec<u8> {
+    reversed(&native(be))
+}
+
+#[test]
+fn ne_bytes_match_the_logical_pattern() {
+    for be in PATTERNS {
+        let nan = NanBstr::from_be_bytes(be).unwrap();
+        assert_eq!(NanBstr::from_ne_bytes(native(be)).unwrap(), nan);
+        assert_eq!(NanBstr::from_ne_bytes(native(be)).unwrap().as_bytes(), be);
+    }
+    let value = f64::from_bits(0x7FF8_0000_0000_0001);
+    assert_eq!(
+        NanBstr::from_ne_bytes(value.to_ne_bytes()).unwrap(),
+        NanBstr::from_f64_bits(value.to_bits()).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_binary64_ne_bytes(value.to_ne_bytes()).unwrap(),
+        NanBstr::from_f64_bits(value.to_bits()).unwrap()
+    );
+    let value = f32::from_bits(0x7F80_0001);
+    assert_eq!(
+        NanBstr::from_binary32_ne_bytes(value.to_ne_bytes()).unwrap(),
+        NanBstr::from_f32_bits(value.to_bits()).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_binary16_ne_bytes(0x7E00u16.to_ne_bytes()).unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+    let bits = 0x7FFF_8000_0000_0000_0000_0000_0000_0003u128;
+    assert_eq!(
+        NanBstr::from_binary128_ne_bytes(bits.to_ne_bytes()).unwrap(),
+        NanBstr::from_binary128_bits(bits).unwrap()
+    );
+}
+
+#[test]
+fn other_byte_order_agrees_when_decoded_in_its_order() {
+    // The memory image another host would produce for the same pattern
+    // decodes to the same value with that host's byte order, and is not
+    // mistaken for a NaN in ours.
+    for be in PATTERNS {
+        let nan = NanBstr::from_be_bytes(be).unwrap();
+        let image = foreign(be);
+        let decoded = if cfg!(target_endian = "big") {
+            NanBstr::from_le_bytes(&image)
+        } else {
+            NanBstr::from_be_bytes(&image)
+        };
+        assert_eq!(decoded.unwrap(), nan);
+        assert!(matches!(
+            NanBstr::from_ne_bytes(&image),
+            Err(Error::NotANan)
+        ));
+    }
+}
+
+#[test]
+fn ne_bytes_validate() {
+    assert!(matches!(
+        NanBstr::from_ne_bytes([0u8; 5]),
+        Err(Error::InvalidLength(5))
+    ));
+    assert!(matches!(
+        NanBstr::from_binary32_ne_bytes(f32::INFINITY.to_ne_bytes()),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_ne_bytes(1.5f64.to_ne_bytes()),
+        Err(Error::NotANan)
+    ));
+}
@@ -0,0 +1,70 @@
+use cbor_nan_bstr::NanBstr;
+
+#[test]
+fn binary16() {
+    let nan = NanBstr::from_binary16_bits(0x7C01).unwrap();
+    assert_eq!(
+        nan.explain(),
+        "binary16 7c01\n\
+         \x20 sign      bit  15     0 (+)\n\
+         \x20 exponent  bits 14-10  0x1f 11111 (all ones)\n\
+         \x20 quiet     bit  9      0 (signaling)\n\
+         \x20 payload   bits 8-0    0x1 000000001\n"
+    );
+}
+
+#[test]
+fn binary32() {
+    let nan = NanBstr::from_binary32_bits(0xFFC0_0001).unwrap();
+    assert_eq!(
+        nan.explain(),
+        "binary32 ffc00001\n\
+         \x20 sign      bit  31     1 (-)\n\
+         \x20 exponent  bits 30-23  0xff 11111111 (all ones)\n\
+         \x20 quiet     bit  22     1 (quiet)\n\
+         \x20 payload   bits 21-0   0x1 0000000000000000000001\n"
+    );
+}
+
+#[test]
+fn binary64() {
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_4E41).unwrap();
+    let payload = format!("{}100111001000001", "0".repeat(36));
+    assert_eq!(
+        nan.explain(),
+        format!(
+            "binary64 7ff8000000004e41\n\
+             \x20 sign      bit  63     0 (+)\n\
+             \x20 exponent  bits 62-52  0x7ff 11111111111 (all ones)\n\
+             \x20 quiet     bit  51     1 (quiet)\n\
+             \x20 payload   bits 50-0   0x4e41 {payload}\n"
+        )
+    );
+}
+
+#[test]
+fn binary128() {
+    let nan =
+        NanBstr::from_binary128_words(0x7FFF_4000_0000_0000, 0x2A).unwrap();
+    let payload = format!("1{}0101010", "0".repeat(103));
+    assert_eq!(
+        nan.explain(),
+        format!(
+            "binary128 7fff400000000000000000000000002a\n\
+             \x20 sign      bit  127      0 (+)\n\
+             \x20 exponent  bits 126-112  0x7fff 111111111111111 (all ones)\n\
+             \x20 quiet     bit  111      0 (signaling)\n\
+             \x20 payload   bits 110-0    0x400000000000000000000000002a \
+             {payload}\n"
+        )
+    );
+}
+
+#[test]
+fn explain_to_writes_the_same_text() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0000).unwrap();
+    let mut out = String::from("> ");
+    nan.explain_to(&mut out).unwrap();
+    assert_eq!(out, format!("> {}", nan.explain()));
+    assert_eq!(nan.explain().lines().count(), 5);
+}
@@ -0,0 +1,228 @@
+#![cfg(feature = "extended80")]
+
+use cbor_nan_bstr::{Error, Extended80Nan, NanBstr, NanWidth};
+use hex_literal::hex;
+
+// Patterns below were captured with `FSTP TBYTE` after the named operation and
+// are written big-endian.
+
+#[test]
+fn real_indefinite_from_zero_div_zero() {
+    // FLDZ; FLDZ; FDIVP — the x87 default NaN.
+    let nan =
+        Extended80Nan::from_be_bytes(hex!("ffff c000 0000 0000 0000")).unwrap();
+    assert!(nan.sign());
+    assert!(nan.is_quiet());
+    assert_eq!(nan.payload_bits(), 0);
+    assert_eq!(
+        nan.to_string(),
+        "NaN[80]: - quiet frac=0x4000000000000000 payload=0x0"
+    );
+    assert_eq!(
+        nan.to_binary64().unwrap(),
+        NanBstr::from_binary64_bits(0xFFF8_0000_0000_0000).unwrap()
+    );
+}
+
+#[test]
+fn quieted_snan_loaded_from_f64() {
+    // FLD QWORD of 0x7FF0000000000001: the load quiets the sNaN and
+    // left-aligns its payload.
+    let nan =
+        Extended80Nan::from_be_bytes(hex!("7fff c000 0000 0000 0800")).unwrap();
+    assert!(nan.is_quiet());
+    assert_eq!(nan.payload_bits(), 0x800);
+    assert_eq!(
+        nan.to_binary64().unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap()
+    );
+}
+
+#[test]
+fn little_endian_memory_layout() {
+    let le = hex!("0000 0000 0000 00c0 ffff");
+    let nan = Extended80Nan::from_le_bytes(le).unwrap();
+    assert_eq!(nan.sign_exponent(), 0xFFFF);
+    assert_eq!(nan.significand(), 0xC000_0000_0000_0000);
+    assert_eq!(nan.to_le_bytes(), le);
+    assert_eq!(nan.to_be_bytes(), hex!("ffff c000 0000 0000 0000"));
+    assert_eq!(Extended80Nan::from_bits(nan.to_bits()).unwrap(), nan);
+}
+
+#[test]
+fn to_binary128_is_lossless() {
+    let nan = Extended80Nan::from_parts(0x7FFF, 0x8000_0000_0000_0001).unwrap();
+    assert!(nan.is_signaling());
+    let wide = NanBstr::from(nan);
+    assert_eq!(wide.width(), NanWidth::Binary128);
+    assert!(wide.is_signaling());
+    assert_eq!(wide.payload_bits(), 1 << 49);
+}
+
+#[test]
+fn to_binary64_rejects_low_bits() {
+    let nan = Extended80Nan::from_parts(0x7FFF, 0x8000_0000_0000_0001).unwrap();
+    assert!(matches!(
+        nan.to_binary64(),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary64,
+            payload: 1
+        })
+    ));
+    // Truncation leaves no fraction, and the quiet bit is set, so the result
+    // is a quiet NaN rather than an infinity.
+    assert_eq!(
+        nan.to_binary64_lossy(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap()
+    );
+}
+
+#[test]
+fn to_binary64_lossy_quiets_signaling_nans() {
+    // A signaling NaN whose payload survives truncation: `FST m64` still
+    // sets the quiet bit.
+    let nan =
+        Extended80Nan::from_parts(0xFFFF, 0x8000_0000_0000_0FFF).unwrap();
+    assert!(nan.is_signaling());
+    let narrow = nan.to_binary64_lossy();
+    assert!(narrow.is_quiet());
+    assert!(narrow.sign());
+    assert_eq!(narrow.payload_bits(), 1);
+    assert_eq!(
+        narrow,
+        NanBstr::from_binary64_bits(0xFFF8_0000_0000_0001).unwrap()
+    );
+
+    // Without low bits the strict conversion is exact and stays signaling.
+    let nan = Extended80Nan::from_parts(0x7FFF, 0x8000_0000_0000_0800).unwrap();
+    assert_eq!(
+        nan.to_binary64().unwrap(),
+        NanBstr::from_binary64_bits(0x7FF0_0000_0000_0001).unwrap()
+    );
+    assert_eq!(
+        nan.to_binary64_lossy(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap()
+    );
+}
+
+#[test]
+fn rejects_pseudo_nan() {
+    // Exponent all ones, fraction non-zero, integer bit clear.
+    assert!(matches!(
+        Extended80Nan::from_be_bytes(hex!("7fff 4000 0000 0000 0000")),
+        Err(Error::PseudoNan)
+    ));
+    assert!(matches!(
+        Extended80Nan::from_parts(0xFFFF, 0x0000_0000_0000_0001),
+        Err(Error::PseudoNan)
+    ));
+}
+
+#[test]
+fn rejects_non_nans() {
+    // Infinity.
+    assert!(matches!(
+        Extended80Nan::from_parts(0x7FFF, 0x8000_0000_0000_0000),
+        Err(Error::NotANan)
+    ));
+    // Pseudo-infinity.
+    assert!(matches!(
+        Extended80Nan::from_parts(0x7FFF, 0),
+        Err(Error::NotANan)
+    ));
+    // 1.0.
+    assert!(matches!(
+        Extended80Nan::from_parts(0x3FFF, 0x8000_0000_0000_0000),
+        Err(Error::NotANan)
+    ));
+}
+
+#[test]
+fn nan_bstr_from_x87_memory() {
+    let nan =
+        NanBstr::from_x87_extended_bytes(hex!("0000 0000 0000 00c0 ffff"))
+            .unwrap();
+    assert_eq!(
+        nan,
+        NanBstr::from_binary128_bits(0xFFFF_8000_0000_0000_0000_0000_0000_0000)
+            .unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_x87_extended_parts(0xFFFF, 0xC000_0000_0000_0000)
+            .unwrap(),
+        nan
+    );
+
+    // The 62-bit payload lands in the high payload bits of binary128.
+    let nan = NanBstr::from_x87_extended_parts(0x7FFF, 0x8000_0000_0000_0001)
+        .unwrap();
+    assert!(nan.is_signaling());
+    assert_eq!(nan.payload_bits(), 1 << 49);
+}
+
+#[test]
+fn nan_bstr_round_trips_through_x87() {
+    for le in [
+        hex!("0000 0000 0000 00c0 ffff"),
+        hex!("0100 0000 0000 0080 ff7f"),
+        hex!("ffff ffff ffff ffff ffff"),
+        hex!("2301 0000 0000 00e0 ff7f"),
+    ] {
+        let nan = NanBstr::from_x87_extended_bytes(le).unwrap();
+        assert_eq!(nan.to_x87_extended_bytes().unwrap(), le);
+        assert_eq!(
+            Extended80Nan::try_from(&nan).unwrap(),
+            Extended80Nan::from_le_bytes(le).unwrap()
+        );
+    }
+}
+
+#[test]
+fn to_x87_rejects_what_does_not_fit() {
+    let low_bit =
+        NanBstr::from_binary128_bits(0x7FFF_8000_0000_0000_0000_0000_0000_0001)
+            .unwrap();
+    assert!(matches!(
+        low_bit.to_x87_extended_bytes(),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary128,
+            payload: 1
+        })
+    ));
+    let binary64 = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap();
+    assert!(matches!(
+        binary64.to_x87_extended_bytes(),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary128,
+            actual: NanWidth::Binary64
+        })
+    ));
+}
+
+#[test]
+fn nan_bstr_rejects_x87_quirks() {
+    // Integer bit clear: pseudo-NaN.
+    assert!(matches!(
+        NanBstr::from_x87_extended_bytes(hex!("0000 0000 0000 0040 ff7f")),
+        Err(Error::PseudoNan)
+    ));
+    assert!(matches!(
+        NanBstr::from_x87_extended_parts(0xFFFF, 0x7FFF_FFFF_FFFF_FFFF),
+        Err(Error::PseudoNan)
+    ));
+    // Integer bit clear, fraction zero: pseudo-infinity.
+    assert!(matches!(
+        NanBstr::from_x87_extended_bytes(hex!("0000 0000 0000 0000 ff7f")),
+        Err(Error::NotANan)
+    ));
+    // Integer bit set, fraction zero: infinity.
+    assert!(matches!(
+        NanBstr::from_x87_extended_parts(0xFFFF, 0x8000_0000_0000_0000),
+        Err(Error::NotANan)
+    ));
+    // Exponent not all ones.
+    assert!(matches!(
+        NanBstr::from_x87_extended_parts(0x7FFE, 0xC000_0000_0000_0000),
+        Err(Error::NotANan)
+    ));
+}
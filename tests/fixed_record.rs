@@ -0,0 +1,58 @@
+use cbor_nan_bstr::{Error, FIXED_RECORD_LEN, NanBstr};
+use hex_literal::hex;
+
+#[test]
+fn record_layout() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(
+        nan.to_fixed_record(),
+        hex!("04 000000000000000000000000 7fc00001")
+    );
+}
+
+#[test]
+fn round_trips_every_width() {
+    let nans = [
+        NanBstr::from_binary16_bits(0xFE01).unwrap(),
+        NanBstr::from_binary32_bits(0x7F80_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+        NanBstr::from_binary128_words(0xFFFF_8000_0000_0000, 7).unwrap(),
+    ];
+    for nan in nans {
+        let record = nan.to_fixed_record();
+        assert_eq!(record.len(), FIXED_RECORD_LEN);
+        assert_eq!(record[0] as usize, nan.as_bytes().len());
+        assert_eq!(NanBstr::from_fixed_record(&record).unwrap(), nan);
+    }
+}
+
+#[test]
+fn rejects_corrupt_records() {
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    let mut record = nan.to_fixed_record();
+    record[1] = 0x01;
+    assert!(matches!(
+        NanBstr::from_fixed_record(&record),
+        Err(Error::NonZeroPadding)
+    ));
+
+    let mut record = nan.to_fixed_record();
+    record[14] = 0x80;
+    assert!(matches!(
+        NanBstr::from_fixed_record(&record),
+        Err(Error::NonZeroPadding)
+    ));
+
+    let mut record = nan.to_fixed_record();
+    record[0] = 3;
+    assert!(matches!(
+        NanBstr::from_fixed_record(&record),
+        Err(Error::InvalidLength(3))
+    ));
+
+    let infinity = hex!("02 0000000000000000000000000000 7c00");
+    assert!(matches!(
+        NanBstr::from_fixed_record(&infinity),
+        Err(Error::NotANan)
+    ));
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "test-support")]
+
+use cbor_nan_bstr::{
+    assert_float_bits_eq, assert_float_semantic_eq,
+    test_support::{check_float_bits_eq, check_float_semantic_eq},
+};
+use indoc::indoc;
+
+const QNAN_1: f64 = f64::from_bits(0x7FF8_0000_0000_0001);
+
+#[test]
+fn bits_eq_passes() {
+    assert_float_bits_eq!(1.5, 1.5);
+    assert_float_bits_eq!(-0.0f32, -0.0f32);
+    assert_float_bits_eq!(QNAN_1, QNAN_1);
+    assert_float_bits_eq!(f32::NAN, f32::NAN);
+}
+
+#[test]
+#[should_panic(expected = "float bits differ")]
+fn bits_eq_fails_on_signed_zero() {
+    assert_float_bits_eq!(0.0, -0.0);
+}
+
+#[test]
+#[should_panic(expected = "float bits differ")]
+fn bits_eq_fails_on_different_nans() {
+    assert_float_bits_eq!(QNAN_1, f64::NAN);
+}
+
+#[test]
+fn semantic_eq_passes() {
+    assert_float_semantic_eq!(1.5, 1.5);
+    assert_float_semantic_eq!(0.0, -0.0);
+    assert_float_semantic_eq!(QNAN_1, -f64::NAN);
+    assert_float_semantic_eq!(f32::NAN, f32::from_bits(0x7F80_0001));
+}
+
+#[test]
+#[should_panic(expected = "floats differ")]
+fn semantic_eq_fails_on_nan_and_number() {
+    assert_float_semantic_eq!(f64::NAN, 1.0);
+}
+
+#[test]
+fn non_nan_failure_message() {
+    assert_eq!(
+        check_float_bits_eq(0.0f32, -0.0f32).unwrap_err(),
+        indoc! {"
+            float bits differ
+              left: 0.0 (0x00000000)
+             right: -0.0 (0x80000000)"
+        }
+    );
+    assert_eq!(
+        check_float_semantic_eq(1.5, 2.5).unwrap_err(),
+        indoc! {"
+            floats differ
+              left: 1.5 (0x3ff8000000000000)
+             right: 2.5 (0x4004000000000000)"
+        }
+    );
+}
+
+#[test]
+fn nan_failure_message() {
+    assert_eq!(
+        check_float_bits_eq(QNAN_1, -f64::NAN).unwrap_err(),
+        indoc! {"
+            float bits differ
+              left: NaN[binary64]: + quiet frac=0x8000000000001 payload=0x1 (0x7ff8000000000001)
+             right: NaN[binary64]: - quiet frac=0x8000000000000 payload=0x0 (0xfff8000000000000)
+            label  width  sign  q/s  payload  raw
+            -----  -----  ----  ---  -------  ----------------
+            left   64     +     q    0x1      7ff8000000000001
+            right  64     -     q    0x0      fff8000000000000
+                          ^^^^       ^^^^^^^  ^^^^^^^^^^^^^^^^"
+        }
+    );
+    assert_eq!(
+        check_float_semantic_eq(f32::NAN, 1.0).unwrap_err(),
+        indoc! {"
+            floats differ
+              left: NaN[binary32]: + quiet frac=0x400000 payload=0x0 (0x7fc00000)
+             right: 1.0 (0x3f800000)"
+        }
+    );
+}
@@ -0,0 +1,105 @@
+use core::ops::ControlFlow;
+
+use cbor_nan_bstr::{
+    NanBstr, contains_noncanonical_nan, find_nan_bstr_refs, find_nan_bstrs,
+    for_each_nan_bstr,
+};
+use dcbor::prelude::*;
+
+fn fixture() -> CBOR {
+    let readings: Vec<CBOR> = vec![
+        1.5.into(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap().into(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap().into(),
+        NanBstr::from_binary32_bits(0x7FC0_0123).unwrap().into(),
+    ];
+    let na = NanBstr::from_binary64_bits(0x7FF0_0000_0000_07A2).unwrap();
+
+    let mut map = Map::new();
+    map.insert("sensor", "a");
+    map.insert("readings", readings);
+    map.insert("meta", CBOR::to_tagged_value(40000, na));
+    map.insert(
+        "bad",
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0x7C, 0x00])),
+    );
+    map.insert(NanBstr::from_binary16_bits(0x7E01).unwrap(), "key");
+    map.into()
+}
+
+#[test]
+fn agrees_with_find_nan_bstrs() {
+    let cbor = fixture();
+    let mut visited = Vec::new();
+    let completed = for_each_nan_bstr(&cbor, |path, nan| {
+        visited.push((path.clone(), nan.to_owned()));
+        ControlFlow::Continue(())
+    });
+    assert!(completed);
+    assert_eq!(visited, find_nan_bstrs(&cbor));
+}
+
+#[test]
+fn stops_at_first_break() {
+    let cbor = fixture();
+    let total = find_nan_bstrs(&cbor).len();
+    assert_eq!(total, 5);
+    for stop_after in 1..=total {
+        let mut visits = 0;
+        let completed = for_each_nan_bstr(&cbor, |_, _| {
+            visits += 1;
+            if visits == stop_after {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert!(!completed);
+        assert_eq!(visits, stop_after);
+    }
+}
+
+#[test]
+fn finds_the_first_payload_bearing_nan() {
+    let cbor = fixture();
+    let mut first = None;
+    for_each_nan_bstr(&cbor, |path, nan| {
+        if nan.payload_bits() != 0 {
+            first = Some(path.to_string());
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    });
+    assert_eq!(first.as_deref(), Some(r#"$["meta"]#40000"#));
+}
+
+#[test]
+fn noncanonical_nans() {
+    assert!(contains_noncanonical_nan(&fixture()));
+
+    let canonical: Vec<CBOR> = vec![
+        NanBstr::from_binary16_bits(0x7E00).unwrap().into(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000)
+            .unwrap()
+            .into(),
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0xFC, 0x00])),
+    ];
+    assert!(!contains_noncanonical_nan(&canonical.into()));
+    assert!(!contains_noncanonical_nan(&CBOR::from(1.5)));
+
+    for bits in [0xFE00, 0x7C01, 0x7E01] {
+        let nan = NanBstr::from_binary16_bits(bits).unwrap();
+        assert!(contains_noncanonical_nan(&vec![nan].into()), "{:04x}", bits);
+    }
+}
+
+#[test]
+fn visits_views_into_the_document() {
+    let cbor = fixture();
+    let mut visited = Vec::new();
+    for_each_nan_bstr(&cbor, |path, nan| {
+        visited.push((path.clone(), nan));
+        ControlFlow::Continue(())
+    });
+    assert_eq!(visited, find_nan_bstr_refs(&cbor));
+}
@@ -0,0 +1,174 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn parses_display_output() {
+    for nan in [
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0001).unwrap(),
+        NanBstr::from_binary64_bits(0xFFF0_0000_0000_0001).unwrap(),
+        NanBstr::from_binary128_bits(u128::MAX).unwrap(),
+    ] {
+        assert_eq!(nan.to_string().parse::<NanBstr>().unwrap(), nan);
+    }
+    assert_eq!(
+        "NaN[binary32]: + quiet frac=0x400001 payload=0x1"
+            .parse::<NanBstr>()
+            .unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0001).unwrap()
+    );
+}
+
+#[test]
+fn accepts_every_width_form() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    for width in ["binary32", "f32", "32"] {
+        let text = format!("NaN[{width}]: + quiet payload=0x1");
+        assert_eq!(text.parse::<NanBstr>().unwrap(), nan, "{text}");
+    }
+}
+
+#[test]
+fn frac_is_optional() {
+    assert_eq!(
+        "NaN[64]: - signaling payload=0x1"
+            .parse::<NanBstr>()
+            .unwrap(),
+        NanBstr::from_binary64_bits(0xFFF0_0000_0000_0001).unwrap()
+    );
+    assert_eq!(
+        "  NaN[16]:  +\tquiet payload=0x0 "
+            .parse::<NanBstr>()
+            .unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+}
+
+fn text_error(s: &str) -> (usize, String) {
+    match s.parse::<NanBstr>() {
+        Err(Error::InvalidNanText { offset, reason }) => (offset, reason),
+        other => panic!("{s:?}: expected InvalidNanText, got {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_malformed_text() {
+    let cases = [
+        ("", 0, "expected `NaN[<width>]:`"),
+        ("NaN[24]: + quiet payload=0x1", 0, "got `NaN[24]:`"),
+        ("NaN[32] + quiet payload=0x1", 0, "got `NaN[32]`"),
+        ("NaN[32]: ~ quiet payload=0x1", 9, "expected `+` or `-`"),
+        ("NaN[32]: + loud payload=0x1", 11, "got `loud`"),
+        ("NaN[32]: + quiet", 16, "expected `payload=0x<hex>`"),
+        ("NaN[32]: + quiet payload=1", 17, "got `payload=1`"),
+        (
+            "NaN[32]: + quiet frac=0xz payload=0x1",
+            17,
+            "got `frac=0xz`",
+        ),
+        ("NaN[32]: + quiet payload=0x1 extra", 29, "trailing text"),
+        (
+            "NaN[32]: + quiet frac=0x1 payload=0x1",
+            17,
+            "which give frac=0x400001",
+        ),
+    ];
+    for (s, offset, reason) in cases {
+        let (actual_offset, actual_reason) = text_error(s);
+        assert_eq!(actual_offset, offset, "{s:?}");
+        assert!(actual_reason.contains(reason), "{s:?}: {actual_reason}");
+    }
+}
+
+#[test]
+fn rejects_invalid_fields() {
+    assert!(matches!(
+        "NaN[16]: + quiet payload=0x200".parse::<NanBstr>(),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary16,
+            payload: 0x200,
+        })
+    ));
+    assert!(matches!(
+        "NaN[16]: + signaling payload=0x0".parse::<NanBstr>(),
+        Err(Error::SignalingZeroPayload(NanWidth::Binary16))
+    ));
+}
+
+#[test]
+fn alternate_display_appends_the_pattern() {
+    let cases = [
+        (
+            NanBstr::from_binary16_bits(0xFE01).unwrap(),
+            "NaN[binary16]: - quiet frac=0x201 payload=0x1",
+            "fe01",
+        ),
+        (
+            NanBstr::from_binary32_bits(0x7F80_0001).unwrap(),
+            "NaN[binary32]: + signaling frac=0x1 payload=0x1",
+            "7f800001",
+        ),
+        (
+            NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+            "NaN[binary64]: + quiet frac=0x8000000000123 payload=0x123",
+            "7ff8000000000123",
+        ),
+        (
+            NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0x2A).unwrap(),
+            "NaN[binary128]: + quiet frac=0x800000000000000000000000002a \
+             payload=0x2a",
+            "7fff800000000000000000000000002a",
+        ),
+    ];
+    for (nan, plain, hex) in cases {
+        assert_eq!(nan.to_string(), plain);
+        assert_eq!(format!("{nan}"), plain);
+        assert_eq!(format!("{nan:#}"), format!("{plain} (0x{hex})"));
+        assert_eq!(format!("{:#}", nan.describe()), format!("{nan:#}"));
+    }
+}
+
+#[test]
+fn parses_alternate_display_output() {
+    for width in NanWidth::ALL {
+        for nan in [
+            NanBstr::canonical_quiet(width),
+            NanBstr::minimal_signaling(width),
+            NanBstr::from_parts(width, true, true, width.max_payload())
+                .unwrap(),
+        ] {
+            assert_eq!(format!("{nan:#}").parse::<NanBstr>().unwrap(), nan);
+        }
+    }
+    assert_eq!(
+        "NaN[binary16]: + quiet payload=0x0 (0x7e00)"
+            .parse::<NanBstr>()
+            .unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+}
+
+#[test]
+fn rejects_a_disagreeing_pattern() {
+    let cases = [
+        (
+            "NaN[binary16]: + quiet payload=0x1 (0x7e00)",
+            35,
+            "which give `(0x7e01)`",
+        ),
+        (
+            "NaN[binary16]: + quiet payload=0x0 (7e00)",
+            35,
+            "trailing text",
+        ),
+        (
+            "NaN[binary16]: + quiet payload=0x0 (0x7e00) x",
+            44,
+            "trailing text",
+        ),
+    ];
+    for (s, offset, reason) in cases {
+        let (actual_offset, actual_reason) = text_error(s);
+        assert_eq!(actual_offset, offset, "{s:?}");
+        assert!(actual_reason.contains(reason), "{s:?}: {actual_reason}");
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "test-support")]
+
+use cbor_nan_bstr::NanBstr;
+use cbor_nan_bstr::test_support::{fuzz_dictionary, write_afl_dictionary};
+use dcbor::prelude::*;
+
+fn is_infinity(bytes: &[u8]) -> bool {
+    let (exponent, fraction) = match bytes.len() {
+        2 => (5, 10),
+        4 => (8, 23),
+        8 => (11, 52),
+        16 => (15, 112),
+        _ => return false,
+    };
+    let bits = bytes.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128);
+    let exponent_mask = (1u128 << exponent) - 1;
+    (bits >> fraction) & exponent_mask == exponent_mask
+        && bits & ((1u128 << fraction) - 1) == 0
+}
+
+#[test]
+fn every_entry_is_a_fragment_or_near_miss() {
+    for entry in fuzz_dictionary() {
+        let valid = entry == [0xD8, 0x66]
+            || matches!(entry.as_slice(), [0x42 | 0x44 | 0x48 | 0x50])
+            || NanBstr::from_be_bytes(&entry).is_ok()
+            || CBOR::try_from_data(&entry)
+                .ok()
+                .and_then(|cbor| NanBstr::try_from(cbor).ok())
+                .is_some();
+        assert!(
+            valid || is_infinity(&entry),
+            "unexpected entry {}",
+            hex::encode(&entry)
+        );
+    }
+}
+
+#[test]
+fn covers_every_width() {
+    let dictionary = fuzz_dictionary();
+    for len in [2, 4, 8, 16] {
+        assert!(dictionary.contains(&vec![0x40 | len as u8]));
+        assert!(
+            dictionary
+                .iter()
+                .any(|e| e.len() == len && is_infinity(e))
+        );
+    }
+    assert!(dictionary.contains(&vec![0xD8, 0x66, 0x42, 0x7E, 0x00]));
+}
+
+#[test]
+fn afl_syntax() {
+    let mut out = Vec::new();
+    write_afl_dictionary(&mut out).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text.lines().count(), fuzz_dictionary().len());
+    assert!(text.starts_with("tag_head=\"\\xd8\\x66\"\n"));
+    assert!(text.contains("qnan_16_tagged=\"\\xd8\\x66\\x42\\x7e\\x00\"\n"));
+    assert!(text.contains("pos_inf_32=\"\\x7f\\x80\\x00\\x00\"\n"));
+}
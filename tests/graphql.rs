@@ -0,0 +1,104 @@
+#![cfg(feature = "async-graphql")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn describe(&self, nan: NanBstr) -> String {
+        nan.to_string()
+    }
+
+    async fn width(&self, nan: NanBstr) -> NanWidth {
+        nan.width()
+    }
+
+    async fn widen(&self, nan: NanBstr, width: NanWidth) -> Option<NanBstr> {
+        nan.widen_to(width).ok()
+    }
+}
+
+fn execute(query: &str) -> async_graphql::Response {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let mut future = pin!(schema.execute(query));
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(response) = future.as_mut().poll(&mut cx) {
+            return response;
+        }
+    }
+}
+
+#[test]
+fn round_trips_through_a_query() {
+    let response = execute(
+        r#"{
+            width(nan: "7fc00001")
+            widen(nan: "0x7E01", width: BINARY32)
+            describe(nan: "FE00")
+        }"#,
+    );
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["width"], "BINARY32");
+    assert_eq!(data["widen"], "7fc00001");
+    assert_eq!(
+        data["describe"],
+        NanBstr::from_binary16_bits(0xFE00).unwrap().to_string()
+    );
+}
+
+#[test]
+fn accepts_the_display_form() {
+    let nan = NanBstr::from_binary32_bits(0xFFA0_0001).unwrap();
+    let response = execute(&format!(
+        r#"{{
+            width(nan: "{nan}")
+            widen(nan: "NaN[16]: + quiet payload=0x1", width: BINARY32)
+        }}"#
+    ));
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert_eq!(data["width"], "BINARY32");
+    assert_eq!(data["widen"], "7fc00001");
+
+    let response = execute(r#"{ width(nan: "NaN[24]: + quiet") }"#);
+    assert_eq!(response.errors.len(), 1);
+    let message = &response.errors[0].message;
+    assert!(message.contains("invalid NaN `NaN[24]: + quiet`"), "{}", message);
+}
+
+#[test]
+fn rejects_malformed_input() {
+    let response = execute(r#"{ width(nan: "7f800000") }"#);
+    assert_eq!(response.errors.len(), 1);
+    let message = &response.errors[0].message;
+    assert!(message.contains("`7f800000` is not a NaN"), "{}", message);
+
+    let response = execute(r#"{ width(nan: "7e0") }"#);
+    let message = &response.errors[0].message;
+    assert!(message.contains("invalid hex `7e0`"), "{}", message);
+
+    let response = execute(r#"{ width(nan: "7e00000000") }"#);
+    let message = &response.errors[0].message;
+    assert!(message.contains("got 5 bytes"), "{}", message);
+
+    let response = execute(r#"{ width(nan: 32) }"#);
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[test]
+fn schema_declares_the_scalar_and_enum() {
+    let sdl = Schema::new(Query, EmptyMutation, EmptySubscription).sdl();
+    assert!(sdl.contains("scalar NanBstr"), "{}", sdl);
+    assert!(sdl.contains("enum NanWidth"), "{}", sdl);
+    assert!(sdl.contains("BINARY128"), "{}", sdl);
+}
@@ -0,0 +1,96 @@
+use cbor_nan_bstr::{Error, GuardedF64, NanBstr, PayloadVerdict};
+
+const SNAN_PAYLOAD: u64 = 0x7FF0_0000_0000_0123;
+
+fn guard() -> GuardedF64 {
+    GuardedF64::new(f64::from_bits(SNAN_PAYLOAD)).unwrap()
+}
+
+#[test]
+fn derefs_to_f64() {
+    let g = guard();
+    assert!(g.is_nan());
+    assert_eq!(g.to_bits(), SNAN_PAYLOAD);
+    assert_eq!(
+        g.original(),
+        &NanBstr::from_binary64_bits(SNAN_PAYLOAD).unwrap()
+    );
+}
+
+#[test]
+fn rejects_non_nans_and_wrong_widths() {
+    assert!(matches!(GuardedF64::new(1.0), Err(Error::NotANan)));
+    let single = NanBstr::from_binary32_bits(0x7FC0_0000).unwrap();
+    assert!(matches!(
+        GuardedF64::from_nan_bstr(single),
+        Err(Error::WidthMismatch { .. })
+    ));
+}
+
+#[test]
+fn verdict_intact() {
+    let g = guard();
+    assert_eq!(g.verify(*g), PayloadVerdict::Intact);
+}
+
+#[test]
+fn verdict_quieted() {
+    let g = guard();
+    let quieted = f64::from_bits(SNAN_PAYLOAD | (1 << 51));
+    assert_eq!(g.verify(quieted), PayloadVerdict::Quieted);
+}
+
+#[test]
+fn verdict_replaced_by_canonical() {
+    let g = guard();
+    assert_eq!(g.verify(f64::NAN), PayloadVerdict::ReplacedByCanonical);
+    let x86 = f64::from_bits(0xFFF8_0000_0000_0000);
+    assert_eq!(g.verify(x86), PayloadVerdict::ReplacedByCanonical);
+}
+
+#[test]
+fn verdict_payload_changed() {
+    let g = guard();
+    let changed = f64::from_bits(0x7FF8_0000_0000_0456);
+    assert_eq!(
+        g.verify(changed),
+        PayloadVerdict::PayloadChanged {
+            from: NanBstr::from_binary64_bits(SNAN_PAYLOAD).unwrap(),
+            to: NanBstr::from_binary64_bits(0x7FF8_0000_0000_0456).unwrap(),
+        }
+    );
+    // A sign flip is also a change to the pattern.
+    let flipped = f64::from_bits(SNAN_PAYLOAD | (1 << 63));
+    assert!(matches!(
+        g.verify(flipped),
+        PayloadVerdict::PayloadChanged { .. }
+    ));
+}
+
+#[test]
+fn verdict_no_longer_nan() {
+    let g = guard();
+    assert_eq!(g.verify(0.0), PayloadVerdict::NoLongerNan);
+    assert_eq!(g.verify(f64::INFINITY), PayloadVerdict::NoLongerNan);
+}
+
+#[test]
+fn restore_reapplies_original_pattern() {
+    let g = guard();
+    let restored = g.restore();
+    assert_eq!(restored.to_bits(), SNAN_PAYLOAD);
+    assert_eq!(g.verify(restored), PayloadVerdict::Intact);
+}
+
+#[test]
+fn restore_undoes_a_payload_change() {
+    let g = guard();
+    let changed = f64::from_bits(0x7FF8_0000_0000_0456);
+    assert!(matches!(
+        g.verify(changed),
+        PayloadVerdict::PayloadChanged { .. }
+    ));
+    let restored = g.restore();
+    assert_eq!(restored.to_bits(), SNAN_PAYLOAD);
+    assert_eq!(g.verify(restored), PayloadVerdict::Intact);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "half")]
+
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+use half::{bf16, f16};
+
+#[test]
+fn f16_to_nanbstr_roundtrip() {
+    let n = NanBstr::try_from(f16::NAN).unwrap();
+    assert_eq!(n.width(), NanWidth::Binary16);
+
+    let back = f16::try_from(n).unwrap();
+    assert!(back.is_nan());
+}
+
+#[test]
+fn f16_keeps_exact_bits() {
+    for bits in [0x7C01, 0x7E00, 0xFDFF, 0xFFFF] {
+        let n = NanBstr::try_from(f16::from_bits(bits)).unwrap();
+        assert_eq!(n, NanBstr::from_binary16_bits(bits).unwrap());
+        assert_eq!(f16::try_from(n).unwrap().to_bits(), bits);
+    }
+}
+
+#[test]
+fn f16_try_from_rejects_non_nan() {
+    for value in [f16::ONE, f16::INFINITY, f16::NEG_INFINITY, f16::ZERO] {
+        assert!(matches!(NanBstr::try_from(value), Err(Error::NotANan)));
+    }
+}
+
+#[test]
+fn f16_try_from_nanbstr_rejects_wrong_width() {
+    let n = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert!(matches!(f16::try_from(n), Err(Error::InvalidLength(4))));
+}
+
+#[test]
+fn bf16_widens_to_binary32() {
+    let n = NanBstr::try_from(bf16::from_bits(0xFFC5)).unwrap();
+    assert_eq!(n, NanBstr::from_binary32_bits(0xFFC5_0000).unwrap());
+    assert!(n.sign());
+    assert!(n.is_quiet());
+
+    let n = NanBstr::try_from(bf16::from_bits(0x7F81)).unwrap();
+    assert!(n.is_signaling());
+    assert_eq!(n.payload_bits(), 0x1_0000);
+}
+
+#[test]
+fn bf16_try_from_rejects_non_nan() {
+    for value in [bf16::ONE, bf16::INFINITY, bf16::NEG_INFINITY, bf16::ZERO] {
+        assert!(matches!(NanBstr::try_from(value), Err(Error::NotANan)));
+    }
+}
@@ -0,0 +1,124 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn from_hex_infers_the_width() {
+    let cases = [
+        ("7e00", NanWidth::Binary16),
+        ("7fc00001", NanWidth::Binary32),
+        ("7ff8000000000123", NanWidth::Binary64),
+        ("7fff8000000000000000000000000001", NanWidth::Binary128),
+    ];
+    for (hex, width) in cases {
+        let nan = NanBstr::from_hex(hex).unwrap();
+        assert_eq!(nan.width(), width);
+        assert_eq!(nan.to_hex(), hex);
+        assert_eq!(nan.as_bytes(), hex::decode(hex).unwrap());
+    }
+}
+
+#[test]
+fn from_hex_accepts_prefix_and_separators() {
+    let expected = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap();
+    for hex in [
+        "0x7ff8000000000123",
+        "0X7FF8000000000123",
+        "7ff8_0000_0000_0123",
+        " 7f f8 00 00 00 00 01 23 ",
+        "0x_7FF8_0000_0000_0123",
+    ] {
+        assert_eq!(NanBstr::from_hex(hex).unwrap(), expected, "{hex:?}");
+    }
+}
+
+#[test]
+fn to_hex_is_lowercase() {
+    let nan = NanBstr::from_binary32_bits(0xFFC0_ABCD).unwrap();
+    assert_eq!(nan.to_hex(), "ffc0abcd");
+}
+
+#[test]
+fn from_hex_rejects_bad_lengths() {
+    for (hex, len) in [("", 0), ("7e0", 2), ("7e000", 3), ("7fc000", 3)] {
+        assert!(
+            matches!(NanBstr::from_hex(hex), Err(Error::InvalidLength(l)) if l == len),
+            "{hex:?}"
+        );
+    }
+}
+
+#[test]
+fn from_hex_rejects_bad_digits() {
+    assert!(matches!(
+        NanBstr::from_hex("7g00"),
+        Err(Error::InvalidHexDigit {
+            offset: 1,
+            digit: 'g'
+        })
+    ));
+    assert!(matches!(
+        NanBstr::from_hex("0x7e-00"),
+        Err(Error::InvalidHexDigit {
+            offset: 4,
+            digit: '-'
+        })
+    ));
+    assert!(matches!(
+        NanBstr::from_hex("7e00\u{e9}"),
+        Err(Error::InvalidHexDigit {
+            offset: 4,
+            digit: '\u{e9}'
+        })
+    ));
+}
+
+#[test]
+fn from_hex_rejects_non_nans() {
+    assert!(matches!(NanBstr::from_hex("7c00"), Err(Error::NotANan)));
+    assert!(matches!(NanBstr::from_hex("3c00"), Err(Error::NotANan)));
+}
+
+#[test]
+fn every_width_has_a_fixed_digit_count() {
+    for (width, digits) in [
+        (NanWidth::Binary16, "7e00"),
+        (NanWidth::Binary32, "7fc00000"),
+        (NanWidth::Binary64, "7ff8000000000000"),
+        (NanWidth::Binary128, "7fff8000000000000000000000000000"),
+    ] {
+        let nan = NanBstr::canonical_quiet(width);
+        assert_eq!(format!("{nan:x}"), digits);
+        assert_eq!(format!("{nan:X}"), digits.to_uppercase());
+        assert_eq!(format!("{nan:x}").len(), width.len() * 2);
+        assert_eq!(format!("{nan:x}"), nan.to_hex());
+    }
+}
+
+#[test]
+fn leading_zero_digits_are_kept() {
+    // The widths stay distinguishable even where the value would not.
+    let half = NanBstr::from_binary16_bits(0x7C01).unwrap();
+    let single = NanBstr::from_binary32_bits(0x7F80_0001).unwrap();
+    assert_eq!(format!("{half:x}"), "7c01");
+    assert_eq!(format!("{single:x}"), "7f800001");
+    let quad = NanBstr::from_binary128_words(0x7FFF_0000_0000_0000, 1).unwrap();
+    assert_eq!(format!("{quad:x}"), "7fff0000000000000000000000000001");
+}
+
+#[test]
+fn alternate_flag_adds_a_prefix() {
+    let nan = NanBstr::from_binary32_bits(0xFFC0_00AB).unwrap();
+    assert_eq!(format!("{nan:#x}"), "0xffc000ab");
+    assert_eq!(format!("{nan:#X}"), "0xFFC000AB");
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_4E41).unwrap();
+    assert_eq!(format!("{nan:#X}"), "0x7FF8000000004E41");
+}
+
+#[test]
+fn width_and_fill_apply() {
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert_eq!(format!("{nan:>8x}"), "    7e00");
+    assert_eq!(format!("{nan:*<8X}"), "7E00****");
+    assert_eq!(format!("{nan:08x}"), "00007e00");
+    assert_eq!(format!("{nan:#08x}"), "0x007e00");
+    assert_eq!(format!("{nan:2x}"), "7e00");
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "json")]
+
+use cbor_nan_bstr::{
+    JsonNanMode, NanBstr, NanFields, project_to_json,
+    project_to_json_with_paths,
+};
+use dcbor::prelude::*;
+use serde_json::json;
+
+fn fixture() -> CBOR {
+    let mut sensor = Map::new();
+    sensor.insert("name", "inlet");
+    sensor.insert("fault", NanBstr::from_binary32_bits(0x7F80_0001).unwrap());
+    let mut doc = Map::new();
+    doc.insert("sensor", sensor);
+    doc.insert(
+        "samples",
+        vec![
+            CBOR::from(1.5),
+            NanBstr::from_binary64_bits(0xFFF8_0000_0000_0123)
+                .unwrap()
+                .into(),
+            CBOR::from(-7),
+            CBOR::from(f64::NAN),
+        ],
+    );
+    doc.insert(1, CBOR::to_byte_string([0xCA, 0xFE]));
+    doc.insert("ok", true);
+    doc.into()
+}
+
+#[test]
+fn null_mode() {
+    assert_eq!(
+        project_to_json(&fixture(), JsonNanMode::Null),
+        json!({
+            "1": "cafe",
+            "ok": true,
+            "sensor": { "name": "inlet", "fault": null },
+            "samples": [1.5, null, -7, null],
+        })
+    );
+}
+
+#[test]
+fn string_mode() {
+    assert_eq!(
+        project_to_json(&fixture(), JsonNanMode::String),
+        json!({
+            "1": "cafe",
+            "ok": true,
+            "sensor": { "name": "inlet", "fault": "102(h'7f800001')" },
+            "samples": [1.5, "102(h'fff8000000000123')", -7, null],
+        })
+    );
+}
+
+#[test]
+fn object_mode() {
+    let json = project_to_json(&fixture(), JsonNanMode::Object);
+    assert_eq!(
+        json["sensor"]["fault"],
+        json!({
+            "width": "binary32",
+            "sign": false,
+            "quiet": false,
+            "fraction": "0x1",
+            "payload": "0x1",
+            "bytes": "7f800001",
+        })
+    );
+    assert_eq!(
+        json["samples"][1],
+        json!({
+            "width": "binary64",
+            "sign": true,
+            "quiet": true,
+            "fraction": "0x8000000000123",
+            "payload": "0x123",
+            "bytes": "fff8000000000123",
+        })
+    );
+    assert_eq!(json["samples"][3], json!(null));
+    let fault = NanBstr::from_binary32_bits(0x7F80_0001).unwrap();
+    assert_eq!(
+        serde_json::from_value::<NanFields>(json["sensor"]["fault"].clone())
+            .unwrap(),
+        NanFields::from(&fault)
+    );
+}
+
+#[test]
+fn number_null_mode_reports_paths() {
+    let (json, paths) =
+        project_to_json_with_paths(&fixture(), JsonNanMode::NumberNull);
+    assert_eq!(
+        json,
+        json!({
+            "1": "cafe",
+            "ok": true,
+            "sensor": { "name": "inlet", "fault": null },
+            "samples": [1.5, null, -7, null],
+        })
+    );
+    assert_eq!(json, project_to_json(&fixture(), JsonNanMode::NumberNull));
+    let paths: Vec<String> = paths.iter().map(|p| p.to_string()).collect();
+    assert_eq!(
+        paths,
+        [
+            r#"$["sensor"]["fault"]"#,
+            r#"$["samples"][1]"#,
+            r#"$["samples"][3]"#
+        ]
+    );
+}
+
+#[test]
+fn malformed_and_other_tags_project_content() {
+    let malformed =
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0x3C, 0x00]));
+    let other = CBOR::to_tagged_value(
+        200,
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+    );
+    let doc: CBOR = vec![malformed, other].into();
+    let (json, paths) = project_to_json_with_paths(&doc, JsonNanMode::String);
+    assert_eq!(json, json!(["3c00", "102(h'7e00')"]));
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].to_string(), "$[1]#200");
+}
+
+#[test]
+fn large_negative_integers_become_strings() {
+    let min = CBOR::from(i64::MIN);
+    assert_eq!(project_to_json(&min, JsonNanMode::Null), json!(i64::MIN));
+    let below: CBOR = CBOR::try_from_data([
+        0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    ])
+    .unwrap();
+    assert_eq!(
+        project_to_json(&below, JsonNanMode::Null),
+        json!("-18446744073709551616")
+    );
+}
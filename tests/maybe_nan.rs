@@ -0,0 +1,94 @@
+use cbor_nan_bstr::{Error, MaybeNan, NanBstr};
+use dcbor::prelude::*;
+use hex_literal::hex;
+
+#[test]
+fn valid_item() {
+    let data = hex!("d866 447fc00001");
+    let maybe = MaybeNan::from_cbor_data(data).unwrap();
+    assert!(maybe.is_valid());
+    let nan = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(maybe.as_valid(), Some(&nan));
+    assert!(maybe.reason().is_none());
+    assert_eq!(maybe.to_string(), nan.to_string());
+    assert_eq!(CBOR::from(maybe).to_cbor_data(), data);
+}
+
+#[test]
+fn infinity_is_kept() {
+    let data = hex!("d866 447f800000");
+    let maybe = MaybeNan::from_cbor_data(data).unwrap();
+    assert!(!maybe.is_valid());
+    assert!(matches!(maybe.reason(), Some(Error::NotANan)));
+    assert_eq!(maybe.as_bytes(), hex!("7f800000"));
+    assert_eq!(
+        maybe.to_string(),
+        "invalid NaN (not a NaN bit pattern): h'7f800000' is binary32 \
+         sign=+ exponent=0xff fraction=0x0, an infinity; 1 bit away from \
+         canonical-qnan-32"
+    );
+    assert_eq!(CBOR::from(&maybe).to_cbor_data(), data);
+}
+
+#[test]
+fn five_byte_payload_is_kept() {
+    let data = hex!("d866 457ff8000001");
+    let maybe = MaybeNan::from_cbor_data(data).unwrap();
+    assert!(matches!(
+        maybe,
+        MaybeNan::Invalid {
+            reason: Error::InvalidLength(5),
+            ..
+        }
+    ));
+    assert_eq!(
+        maybe.to_string(),
+        "invalid NaN (invalid NaN length: expected 2, 4, 8, or 16 bytes, got \
+         5 bytes): h'7ff8000001' is not a NaN width"
+    );
+    assert_eq!(CBOR::from(maybe).to_cbor_data(), data);
+}
+
+#[test]
+fn finite_values_are_described() {
+    let maybe = MaybeNan::from_be_bytes(hex!("8000"));
+    assert!(
+        maybe
+            .to_string()
+            .ends_with("sign=- exponent=0x0 fraction=0x0, a zero")
+    );
+    let maybe = MaybeNan::from_be_bytes(hex!("3c00"));
+    assert!(maybe.to_string().ends_with(
+        "a normal number; 2 bits away from canonical-qnan-16"
+    ));
+    let maybe = MaybeNan::from_be_bytes(hex!("0001"));
+    assert!(maybe.to_string().ends_with("a subnormal number"));
+}
+
+#[test]
+fn structural_failures_are_errors() {
+    assert!(matches!(
+        MaybeNan::from_cbor_data(hex!("d867 427e00")),
+        Err(Error::Cbor(dcbor::Error::WrongTag(_, _)))
+    ));
+    assert!(matches!(
+        MaybeNan::from_cbor_data(hex!("d866 f97e00")),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+    assert!(matches!(
+        MaybeNan::from_cbor_data(hex!("d866 44")),
+        Err(Error::Cbor(_))
+    ));
+}
+
+#[test]
+fn nearest_known_covers_invalid_content() {
+    let maybe = MaybeNan::from_be_bytes(hex!("7c00"));
+    let (pattern, distance) = maybe.nearest_known(2).unwrap();
+    assert_eq!((pattern.name, distance), ("canonical-qnan-16", 1));
+    assert!(maybe.nearest_known(0).is_none());
+    let maybe = MaybeNan::from_be_bytes(hex!("7e00"));
+    assert_eq!(maybe.nearest_known(0).unwrap().0.name, "canonical-qnan-16");
+    let maybe = MaybeNan::from_be_bytes(hex!("7ff8000001"));
+    assert!(maybe.nearest_known(128).is_none());
+}
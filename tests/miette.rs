@@ -0,0 +1,154 @@
+#![cfg(feature = "miette")]
+
+use cbor_nan_bstr::{DecodeFailure, Error, NanBstr};
+use hex_literal::hex;
+use miette::{Diagnostic, NarratableReportHandler};
+
+/// A label's offset, length, and text.
+type Label<'a> = (usize, usize, &'a str);
+
+fn failure(data: &[u8]) -> DecodeFailure {
+    NanBstr::from_cbor_data_diagnosed("input", data).unwrap_err()
+}
+
+fn labels(failure: &DecodeFailure) -> Vec<(usize, usize, String)> {
+    failure
+        .labels()
+        .unwrap()
+        .map(|l| (l.offset(), l.len(), l.label().unwrap().to_string()))
+        .collect()
+}
+
+fn render(failure: &DecodeFailure) -> String {
+    let mut out = String::new();
+    NarratableReportHandler::new()
+        .render_report(&mut out, failure)
+        .unwrap();
+    out
+}
+
+#[test]
+fn valid_item_decodes() {
+    let nan = NanBstr::from_cbor_data_diagnosed("input", hex!("d866427e00"));
+    assert_eq!(nan.unwrap(), NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
+
+#[test]
+fn infinity() {
+    let failure = failure(&hex!("d866 44 7f800000"));
+    assert!(matches!(failure.error(), Error::NotANan));
+    assert_eq!(failure.input(), hex!("d866447f800000"));
+    assert_eq!(
+        labels(&failure),
+        [
+            (0, 5, "tag 102".to_string()),
+            (6, 2, "4 bytes".to_string()),
+            (9, 11, "binary32: exponent 0xff, fraction 0x0".to_string()),
+        ]
+    );
+    assert_eq!(
+        failure.help().unwrap().to_string(),
+        "fraction bits are zero \u{2014} this encodes +infinity, not a NaN"
+    );
+    assert_eq!(
+        failure.code().unwrap().to_string(),
+        "cbor_nan_bstr::not_a_nan"
+    );
+}
+
+#[test]
+fn finite_number() {
+    let failure = failure(&hex!("d866 42 bc00"));
+    assert_eq!(
+        labels(&failure)[2],
+        (9, 5, "binary16: exponent 0xf, fraction 0x0".to_string())
+    );
+    assert_eq!(
+        failure.help().unwrap().to_string(),
+        "exponent bits are not all ones \u{2014} this encodes a normal \
+         number, not a NaN"
+    );
+}
+
+#[test]
+fn structural_failures() {
+    let cases: [(&[u8], &str, Vec<Label>); 6] = [
+        (
+            &hex!("d867 42 7e00"),
+            "cbor_nan_bstr::wrong_tag",
+            vec![(0, 5, "expected tag 102")],
+        ),
+        (
+            &hex!("d866 a0"),
+            "cbor_nan_bstr::wrong_type",
+            vec![(0, 5, "tag 102"), (6, 2, "not a byte string")],
+        ),
+        (
+            &hex!("d866 43 7e0000"),
+            "cbor_nan_bstr::invalid_length",
+            vec![
+                (0, 5, "tag 102"),
+                (6, 2, "3 bytes"),
+                (9, 8, "3 bytes of pattern"),
+            ],
+        ),
+        (
+            &hex!("d866 44 7e00"),
+            "cbor_nan_bstr::malformed",
+            vec![
+                (0, 5, "tag 102"),
+                (6, 2, "byte string"),
+                (9, 5, "truncated here"),
+            ],
+        ),
+        (
+            &hex!("d866 42 7e00 00"),
+            "cbor_nan_bstr::malformed",
+            vec![
+                (0, 5, "tag 102"),
+                (6, 2, "2 bytes"),
+                (9, 5, "binary16 NaN"),
+                (15, 2, "trailing data"),
+            ],
+        ),
+        (
+            &hex!("d866 5802 7e00"),
+            "cbor_nan_bstr::malformed",
+            vec![
+                (0, 5, "tag 102"),
+                (6, 5, "2 bytes"),
+                (12, 5, "binary16 NaN"),
+                (0, 17, "not deterministic CBOR"),
+            ],
+        ),
+    ];
+    for (data, code, expected) in cases {
+        let failure = failure(data);
+        assert_eq!(failure.code().unwrap().to_string(), code);
+        let expected: Vec<_> = expected
+            .into_iter()
+            .map(|(offset, len, text)| (offset, len, text.to_string()))
+            .collect();
+        assert_eq!(labels(&failure), expected, "{}", hex::encode(data));
+        assert!(failure.help().is_some());
+    }
+}
+
+#[test]
+fn narratable_report() {
+    assert_eq!(
+        render(&failure(&hex!("d866 44 7f800000"))),
+        "not a NaN bit pattern\n    \
+         Diagnostic severity: error\n\
+         Begin snippet for input starting at line 1, column 1\n\
+         \n\
+         snippet line 1: d8 66 44 7f 80 00 00\n    \
+         label at line 1, columns 1 to 5: tag 102\n    \
+         label at line 1, columns 7 to 8: 4 bytes\n    \
+         label at line 1, columns 10 to 20: binary32: exponent 0xff, \
+         fraction 0x0\n\
+         diagnostic help: fraction bits are zero \u{2014} this encodes \
+         +infinity, not a NaN\n\
+         diagnostic code: cbor_nan_bstr::not_a_nan\n"
+    );
+}
@@ -0,0 +1,97 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth, WordOrder};
+
+const ORDERS: [WordOrder; 4] = [
+    WordOrder::ABCD,
+    WordOrder::CDAB,
+    WordOrder::BADC,
+    WordOrder::DCBA,
+];
+
+#[test]
+fn binary32_in_every_order() {
+    // Bytes A B C D = 7F C0 12 34.
+    let nan = NanBstr::from_binary32_bits(0x7FC0_1234).unwrap();
+    let expected: [&[u16]; 4] = [
+        &[0x7FC0, 0x1234],
+        &[0x1234, 0x7FC0],
+        &[0xC07F, 0x3412],
+        &[0x3412, 0xC07F],
+    ];
+    for (order, regs) in ORDERS.into_iter().zip(expected) {
+        assert_eq!(nan.to_modbus_registers(order), regs, "{:?}", order);
+        let decoded =
+            NanBstr::from_modbus_registers(regs, NanWidth::Binary32, order)
+                .unwrap();
+        assert_eq!(decoded, nan, "{:?}", order);
+    }
+}
+
+#[test]
+fn binary64_in_every_order() {
+    let nan = NanBstr::from_binary64_bits(0xFFF0_0000_1122_3344).unwrap();
+    let expected: [&[u16]; 4] = [
+        &[0xFFF0, 0x0000, 0x1122, 0x3344],
+        &[0x3344, 0x1122, 0x0000, 0xFFF0],
+        &[0xF0FF, 0x0000, 0x2211, 0x4433],
+        &[0x4433, 0x2211, 0x0000, 0xF0FF],
+    ];
+    for (order, regs) in ORDERS.into_iter().zip(expected) {
+        assert_eq!(nan.to_modbus_registers(order), regs, "{:?}", order);
+        let decoded =
+            NanBstr::from_modbus_registers(regs, NanWidth::Binary64, order)
+                .unwrap();
+        assert_eq!(decoded, nan, "{:?}", order);
+    }
+}
+
+#[test]
+fn round_trips_every_width() {
+    let nans = [
+        NanBstr::from_binary16_bits(0x7E01).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+        NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0xABCD).unwrap(),
+    ];
+    for nan in nans {
+        for order in ORDERS {
+            let regs = nan.to_modbus_registers(order);
+            assert_eq!(regs.len(), nan.width().len() / 2);
+            let decoded =
+                NanBstr::from_modbus_registers(&regs, nan.width(), order)
+                    .unwrap();
+            assert_eq!(decoded, nan);
+        }
+    }
+}
+
+#[test]
+fn register_count_must_match_width() {
+    assert!(matches!(
+        NanBstr::from_modbus_registers(
+            &[0x7FC0, 0x0000],
+            NanWidth::Binary64,
+            WordOrder::ABCD
+        ),
+        Err(Error::InvalidRegisterCount {
+            width: NanWidth::Binary64,
+            expected: 4,
+            actual: 2
+        })
+    ));
+}
+
+#[test]
+fn wrong_order_is_caught_when_not_a_nan() {
+    // Read as CDAB, the registers of this ABCD NaN are a finite number.
+    let regs = NanBstr::from_binary32_bits(0x7FC0_1234)
+        .unwrap()
+        .to_modbus_registers(WordOrder::ABCD);
+    assert!(matches!(
+        NanBstr::from_modbus_registers(
+            &regs,
+            NanWidth::Binary32,
+            WordOrder::CDAB
+        ),
+        Err(Error::NotANan)
+    ));
+}
@@ -1,6 +1,6 @@
 use std::f64;
 
-use cbor_nan_bstr::{NanBstr, NanWidth};
+use cbor_nan_bstr::{Error, NanBstr, NanWidth, TruncationPolicy};
 use dcbor::prelude::*;
 
 #[test]
@@ -9,7 +9,7 @@ fn half_quiet_nan_roundtrip() {
     let n = NanBstr::from_binary16_bits(0x7E00).unwrap();
     assert_eq!(
         n.to_string(),
-        "NaN[16]: + quiet frac=0x200 payload=0x0"
+        "NaN[binary16]: + quiet frac=0x200 payload=0x0"
     );
 
     let cbor = CBOR::from(n.clone());
@@ -26,7 +26,7 @@ fn single_signaling_nan_validates() {
     let n = NanBstr::from_binary32_bits(bits).unwrap();
     assert_eq!(
         n.to_string(),
-        "NaN[32]: - signaling frac=0x1 payload=0x1"
+        "NaN[binary32]: - signaling frac=0x1 payload=0x1"
     );
 }
 
@@ -37,7 +37,7 @@ fn double_quiet_nan_roundtrip() {
     let n = NanBstr::from_binary64_bits(bits).unwrap();
     assert_eq!(
         n.to_string(),
-        "NaN[64]: + quiet frac=0x8000000000123 payload=0x123"
+        "NaN[binary64]: + quiet frac=0x8000000000123 payload=0x123"
     );
 
     let cbor = CBOR::from(n.clone());
@@ -61,7 +61,7 @@ fn quad_quiet_nan_roundtrip() {
     let n = NanBstr::from_binary128_bits(bits).unwrap();
     assert_eq!(
         n.to_string(),
-        "NaN[128]: + quiet frac=0x8000000000000000000000000000 payload=0x0"
+        "NaN[binary128]: + quiet frac=0x8000000000000000000000000000 payload=0x0"
     );
 
     let cbor = CBOR::from(n.clone());
@@ -76,7 +76,7 @@ fn quad_signaling_nan_validates() {
     let n = NanBstr::from_binary128_bits(bits).unwrap();
     assert_eq!(
         n.to_string(),
-        "NaN[128]: + signaling frac=0x1 payload=0x1"
+        "NaN[binary128]: + signaling frac=0x1 payload=0x1"
     );
 }
 
@@ -116,7 +116,13 @@ fn f32_try_from_rejects_non_nan() {
 #[test]
 fn f32_try_from_nanbstr_rejects_wrong_width() {
     let n = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap();
-    assert!(f32::try_from(n).is_err());
+    assert!(matches!(
+        f32::try_from(n),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary32,
+            actual: NanWidth::Binary64,
+        })
+    ));
 }
 
 #[test]
@@ -139,7 +145,289 @@ fn f64_try_from_rejects_non_nan() {
 #[test]
 fn f64_try_from_nanbstr_rejects_wrong_width() {
     let n = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
-    assert!(f64::try_from(n).is_err());
+    assert!(matches!(
+        f64::try_from(n),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary64,
+            actual: NanWidth::Binary32,
+        })
+    ));
+}
+
+#[test]
+fn integer_bits_roundtrip_every_width() {
+    let half = NanBstr::try_from(0x7E01u16).unwrap();
+    assert_eq!(half, NanBstr::from_binary16_bits(0x7E01).unwrap());
+    assert_eq!(u16::try_from(half).unwrap(), 0x7E01);
+
+    let single = NanBstr::try_from(0xFF80_0001u32).unwrap();
+    assert_eq!(single.width(), NanWidth::Binary32);
+    assert_eq!(u32::try_from(single).unwrap(), 0xFF80_0001);
+
+    let double = NanBstr::try_from(0x7FF8_0000_0000_0123u64).unwrap();
+    assert_eq!(double.width(), NanWidth::Binary64);
+    assert_eq!(u64::try_from(double).unwrap(), 0x7FF8_0000_0000_0123);
+
+    let bits = 0x7FFF_0000_0000_0000_0000_0000_0000_0001u128;
+    let quad = NanBstr::try_from(bits).unwrap();
+    assert_eq!(quad.width(), NanWidth::Binary128);
+    assert_eq!(u128::try_from(quad).unwrap(), bits);
+}
+
+#[test]
+fn integer_bits_reject_non_nan() {
+    assert!(matches!(NanBstr::try_from(0x7C00u16), Err(Error::NotANan)));
+    assert!(matches!(NanBstr::try_from(0u32), Err(Error::NotANan)));
+    assert!(matches!(
+        NanBstr::try_from(0x3FF0_0000_0000_0000u64),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(NanBstr::try_from(u128::MIN), Err(Error::NotANan)));
+}
+
+#[test]
+fn integer_bits_reject_wrong_width() {
+    let single = NanBstr::from_binary32_bits(0x7FC0_0000).unwrap();
+    assert!(matches!(
+        u64::try_from(single.clone()),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary64,
+            actual: NanWidth::Binary32
+        })
+    ));
+    assert!(matches!(
+        u16::try_from(single.clone()),
+        Err(Error::WidthMismatch { expected: NanWidth::Binary16, .. })
+    ));
+    assert!(matches!(
+        u128::try_from(single),
+        Err(Error::WidthMismatch { expected: NanWidth::Binary128, .. })
+    ));
+    let half = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert!(matches!(
+        u32::try_from(half),
+        Err(Error::WidthMismatch { expected: NanWidth::Binary32, .. })
+    ));
+}
+
+#[test]
+fn widen_preserves_sign_quiet_and_payload() {
+    let half = NanBstr::from_binary16_bits(0xFC05).unwrap();
+    let double = half.widen_to(NanWidth::Binary64).unwrap();
+    assert_eq!(
+        double,
+        NanBstr::from_binary64_bits(0xFFF0_0000_0000_0005).unwrap()
+    );
+    let quad = half.widen_to(NanWidth::Binary128).unwrap();
+    assert!(quad.sign());
+    assert!(quad.is_signaling());
+    assert_eq!(quad.payload_bits(), 5);
+    assert_eq!(half.widen_to(NanWidth::Binary16).unwrap(), half);
+    assert!(matches!(
+        double.widen_to(NanWidth::Binary32),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary32,
+            actual: NanWidth::Binary64
+        })
+    ));
+}
+
+#[test]
+fn narrow_rejects_payload_that_does_not_fit() {
+    let double = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0200).unwrap();
+    assert!(matches!(
+        double.narrow_to(NanWidth::Binary16),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary16,
+            payload: 0x200
+        })
+    ));
+    assert_eq!(
+        double.narrow_to(NanWidth::Binary32).unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0200).unwrap()
+    );
+    let half = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert!(matches!(
+        half.narrow_to(NanWidth::Binary32),
+        Err(Error::WidthMismatch { .. })
+    ));
+}
+
+#[test]
+fn to_f64_checked_round_trips_narrower_widths() {
+    let originals = [
+        NanBstr::from_binary16_bits(0x7E01).unwrap(),
+        NanBstr::from_binary16_bits(0xFD55).unwrap(),
+        NanBstr::from_binary32_bits(0x7F80_0001).unwrap(),
+        NanBstr::from_binary32_bits(0xFFFF_FFFF).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF0_0000_DEAD_BEEF).unwrap(),
+    ];
+    for orig in originals {
+        let f = orig.to_f64_checked().unwrap();
+        assert!(f.is_nan());
+        let payload = f.to_bits() & ((1 << 51) - 1);
+        assert_eq!(payload as u128, orig.payload_bits());
+        let back = NanBstr::from_f64_bits(f.to_bits())
+            .and_then(|n| n.narrow_to(orig.width()))
+            .unwrap();
+        assert_eq!(back, orig);
+    }
+    let quad = NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0).unwrap();
+    assert!(matches!(
+        quad.to_f64_checked(),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary64,
+            actual: NanWidth::Binary128
+        })
+    ));
+}
+
+#[test]
+fn to_f32_checked_round_trips_binary16() {
+    let orig = NanBstr::from_binary16_bits(0xFE3F).unwrap();
+    let f = orig.to_f32_checked().unwrap();
+    assert_eq!(f.to_bits(), 0xFFC0_003F);
+    let back = NanBstr::from_f32_bits(f.to_bits())
+        .and_then(|n| n.narrow_to(orig.width()))
+        .unwrap();
+    assert_eq!(back, orig);
+    let double = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap();
+    assert!(matches!(
+        double.to_f32_checked(),
+        Err(Error::WidthMismatch { expected: NanWidth::Binary32, .. })
+    ));
+}
+
+#[test]
+fn lossy_conversion_is_exact_when_payload_fits() {
+    let half = NanBstr::from_binary16_bits(0xFC05).unwrap();
+    assert!(half.fits_in(NanWidth::Binary32));
+    assert_eq!(half.to_f64_lossy().to_bits(), 0xFFF0_0000_0000_0005);
+    assert_eq!(half.to_f32_lossy().to_bits(), 0xFF80_0005);
+
+    let quad =
+        NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0x123).unwrap();
+    assert!(quad.fits_in(NanWidth::Binary64));
+    assert_eq!(quad.to_f64_lossy().to_bits(), 0x7FF8_0000_0000_0123);
+}
+
+#[test]
+fn lossy_conversion_keeps_low_payload_bits() {
+    let quad =
+        NanBstr::from_binary128_words(0xFFFF_0000_0000_0001, 0xAB).unwrap();
+    assert!(!quad.fits_in(NanWidth::Binary64));
+    let f = quad.to_f64_lossy();
+    assert_eq!(f.to_bits(), 0xFFF0_0000_0000_00AB);
+    assert_eq!(
+        NanBstr::from_f64_bits(f.to_bits()).unwrap(),
+        quad.convert_width(NanWidth::Binary64, TruncationPolicy::Truncate)
+            .unwrap()
+    );
+    assert!(matches!(
+        quad.convert_width(NanWidth::Binary64, TruncationPolicy::Reject),
+        Err(Error::PayloadTooLarge { width: NanWidth::Binary64, .. })
+    ));
+    assert_eq!(quad.to_f32_lossy().to_bits(), 0xFF80_00AB);
+}
+
+#[test]
+fn lossy_conversion_quiets_signaling_nan_that_would_become_infinity() {
+    // Signaling, with only a payload bit above the binary64 payload width.
+    let quad = NanBstr::from_binary128_words(0x7FFF_0000_0000_0001, 0).unwrap();
+    assert!(quad.is_signaling());
+    let f = quad.to_f64_lossy();
+    assert!(f.is_nan());
+    assert_eq!(f.to_bits(), 0x7FF8_0000_0000_0000);
+
+    let double = NanBstr::from_binary64_bits(0x7FF0_0000_0040_0000).unwrap();
+    assert_eq!(double.to_f32_lossy().to_bits(), 0x7FC0_0000);
+    let narrowed = double
+        .convert_width(NanWidth::Binary16, TruncationPolicy::Truncate)
+        .unwrap();
+    assert_eq!(narrowed, NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
+
+#[test]
+fn from_f64_as_every_target_width() {
+    let value = f64::from_bits(0xFFF8_0000_0000_0001);
+    for (width, bits) in [
+        (NanWidth::Binary16, 0xFE01u128),
+        (NanWidth::Binary32, 0xFFC0_0001),
+        (NanWidth::Binary64, 0xFFF8_0000_0000_0001),
+    ] {
+        let nan = NanBstr::from_f64_as(value, width, TruncationPolicy::Reject)
+            .unwrap();
+        let expected = &bits.to_be_bytes()[16 - width.len()..];
+        assert_eq!(nan, NanBstr::from_be_bytes(expected).unwrap());
+    }
+    let quad = NanBstr::from_f64_as(
+        value,
+        NanWidth::Binary128,
+        TruncationPolicy::Reject,
+    )
+    .unwrap();
+    assert_eq!(quad.payload_bits(), 1);
+    assert!(quad.sign());
+}
+
+#[test]
+fn from_f64_as_exact_fit_and_overflow() {
+    // 0x1FF is the largest binary16 payload.
+    let exact = f64::from_bits(0x7FF8_0000_0000_01FF);
+    let nan = NanBstr::from_f64_as(
+        exact,
+        NanWidth::Binary16,
+        TruncationPolicy::Reject,
+    )
+    .unwrap();
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7FFF).unwrap());
+
+    let overflow = f64::from_bits(0x7FF8_0000_0000_0200);
+    assert!(matches!(
+        NanBstr::from_f64_as(
+            overflow,
+            NanWidth::Binary16,
+            TruncationPolicy::Reject
+        ),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary16,
+            payload: 0x200
+        })
+    ));
+    let truncated = NanBstr::from_f64_as(
+        overflow,
+        NanWidth::Binary16,
+        TruncationPolicy::Truncate,
+    )
+    .unwrap();
+    assert_eq!(truncated, NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
+
+#[test]
+fn from_f32_as_distinguishes_not_a_nan() {
+    assert!(matches!(
+        NanBstr::from_f32_as(
+            1.0,
+            NanWidth::Binary16,
+            TruncationPolicy::Truncate
+        ),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_f64_as(
+            f64::INFINITY,
+            NanWidth::Binary32,
+            TruncationPolicy::Reject
+        ),
+        Err(Error::NotANan)
+    ));
+    let nan = NanBstr::from_f32_as(
+        f32::NAN,
+        NanWidth::Binary64,
+        TruncationPolicy::Reject,
+    )
+    .unwrap();
+    assert_eq!(nan, NanBstr::try_from(f64::NAN).unwrap());
 }
 
 #[test]
@@ -166,14 +454,254 @@ fn read_me() {
 
     // Create from specific bit patterns
     let quiet_nan = NanBstr::from_binary32_bits(0x7FC00001).unwrap();
-    assert_eq!(quiet_nan.to_string(), "NaN[32]: + quiet frac=0x400001 payload=0x1");
+    assert_eq!(quiet_nan.to_string(), "NaN[binary32]: + quiet frac=0x400001 payload=0x1");
     assert_eq!(quiet_nan.to_cbor().diagnostic(), "102(h'7fc00001')");
 
     let signaling_nan = NanBstr::from_binary64_bits(0xFFF0000000000001).unwrap();
-    assert_eq!(signaling_nan.to_string(), "NaN[64]: - signaling frac=0x1 payload=0x1");
+    assert_eq!(signaling_nan.to_string(), "NaN[binary64]: - signaling frac=0x1 payload=0x1");
     assert_eq!(signaling_nan.to_cbor().diagnostic(), "102(h'fff0000000000001')");
 
     // Non-NaNs cannot be converted
     assert!(NanBstr::try_from(1.0f32).is_err());
     assert!(NanBstr::try_from(f64::INFINITY).is_err());
 }
+
+#[test]
+fn from_parts_composes_fields() {
+    assert_eq!(
+        NanBstr::from_parts(NanWidth::Binary16, false, true, 0).unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_parts(NanWidth::Binary32, true, false, 0x1234).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_1234).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_parts(NanWidth::Binary64, false, true, (1 << 51) - 1)
+            .unwrap(),
+        NanBstr::from_binary64_bits(0x7FFF_FFFF_FFFF_FFFF).unwrap()
+    );
+    assert_eq!(
+        NanBstr::from_parts(NanWidth::Binary128, false, false, 1).unwrap(),
+        NanBstr::from_binary128_bits(0x7FFF_0000_0000_0000_0000_0000_0000_0001)
+            .unwrap()
+    );
+}
+
+#[test]
+fn from_parts_rejects_invalid_fields() {
+    assert!(matches!(
+        NanBstr::from_parts(NanWidth::Binary64, false, true, 1 << 51),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary64,
+            payload: 0x8_0000_0000_0000,
+        })
+    ));
+    assert!(matches!(
+        NanBstr::from_parts(NanWidth::Binary16, false, true, 0x200),
+        Err(Error::PayloadTooLarge { .. })
+    ));
+    for width in [NanWidth::Binary16, NanWidth::Binary128] {
+        assert!(matches!(
+            NanBstr::from_parts(width, true, false, 0),
+            Err(Error::NotANan)
+        ));
+    }
+}
+
+#[test]
+fn canonical_quiet_patterns() {
+    let cases: [(NanWidth, &[u8]); 4] = [
+        (NanWidth::Binary16, &[0x7E, 0x00]),
+        (NanWidth::Binary32, &[0x7F, 0xC0, 0x00, 0x00]),
+        (NanWidth::Binary64, &[0x7F, 0xF8, 0, 0, 0, 0, 0, 0]),
+        (
+            NanWidth::Binary128,
+            &[0x7F, 0xFF, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ),
+    ];
+    for (width, bytes) in cases {
+        let n = NanBstr::canonical_quiet(width);
+        assert_eq!(n.as_bytes(), bytes);
+        assert_eq!(n.width(), width);
+        assert!(!n.sign());
+        assert!(n.is_quiet());
+        assert_eq!(n.payload_bits(), 0);
+    }
+    assert_eq!(
+        NanBstr::canonical_quiet(NanWidth::Binary64),
+        NanBstr::try_from(f64::NAN).unwrap()
+    );
+}
+
+#[test]
+fn minimal_signaling_patterns() {
+    let cases: [(NanWidth, &[u8]); 4] = [
+        (NanWidth::Binary16, &[0x7C, 0x01]),
+        (NanWidth::Binary32, &[0x7F, 0x80, 0x00, 0x01]),
+        (NanWidth::Binary64, &[0x7F, 0xF0, 0, 0, 0, 0, 0, 0x01]),
+        (
+            NanWidth::Binary128,
+            &[0x7F, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01],
+        ),
+    ];
+    for (width, bytes) in cases {
+        let n = NanBstr::minimal_signaling(width);
+        assert_eq!(n.as_bytes(), bytes);
+        assert_eq!(n.width(), width);
+        assert!(!n.sign());
+        assert!(n.is_signaling());
+        assert_eq!(n.payload_bits(), 1);
+    }
+    assert_eq!(
+        NanBstr::minimal_signaling(NanWidth::Binary128).to_string(),
+        "NaN[binary128]: + signaling frac=0x1 payload=0x1"
+    );
+}
+
+#[test]
+fn default_is_canonical_binary64_quiet() {
+    let n = NanBstr::default();
+    assert_eq!(n, NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap());
+    assert_eq!(
+        n.to_string(),
+        "NaN[binary64]: + quiet frac=0x8000000000000 payload=0x0"
+    );
+    assert_eq!(CBOR::from(n).diagnostic(), "102(h'7ff8000000000000')");
+
+    #[derive(Default)]
+    struct Reading {
+        value: NanBstr,
+    }
+    assert_eq!(Reading::default().value, NanBstr::default());
+}
+
+#[test]
+fn standard_pattern_constants() {
+    assert_eq!(NanBstr::QNAN_BINARY16, 0x7E00);
+    assert_eq!(NanBstr::QNAN_BINARY32, 0x7FC0_0000);
+    assert_eq!(NanBstr::QNAN_BINARY64, 0x7FF8_0000_0000_0000);
+    assert_eq!(
+        NanBstr::QNAN_BINARY128,
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000
+    );
+    assert_eq!(NanBstr::SNAN_MIN_BINARY16, 0x7C01);
+    assert_eq!(NanBstr::SNAN_MIN_BINARY32, 0x7F80_0001);
+    assert_eq!(NanBstr::SNAN_MIN_BINARY64, 0x7FF0_0000_0000_0001);
+    assert_eq!(
+        NanBstr::SNAN_MIN_BINARY128,
+        0x7FFF_0000_0000_0000_0000_0000_0000_0001
+    );
+    assert_eq!(NanBstr::QNAN_BINARY32, f32::NAN.to_bits());
+    assert_eq!(NanBstr::QNAN_BINARY64, f64::NAN.to_bits());
+}
+
+#[test]
+fn standard_pattern_constants_validate() {
+    let cases = [
+        (
+            NanBstr::from_binary16_bits(NanBstr::QNAN_BINARY16),
+            NanBstr::from_binary16_bits(NanBstr::SNAN_MIN_BINARY16),
+            NanWidth::Binary16,
+        ),
+        (
+            NanBstr::from_binary32_bits(NanBstr::QNAN_BINARY32),
+            NanBstr::from_binary32_bits(NanBstr::SNAN_MIN_BINARY32),
+            NanWidth::Binary32,
+        ),
+        (
+            NanBstr::from_binary64_bits(NanBstr::QNAN_BINARY64),
+            NanBstr::from_binary64_bits(NanBstr::SNAN_MIN_BINARY64),
+            NanWidth::Binary64,
+        ),
+        (
+            NanBstr::from_binary128_bits(NanBstr::QNAN_BINARY128),
+            NanBstr::from_binary128_bits(NanBstr::SNAN_MIN_BINARY128),
+            NanWidth::Binary128,
+        ),
+    ];
+    for (quiet, signaling, width) in cases {
+        assert_eq!(quiet.unwrap(), NanBstr::canonical_quiet(width));
+        assert_eq!(signaling.unwrap(), NanBstr::minimal_signaling(width));
+    }
+}
+
+#[test]
+fn max_payload_per_width() {
+    assert_eq!(NanWidth::Binary16.max_payload(), 0x1FF);
+    assert_eq!(NanWidth::Binary32.max_payload(), 0x3F_FFFF);
+    assert_eq!(NanWidth::Binary64.max_payload(), (1 << 51) - 1);
+    assert_eq!(NanWidth::Binary128.max_payload(), (1 << 111) - 1);
+    for width in [
+        NanWidth::Binary16,
+        NanWidth::Binary32,
+        NanWidth::Binary64,
+        NanWidth::Binary128,
+    ] {
+        let max = width.max_payload();
+        assert!(NanBstr::from_parts(width, false, true, max).is_ok());
+        assert!(NanBstr::from_parts(width, false, true, max + 1).is_err());
+    }
+}
+
+#[test]
+fn from_payload_boundaries() {
+    for width in [
+        NanWidth::Binary16,
+        NanWidth::Binary32,
+        NanWidth::Binary64,
+        NanWidth::Binary128,
+    ] {
+        let max = width.max_payload();
+        for payload in [0, 1, max] {
+            let quiet = NanBstr::from_payload(width, payload).unwrap();
+            assert_eq!(quiet.width(), width);
+            assert!(!quiet.sign());
+            assert!(quiet.is_quiet());
+            assert_eq!(quiet.payload_bits(), payload);
+        }
+        for payload in [1, max] {
+            let signaling =
+                NanBstr::from_payload_signaling(width, payload).unwrap();
+            assert!(!signaling.sign());
+            assert!(signaling.is_signaling());
+            assert_eq!(signaling.payload_bits(), payload);
+        }
+        assert!(matches!(
+            NanBstr::from_payload(width, max + 1),
+            Err(Error::PayloadTooLarge { width: w, payload })
+                if w == width && payload == max + 1
+        ));
+        assert!(matches!(
+            NanBstr::from_payload_signaling(width, max + 1),
+            Err(Error::PayloadTooLarge { .. })
+        ));
+        assert!(matches!(
+            NanBstr::from_payload_signaling(width, 0),
+            Err(Error::SignalingZeroPayload(w)) if w == width
+        ));
+    }
+    assert!(matches!(
+        NanBstr::from_payload(NanWidth::Binary128, u128::MAX),
+        Err(Error::PayloadTooLarge { .. })
+    ));
+}
+
+#[test]
+fn exponent_field() {
+    for (width, exponent) in [
+        (NanWidth::Binary16, 0x1F),
+        (NanWidth::Binary32, 0xFF),
+        (NanWidth::Binary64, 0x7FF),
+        (NanWidth::Binary128, 0x7FFF),
+    ] {
+        for nan in [
+            NanBstr::canonical_quiet(width),
+            NanBstr::minimal_signaling(width),
+            NanBstr::from_parts(width, true, true, width.max_payload()).unwrap(),
+        ] {
+            assert_eq!(nan.exponent_field_width(), width.exponent_bits());
+            assert_eq!(nan.exponent_bits(), exponent);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use cbor_nan_bstr::{Error, NanBstr, NanBstrBuilder, NanWidth};
+
+#[test]
+fn defaults_to_positive_quiet_zero_payload() {
+    for width in [
+        NanWidth::Binary16,
+        NanWidth::Binary32,
+        NanWidth::Binary64,
+        NanWidth::Binary128,
+    ] {
+        let nan = NanBstr::builder(width).build().unwrap();
+        assert_eq!(nan, NanBstr::from_parts(width, false, true, 0).unwrap());
+    }
+    assert_eq!(
+        NanBstr::builder(NanWidth::Binary32).build().unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0000).unwrap()
+    );
+}
+
+#[test]
+fn sets_each_field() {
+    let nan = NanBstr::builder(NanWidth::Binary32)
+        .negative()
+        .signaling()
+        .payload(0x1234)
+        .build()
+        .unwrap();
+    assert_eq!(nan, NanBstr::from_binary32_bits(0xFF80_1234).unwrap());
+
+    let nan = NanBstr::builder(NanWidth::Binary16)
+        .negative()
+        .positive()
+        .signaling()
+        .quiet()
+        .payload(1)
+        .build()
+        .unwrap();
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7E01).unwrap());
+}
+
+#[test]
+fn payload_bytes_and_hex() {
+    let expected = NanBstr::from_binary64_bits(0x7FF8_0000_0000_BEEF).unwrap();
+    let builder = NanBstrBuilder::new(NanWidth::Binary64);
+    assert_eq!(
+        builder
+            .clone()
+            .payload_bytes(&[0xBE, 0xEF])
+            .build()
+            .unwrap(),
+        expected
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .payload_bytes(&[0; 20])
+            .payload_bytes(&[0, 0, 0, 0xBE, 0xEF])
+            .build()
+            .unwrap(),
+        expected
+    );
+    for hex in ["beef", "0xBEEF", " 00be ef ", "0x_be_ef"] {
+        assert_eq!(
+            builder.clone().payload_hex(hex).build().unwrap(),
+            expected,
+            "{hex}"
+        );
+    }
+}
+
+#[test]
+fn rejects_wide_payloads() {
+    assert!(matches!(
+        NanBstr::builder(NanWidth::Binary32)
+            .payload(1 << 22)
+            .build(),
+        Err(Error::PayloadTooLarge {
+            width: NanWidth::Binary32,
+            payload: 0x40_0000,
+        })
+    ));
+    assert!(matches!(
+        NanBstr::builder(NanWidth::Binary16)
+            .payload_hex("0x200")
+            .build(),
+        Err(Error::PayloadTooLarge { .. })
+    ));
+    assert!(matches!(
+        NanBstr::builder(NanWidth::Binary128)
+            .payload_bytes(&[1; 17])
+            .build(),
+        Err(Error::InvalidPayload(_))
+    ));
+    assert!(matches!(
+        NanBstr::builder(NanWidth::Binary128)
+            .payload_hex(&"1".repeat(33))
+            .build(),
+        Err(Error::InvalidPayload(_))
+    ));
+}
+
+#[test]
+fn rejects_bad_hex() {
+    for hex in ["", "0x", "12g4", "-1", "0x 0x1"] {
+        assert!(
+            matches!(
+                NanBstr::builder(NanWidth::Binary64)
+                    .payload_hex(hex)
+                    .build(),
+                Err(Error::InvalidPayload(_))
+            ),
+            "{hex:?}"
+        );
+    }
+}
+
+#[test]
+fn signaling_needs_a_payload() {
+    let builder = NanBstr::builder(NanWidth::Binary64).signaling();
+    assert!(matches!(
+        builder.build(),
+        Err(Error::SignalingZeroPayload(NanWidth::Binary64))
+    ));
+    assert!(matches!(
+        builder.clone().payload_hex("0000").build(),
+        Err(Error::SignalingZeroPayload(NanWidth::Binary64))
+    ));
+    assert!(builder.clone().payload(1).build().is_ok());
+    assert!(builder.quiet().build().is_ok());
+}
@@ -0,0 +1,94 @@
+use cbor_nan_bstr::{Error, NanBstr, NanBstrRef, NanWidth, find_nan_bstr_refs};
+use dcbor::prelude::*;
+
+#[test]
+fn accessors_match_owned_type() {
+    let patterns: [&[u8]; 6] = [
+        &[0x7E, 0x00],
+        &[0xFC, 0x01],
+        &[0x7F, 0xC0, 0x00, 0x01],
+        &[0xFF, 0xF0, 0, 0, 0, 0, 0, 0x01],
+        &[0x7F, 0xFF, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x2A],
+        &[0xFF, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01],
+    ];
+    for bytes in patterns {
+        let borrowed = NanBstrRef::new(bytes).unwrap();
+        let owned = NanBstr::from_be_bytes(bytes).unwrap();
+        assert_eq!(borrowed.width(), owned.width());
+        assert_eq!(borrowed.sign(), owned.sign());
+        assert_eq!(borrowed.is_quiet(), owned.is_quiet());
+        assert_eq!(borrowed.is_signaling(), owned.is_signaling());
+        assert_eq!(borrowed.fraction_bits(), owned.fraction_bits());
+        assert_eq!(borrowed.payload_bits(), owned.payload_bits());
+        assert_eq!(borrowed.to_string(), owned.to_string());
+        assert_eq!(format!("{:#}", borrowed), format!("{:#}", owned));
+        assert_eq!(borrowed.describe(), owned.describe());
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, borrowed);
+        assert_eq!(borrowed.to_owned(), owned);
+        assert_eq!(owned.as_nan_ref(), borrowed);
+    }
+}
+
+#[test]
+fn rejects_what_the_owned_type_rejects() {
+    assert!(matches!(
+        NanBstrRef::new(&[0x7C, 0x00]),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstrRef::new(&[0x7F, 0x80, 0x00, 0x00]),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstrRef::new(&[0x3F, 0xF0, 0, 0, 0, 0, 0, 0]),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstrRef::try_from(&[0x7E, 0x00, 0x00][..]),
+        Err(Error::InvalidLength(3))
+    ));
+}
+
+#[test]
+fn borrows_from_cbor_without_copying() {
+    let cbor = CBOR::from(NanBstr::from_binary32_bits(0x7FC0_0001).unwrap());
+    let nan = NanBstrRef::from_cbor(&cbor).unwrap();
+    let CBORCase::Tagged(_, content) = cbor.as_case() else {
+        unreachable!()
+    };
+    let CBORCase::ByteString(bytes) = content.as_case() else {
+        unreachable!()
+    };
+    assert!(std::ptr::eq(nan.as_bytes(), bytes.data()));
+    assert_eq!(nan.width(), NanWidth::Binary32);
+
+    let wrong_tag =
+        CBOR::to_tagged_value(40000, CBOR::to_byte_string([0x7E, 0]));
+    assert!(matches!(
+        NanBstrRef::from_cbor(&wrong_tag),
+        Err(Error::Cbor(dcbor::Error::WrongTag(..)))
+    ));
+    assert!(matches!(
+        NanBstrRef::from_cbor(&CBOR::from(1)),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+}
+
+#[test]
+fn find_refs_matches_owned_walk() {
+    let mut map = Map::new();
+    map.insert("a", NanBstr::from_binary16_bits(0x7E00).unwrap());
+    map.insert(
+        "b",
+        vec![NanBstr::from_binary64_bits(0xFFF8_0000_0000_0001).unwrap()],
+    );
+    let cbor: CBOR = map.into();
+    let refs = find_nan_bstr_refs(&cbor);
+    let owned = cbor_nan_bstr::find_nan_bstrs(&cbor);
+    assert_eq!(refs.len(), 2);
+    for ((ref_path, nan_ref), (path, nan)) in refs.iter().zip(&owned) {
+        assert_eq!(ref_path, path);
+        assert_eq!(nan_ref, nan);
+    }
+}
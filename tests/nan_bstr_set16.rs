@@ -0,0 +1,92 @@
+use cbor_nan_bstr::{Error, NanBstr, NanBstrSet16, NanWidth};
+
+fn b16(bits: u16) -> NanBstr {
+    NanBstr::from_binary16_bits(bits).unwrap()
+}
+
+#[test]
+fn membership() {
+    let mut set = NanBstrSet16::new();
+    assert!(set.is_empty());
+    assert!(set.insert(&b16(0x7E00)).unwrap());
+    assert!(set.insert(&b16(0xFC01)).unwrap());
+    assert!(!set.insert(&b16(0x7E00)).unwrap());
+    assert_eq!(set.len(), 2);
+
+    assert!(set.contains(&b16(0x7E00)));
+    assert!(set.contains(&b16(0xFC01)));
+    assert!(!set.contains(&b16(0x7E01)));
+
+    assert!(set.remove(&b16(0x7E00)));
+    assert!(!set.remove(&b16(0x7E00)));
+    assert!(!set.contains(&b16(0x7E00)));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn rejects_wider_inputs() {
+    let mut set: NanBstrSet16 = [b16(0x7E00)].into_iter().collect();
+    let single = NanBstr::from_binary32_bits(0x7FC0_0000).unwrap();
+    let double = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap();
+
+    assert!(!set.contains(&single));
+    assert!(!set.contains(&double));
+    assert!(!set.remove(&single));
+    assert!(matches!(
+        set.insert(&single),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary16,
+            actual: NanWidth::Binary32
+        })
+    ));
+    assert!(NanBstrSet16::try_from_iter([&b16(0x7E00), &double]).is_err());
+
+    // `FromIterator` skips NaNs that can never be members.
+    let set: NanBstrSet16 = [b16(0x7E00), single].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn iteration_is_ascending_by_bit_pattern() {
+    let set: NanBstrSet16 = [0xFE00, 0x7C01, 0xFC3F, 0x7E00, 0x7FFF, 0xFFFF]
+        .into_iter()
+        .map(b16)
+        .collect();
+    let members: Vec<u16> = set
+        .iter()
+        .map(|n| u16::from_be_bytes(n.as_bytes().try_into().unwrap()))
+        .collect();
+    assert_eq!(
+        members,
+        vec![0x7C01, 0x7E00, 0x7FFF, 0xFC3F, 0xFE00, 0xFFFF]
+    );
+}
+
+#[test]
+fn set_operations() {
+    let a: NanBstrSet16 =
+        [0x7E00, 0x7C01, 0xFE00].into_iter().map(b16).collect();
+    let b: NanBstrSet16 =
+        [0x7C01, 0xFE00, 0x7D00].into_iter().map(b16).collect();
+
+    let union: Vec<NanBstr> = a.union(&b).iter().collect();
+    assert_eq!(
+        union,
+        vec![b16(0x7C01), b16(0x7D00), b16(0x7E00), b16(0xFE00)]
+    );
+
+    let intersection: Vec<NanBstr> = a.intersection(&b).iter().collect();
+    assert_eq!(intersection, vec![b16(0x7C01), b16(0xFE00)]);
+}
+
+#[test]
+fn every_binary16_nan_fits() {
+    let all: NanBstrSet16 = (0..=u16::MAX)
+        .filter_map(|bits| NanBstr::from_binary16_bits(bits).ok())
+        .collect();
+    // 2 signs × (2^10 − 1) non-zero fractions.
+    assert_eq!(all.len(), 2 * 1023);
+    assert_eq!(all.iter().count(), 2 * 1023);
+    assert!(all.contains(&b16(0x7C01)));
+    assert!(all.contains(&b16(0xFFFF)));
+}
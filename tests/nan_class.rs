@@ -0,0 +1,48 @@
+use cbor_nan_bstr::{NanBstr, NanClass, NanWidth};
+
+#[test]
+fn classifies_every_width() {
+    for width in NanWidth::ALL {
+        let max = width.max_payload();
+        for sign in [false, true] {
+            let nan = NanBstr::from_parts(width, sign, true, 0).unwrap();
+            assert_eq!(nan.classify(), NanClass::QuietZeroPayload);
+            for payload in [1, max] {
+                let nan =
+                    NanBstr::from_parts(width, sign, true, payload).unwrap();
+                assert_eq!(nan.classify(), NanClass::QuietWithPayload);
+                let nan =
+                    NanBstr::from_parts(width, sign, false, payload).unwrap();
+                assert_eq!(nan.classify(), NanClass::Signaling);
+            }
+        }
+        assert_eq!(
+            NanBstr::canonical_quiet(width).classify(),
+            NanClass::QuietZeroPayload
+        );
+        assert_eq!(
+            NanBstr::minimal_signaling(width).classify(),
+            NanClass::Signaling
+        );
+    }
+}
+
+#[test]
+fn display() {
+    assert_eq!(NanClass::QuietZeroPayload.to_string(), "quiet");
+    assert_eq!(NanClass::QuietWithPayload.to_string(), "quiet with payload");
+    assert_eq!(NanClass::Signaling.to_string(), "signaling");
+}
+
+#[test]
+fn matches_exhaustively() {
+    let describe = |nan: &NanBstr| match nan.classify() {
+        NanClass::QuietZeroPayload => "canonical",
+        NanClass::QuietWithPayload => "boxed",
+        NanClass::Signaling => "trap",
+    };
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap();
+    assert_eq!(describe(&nan), "boxed");
+    let nan = NanBstr::from_binary16_bits(0xFC01).unwrap();
+    assert_eq!(describe(&nan), "trap");
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "serde")]
+use cbor_nan_bstr::NanFields;
+use cbor_nan_bstr::{NanBstr, NanInfo, NanWidth};
+
+fn samples() -> Vec<NanBstr> {
+    let mut nans = Vec::new();
+    for width in NanWidth::ALL {
+        let max = width.max_payload();
+        for (sign, quiet, payload) in [
+            (false, true, 0),
+            (true, true, 0),
+            (false, false, 1),
+            (true, false, max),
+            (false, true, max >> 1),
+            (true, true, 0x2A),
+        ] {
+            nans.push(
+                NanBstr::from_parts(width, sign, quiet, payload).unwrap(),
+            );
+        }
+    }
+    nans
+}
+
+#[test]
+fn fields_agree_with_accessors() {
+    for nan in samples() {
+        let info = nan.describe();
+        assert_eq!(info.width, nan.width());
+        assert_eq!(info.sign, nan.sign());
+        assert_eq!(info.quiet, nan.is_quiet());
+        assert_eq!(info.fraction, nan.fraction_bits());
+        assert_eq!(info.payload, nan.payload_bits());
+        assert_eq!(info.as_bytes(), nan.as_bytes());
+        let padding = 16 - nan.width().len();
+        assert!(info.be_bytes[..padding].iter().all(|&b| b == 0));
+        assert_eq!(info.be_bytes, nan.to_bits_u128().to_be_bytes());
+        assert_eq!(NanInfo::from(&nan), info);
+    }
+}
+
+#[test]
+fn display_matches_nan_bstr() {
+    for nan in samples() {
+        assert_eq!(nan.describe().to_string(), nan.to_string());
+    }
+    let nan = NanBstr::from_binary32_bits(0xFFC0_0001).unwrap();
+    assert_eq!(
+        nan.describe().to_string(),
+        "NaN[binary32]: - quiet frac=0x400001 payload=0x1"
+    );
+}
+
+#[test]
+fn is_plain_old_data() {
+    fn assert_pod<T: Copy + Send + Sync + 'static>() {}
+    assert_pod::<NanInfo>();
+    let info = NanBstr::canonical_quiet(NanWidth::Binary16).describe();
+    let copy = info;
+    let handle = std::thread::spawn(move || copy.payload);
+    assert_eq!(handle.join().unwrap(), info.payload);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serializes_as_nan_fields() {
+    let nan = NanBstr::from_binary16_bits(0xFC01).unwrap();
+    let json = serde_json::to_value(nan.describe()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "width": "binary16",
+            "sign": true,
+            "quiet": false,
+            "fraction": "0x1",
+            "payload": "0x1",
+            "bytes": "fc01",
+        })
+    );
+    assert_eq!(json, serde_json::to_value(NanFields::from(&nan)).unwrap());
+    let back: NanInfo = serde_json::from_value(json).unwrap();
+    assert_eq!(back, nan.describe());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserializing_checks_fields_against_bytes() {
+    let nan = NanBstr::from_binary32_bits(0x7FC0_002A).unwrap();
+    let mut fields = NanFields::from(&nan);
+    fields.sign = true;
+    let json = serde_json::to_value(&fields).unwrap();
+    assert!(serde_json::from_value::<NanInfo>(json).is_err());
+
+    let mut fields = NanFields::from(&nan);
+    fields.bytes = "7c00".into();
+    let json = serde_json::to_value(&fields).unwrap();
+    assert!(serde_json::from_value::<NanInfo>(json).is_err());
+}
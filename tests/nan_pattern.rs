@@ -0,0 +1,293 @@
+use cbor_nan_bstr::{Error, NanBstr, NanPattern, NanWidth, WidthSet};
+
+fn b16(bits: u16) -> NanBstr {
+    NanBstr::from_binary16_bits(bits).unwrap()
+}
+
+fn b32(bits: u32) -> NanBstr {
+    NanBstr::from_binary32_bits(bits).unwrap()
+}
+
+fn b64(bits: u64) -> NanBstr {
+    NanBstr::from_binary64_bits(bits).unwrap()
+}
+
+#[test]
+fn any_matches_everything() {
+    let p = NanPattern::any();
+    assert!(p.matches(&b16(0x7E00)));
+    assert!(p.matches(&b32(0xFF80_0001)));
+    assert!(p.matches(&b64(0x7FF8_0000_0000_0123)));
+}
+
+#[test]
+fn width_constraint() {
+    let p = NanPattern::any().widths([NanWidth::Binary32, NanWidth::Binary64]);
+    assert!(!p.matches(&b16(0x7E00)));
+    assert!(p.matches(&b32(0x7FC0_0000)));
+    assert!(p.matches(&b64(0x7FF8_0000_0000_0000)));
+
+    let p = NanPattern::any().width(NanWidth::Binary16);
+    assert!(p.matches(&b16(0x7E00)));
+    assert!(!p.matches(&b32(0x7FC0_0000)));
+}
+
+#[test]
+fn sign_constraint() {
+    let pos = NanPattern::any().positive();
+    let neg = NanPattern::any().negative();
+    assert!(pos.matches(&b32(0x7FC0_0000)));
+    assert!(!pos.matches(&b32(0xFFC0_0000)));
+    assert!(neg.matches(&b32(0xFFC0_0000)));
+    assert!(!neg.matches(&b32(0x7FC0_0000)));
+}
+
+#[test]
+fn quietness_constraint() {
+    let quiet = NanPattern::any().quiet();
+    let signaling = NanPattern::any().signaling();
+    assert!(quiet.matches(&b16(0x7E00)));
+    assert!(!quiet.matches(&b16(0x7C01)));
+    assert!(signaling.matches(&b16(0x7C01)));
+    assert!(!signaling.matches(&b16(0x7E00)));
+}
+
+#[test]
+fn exact_payload_constraint() {
+    let p = NanPattern::any().payload(0x123);
+    assert!(p.matches(&b64(0x7FF8_0000_0000_0123)));
+    assert!(!p.matches(&b64(0x7FF8_0000_0000_0124)));
+}
+
+#[test]
+fn masked_payload_constraint() {
+    let p = NanPattern::any().payload_masked(0xFF, 0x07);
+    assert!(p.matches(&b32(0x7FC0_0107)));
+    assert!(p.matches(&b32(0x7FC0_0007)));
+    assert!(!p.matches(&b32(0x7FC0_0008)));
+}
+
+#[test]
+fn payload_range_constraint() {
+    let p = NanPattern::any().payload_range(0x10..=0x20);
+    assert!(!p.matches(&b32(0x7FC0_000F)));
+    assert!(p.matches(&b32(0x7FC0_0010)));
+    assert!(p.matches(&b32(0x7FC0_0020)));
+    assert!(!p.matches(&b32(0x7FC0_0021)));
+}
+
+#[test]
+fn compound_pattern() {
+    // Any negative signaling binary32 whose payload & 0xFF == 0x07.
+    let p = NanPattern::any()
+        .width(NanWidth::Binary32)
+        .negative()
+        .signaling()
+        .payload_masked(0xFF, 0x07);
+    assert!(p.matches(&b32(0xFF80_0107)));
+    assert!(!p.matches(&b32(0x7F80_0107))); // positive
+    assert!(!p.matches(&b32(0xFFC0_0107))); // quiet
+    assert!(!p.matches(&b32(0xFF80_0108))); // payload
+    assert!(!p.matches(&b64(0xFFF0_0000_0000_0107))); // width
+}
+
+#[test]
+fn or_and_not_combinators() {
+    let half = NanPattern::any().width(NanWidth::Binary16);
+    let signaling = NanPattern::any().signaling();
+
+    let either = half.clone().or(signaling.clone());
+    assert!(either.matches(&b16(0x7E00)));
+    assert!(either.matches(&b32(0x7F80_0001)));
+    assert!(!either.matches(&b32(0x7FC0_0000)));
+
+    let both = half.clone().and(signaling);
+    assert!(both.matches(&b16(0x7C01)));
+    assert!(!both.matches(&b16(0x7E00)));
+
+    let not_half = !half.clone();
+    assert!(!not_half.matches(&b16(0x7E00)));
+    assert!(not_half.matches(&b32(0x7FC0_0000)));
+    assert_eq!(!not_half, half);
+}
+
+#[test]
+fn filter_owned_and_borrowed() {
+    let nans = vec![
+        b16(0x7E00),
+        b32(0xFF80_0107),
+        b32(0x7FC0_0000),
+        b64(0xFFF0_0000_0000_0001),
+    ];
+    let p = NanPattern::any().negative();
+
+    let borrowed: Vec<&NanBstr> = p.filter(&nans).collect();
+    assert_eq!(borrowed, vec![&nans[1], &nans[3]]);
+
+    let owned: Vec<NanBstr> = p.filter(nans.clone()).collect();
+    assert_eq!(owned, vec![nans[1].clone(), nans[3].clone()]);
+}
+
+// ─────────────────────────────── Text syntax ────────────────────────────────
+
+fn parse(s: &str) -> NanPattern {
+    s.parse().unwrap()
+}
+
+fn parse_err(s: &str) -> (usize, String) {
+    match s.parse::<NanPattern>() {
+        Err(Error::InvalidPattern { offset, token, .. }) => (offset, token),
+        other => panic!("expected InvalidPattern, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_each_clause() {
+    assert_eq!(parse("any"), NanPattern::any());
+    assert_eq!(
+        parse("w=32,64"),
+        NanPattern::any().widths([NanWidth::Binary32, NanWidth::Binary64])
+    );
+    assert_eq!(parse("w=16"), NanPattern::any().width(NanWidth::Binary16));
+    assert_eq!(parse("w=128"), NanPattern::any().width(NanWidth::Binary128));
+    assert_eq!(parse("w=none"), NanPattern::any().widths([]));
+    assert_eq!(parse("sign=+"), NanPattern::any().positive());
+    assert_eq!(parse("sign=-"), NanPattern::any().negative());
+    assert_eq!(parse("kind=qnan"), NanPattern::any().quiet());
+    assert_eq!(parse("kind=snan"), NanPattern::any().signaling());
+    assert_eq!(parse("payload==0x123"), NanPattern::any().payload(0x123));
+    assert_eq!(parse("payload==291"), NanPattern::any().payload(0x123));
+    assert_eq!(
+        parse("payload&0xff==0x7"),
+        NanPattern::any().payload_masked(0xFF, 0x07)
+    );
+    assert_eq!(
+        parse("payload=0x10..=0x20"),
+        NanPattern::any().payload_range(0x10..=0x20)
+    );
+}
+
+#[test]
+fn parse_compound_pattern() {
+    let p = parse("w=32,64 sign=- kind=snan payload&0xff==0x7");
+    let expected = NanPattern::any()
+        .widths([NanWidth::Binary32, NanWidth::Binary64])
+        .negative()
+        .signaling()
+        .payload_masked(0xFF, 0x07);
+    assert_eq!(p, expected);
+    assert!(p.matches(&b32(0xFF80_0107)));
+    assert!(p.matches(&b64(0xFFF0_0000_0000_0007)));
+    assert!(!p.matches(&b16(0xFC07)));
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    let p = parse("w=32 kind=snan | sign=-");
+    let expected = NanPattern::any()
+        .width(NanWidth::Binary32)
+        .signaling()
+        .or(NanPattern::any().negative());
+    assert_eq!(p, expected);
+    assert!(p.matches(&b32(0x7F80_0001)));
+    assert!(p.matches(&b16(0xFE00)));
+    assert!(!p.matches(&b16(0x7C01)));
+
+    let grouped = parse("w=32 (kind=snan | sign=-)");
+    assert!(!grouped.matches(&b16(0xFE00)));
+    assert!(grouped.matches(&b32(0xFFC0_0000)));
+}
+
+#[test]
+fn parse_negation() {
+    let p = parse("!w=16");
+    assert_eq!(p, !NanPattern::any().width(NanWidth::Binary16));
+    let p = parse("!(w=16 kind=qnan)");
+    assert!(!p.matches(&b16(0x7E00)));
+    assert!(p.matches(&b16(0x7C01)));
+}
+
+#[test]
+fn display_round_trips() {
+    let patterns = [
+        NanPattern::any(),
+        NanPattern::any().width(NanWidth::Binary16),
+        NanPattern::any().widths([]),
+        NanPattern::any().widths([]).negative(),
+        NanPattern::any()
+            .widths([NanWidth::Binary32, NanWidth::Binary64])
+            .negative()
+            .signaling()
+            .payload_masked(0xFF, 0x07),
+        NanPattern::any().quiet().payload(0x123),
+        NanPattern::any().payload_range(1..=0x1F),
+        NanPattern::any()
+            .width(NanWidth::Binary32)
+            .or(NanPattern::any().negative().quiet()),
+        !NanPattern::any().width(NanWidth::Binary128).positive(),
+        NanPattern::any().width(NanWidth::Binary64).and(
+            NanPattern::any()
+                .signaling()
+                .or(NanPattern::any().payload(0)),
+        ),
+    ];
+    for p in patterns {
+        let text = p.to_string();
+        assert_eq!(parse(&text), p, "round trip of `{}`", text);
+    }
+    assert_eq!(
+        NanPattern::any()
+            .widths([NanWidth::Binary32, NanWidth::Binary64])
+            .negative()
+            .signaling()
+            .payload_masked(0xFF, 0x07)
+            .to_string(),
+        "w=32,64 sign=- kind=snan payload&0xff==0x7"
+    );
+    assert_eq!(
+        NanPattern::any()
+            .width(NanWidth::Binary64)
+            .and(
+                NanPattern::any()
+                    .signaling()
+                    .or(NanPattern::any().payload(0))
+            )
+            .to_string(),
+        "w=64 (kind=snan | payload==0x0)"
+    );
+    assert_eq!(NanPattern::any().widths([]).to_string(), "w=none");
+}
+
+#[test]
+fn malformed_input_points_at_token() {
+    assert_eq!(parse_err("w=32 sign=x"), (5, "sign=x".to_string()));
+    assert_eq!(parse_err("w=24"), (0, "w=24".to_string()));
+    assert_eq!(parse_err("kind=qnan  bogus"), (11, "bogus".to_string()));
+    assert_eq!(parse_err("kind=nan"), (0, "kind=nan".to_string()));
+    assert_eq!(parse_err("payload==0xZZ"), (0, "payload==0xZZ".to_string()));
+    assert_eq!(parse_err("payload&0xff"), (0, "payload&0xff".to_string()));
+    assert_eq!(parse_err("payload=1..2"), (0, "payload=1..2".to_string()));
+    assert_eq!(parse_err("w=32 )"), (5, ")".to_string()));
+    assert_eq!(parse_err("| w=32"), (0, "|".to_string()));
+    assert_eq!(parse_err("(w=32"), (5, String::new()));
+    assert_eq!(parse_err(""), (0, String::new()));
+    assert_eq!(parse_err("w=32 |"), (6, String::new()));
+}
+
+#[test]
+fn width_clause_uses_width_set_text() {
+    for bits in 0u8..16 {
+        let set: WidthSet = NanWidth::ALL
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| bits & (1 << i) != 0)
+            .map(|(_, w)| w)
+            .collect();
+        let pattern = NanPattern::Width(set);
+        let text = format!("w={}", set.to_string().replace('|', ","));
+        assert_eq!(pattern.to_string(), text);
+        assert_eq!(parse(&text), pattern);
+    }
+    assert_eq!(parse_err("w=016"), (0, "w=016".to_string()));
+    assert_eq!(parse_err("w=binary16"), (0, "w=binary16".to_string()));
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use cbor_nan_bstr::WithNans;
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Status {
+    Ok,
+    Fault(f64),
+    Range { low: f32, high: f32 },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Reading {
+    at: u64,
+    value: f64,
+    status: Status,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Telemetry {
+    station: String,
+    readings: Vec<Reading>,
+    calibration: Option<f32>,
+    limits: BTreeMap<String, f64>,
+    history: (f32, f64),
+}
+
+fn telemetry(nan64: f64, nan32: f32) -> Telemetry {
+    Telemetry {
+        station: "north".into(),
+        readings: vec![
+            Reading {
+                at: 1,
+                value: 20.5,
+                status: Status::Ok,
+            },
+            Reading {
+                at: 2,
+                value: nan64,
+                status: Status::Fault(nan64),
+            },
+            Reading {
+                at: 3,
+                value: f64::INFINITY,
+                status: Status::Range {
+                    low: nan32,
+                    high: 1.0,
+                },
+            },
+        ],
+        calibration: Some(nan32),
+        limits: [("max".to_string(), nan64), ("min".to_string(), -4.0)]
+            .into_iter()
+            .collect(),
+        history: (nan32, nan64),
+    }
+}
+
+fn encode(value: &impl Serialize) -> Vec<u8> {
+    let mut data = Vec::new();
+    ciborium::into_writer(value, &mut data).unwrap();
+    data
+}
+
+#[test]
+fn round_trips_nested_nans_bit_exactly() {
+    let nan64 = f64::from_bits(0xFFF0_0000_0000_0BAD);
+    let nan32 = f32::from_bits(0x7FC0_1234);
+    let data = encode(&WithNans::<_>(telemetry(nan64, nan32)));
+
+    let WithNans(decoded): WithNans<Telemetry> =
+        ciborium::from_reader(data.as_slice()).unwrap();
+    assert_eq!(decoded.station, "north");
+    assert_eq!(decoded.readings[0].value, 20.5);
+    assert_eq!(decoded.readings[1].value.to_bits(), nan64.to_bits());
+    assert!(matches!(
+        decoded.readings[1].status,
+        Status::Fault(v) if v.to_bits() == nan64.to_bits()
+    ));
+    assert_eq!(decoded.readings[2].value, f64::INFINITY);
+    assert!(matches!(
+        decoded.readings[2].status,
+        Status::Range { low, high: 1.0 } if low.to_bits() == nan32.to_bits()
+    ));
+    assert_eq!(decoded.calibration.unwrap().to_bits(), nan32.to_bits());
+    assert_eq!(decoded.limits["max"].to_bits(), nan64.to_bits());
+    assert_eq!(decoded.limits["min"], -4.0);
+    assert_eq!(decoded.history.0.to_bits(), nan32.to_bits());
+    assert_eq!(decoded.history.1.to_bits(), nan64.to_bits());
+}
+
+#[test]
+fn nans_are_tagged() {
+    let nan = f64::from_bits(0x7FF8_0000_0000_0001);
+    assert_eq!(
+        encode(&WithNans::<_>(vec![nan])),
+        hex!("81 d866 48 7ff8000000000001")
+    );
+    let nan = f32::from_bits(0x7F80_0001);
+    assert_eq!(encode(&WithNans::<_>(nan)), hex!("d866 44 7f800001"));
+}
+
+#[test]
+fn other_values_are_byte_identical() {
+    let plain = telemetry(1.25, -0.5);
+    assert_eq!(encode(&WithNans::<_>(&plain)), encode(&plain));
+}
+
+#[test]
+fn plain_floats_still_decode() {
+    let data = encode(&(1.5f64, 2.5f32, f64::NAN));
+    let WithNans(decoded): WithNans<(f64, f32, f64)> =
+        ciborium::from_reader(data.as_slice()).unwrap();
+    assert_eq!((decoded.0, decoded.1), (1.5, 2.5));
+    assert!(decoded.2.is_nan());
+}
+
+#[test]
+fn narrower_patterns_follow_the_policy() {
+    let data = encode(&WithNans::<_>(vec![f32::from_bits(0x7F80_0001)]));
+
+    let rejected: Result<WithNans<Vec<f64>>, _> =
+        ciborium::from_reader(data.as_slice());
+    assert!(rejected.is_err());
+
+    let WithNans(widened): WithNans<Vec<f64>, true> =
+        ciborium::from_reader(data.as_slice()).unwrap();
+    assert_eq!(widened[0].to_bits(), 0x7FF0_0000_0000_0001);
+}
+
+#[test]
+fn wider_patterns_are_rejected() {
+    let data =
+        encode(&WithNans::<_>(f64::from_bits(0x7FF8_0000_0000_0001)));
+    let narrowed: Result<WithNans<f32, true>, _> =
+        ciborium::from_reader(data.as_slice());
+    assert!(narrowed.is_err());
+}
+
+#[test]
+fn widening_wrapper_round_trips() {
+    let nan = f64::from_bits(0xFFF0_0000_0000_0BAD);
+    let value: WithNans<Vec<f64>, true> = WithNans(vec![1.5, nan]);
+    let data = encode(&value);
+    assert_eq!(data, encode(&WithNans::<_>(vec![1.5, nan])));
+    let WithNans(decoded): WithNans<Vec<f64>, true> =
+        ciborium::from_reader(data.as_slice()).unwrap();
+    assert_eq!(decoded[0], 1.5);
+    assert_eq!(decoded[1].to_bits(), nan.to_bits());
+}
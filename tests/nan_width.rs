@@ -0,0 +1,292 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn bits_round_trip() {
+    for (width, bits, len) in [
+        (NanWidth::Binary16, 16, 2),
+        (NanWidth::Binary32, 32, 4),
+        (NanWidth::Binary64, 64, 8),
+        (NanWidth::Binary128, 128, 16),
+    ] {
+        assert_eq!(width.bits(), bits);
+        assert_eq!(width.bits() as usize, width.len() * 8);
+        assert_eq!(NanWidth::from_bits(bits).unwrap(), width);
+        assert_eq!(NanWidth::from_len(len).unwrap(), width);
+    }
+}
+
+#[test]
+fn from_bits_rejects_other_counts() {
+    for bits in [0, 8, 2, 24, 80, 256] {
+        let err = NanWidth::from_bits(bits).unwrap_err();
+        assert!(
+            matches!(&err, Error::InvalidWidth(text) if *text == format!("{bits} bits"))
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "invalid NaN width `{bits} bits`: expected binary16, \
+                 binary32, binary64, or binary128"
+            )
+        );
+    }
+}
+
+#[test]
+fn all_is_narrowest_first_and_ordered() {
+    assert_eq!(
+        NanWidth::ALL,
+        [
+            NanWidth::Binary16,
+            NanWidth::Binary32,
+            NanWidth::Binary64,
+            NanWidth::Binary128,
+        ]
+    );
+    assert!(NanWidth::ALL.is_sorted());
+    assert!(NanWidth::ALL.windows(2).all(|w| w[0].bits() < w[1].bits()));
+    assert_eq!(NanWidth::ALL.iter().max(), Some(&NanWidth::Binary128));
+    assert!(NanWidth::Binary16 < NanWidth::Binary128);
+}
+
+#[test]
+fn display_and_parse() {
+    for width in NanWidth::ALL {
+        let bits = width.bits();
+        assert_eq!(width.to_string(), format!("binary{bits}"));
+        for text in [
+            format!("binary{bits}"),
+            format!("f{bits}"),
+            bits.to_string(),
+        ] {
+            assert_eq!(text.parse::<NanWidth>().unwrap(), width, "{text}");
+        }
+    }
+}
+
+#[test]
+fn parse_rejects_unknown_widths() {
+    for text in [
+        "", "binary", "f", "binary24", "f80", "16 ", "Binary16", "+32", "bf16",
+    ] {
+        assert!(
+            matches!(text.parse::<NanWidth>(), Err(Error::InvalidWidth(t)) if t == text),
+            "{text:?}"
+        );
+    }
+}
+
+#[test]
+fn nan_bstr_display_uses_width_display() {
+    for width in NanWidth::ALL {
+        let nan = NanBstr::canonical_quiet(width);
+        assert!(nan.to_string().starts_with(&format!("NaN[{width}]: ")));
+    }
+}
+
+#[test]
+fn geometry_constants() {
+    let expected = [
+        (NanWidth::Binary16, 5, 10, 9, 15),
+        (NanWidth::Binary32, 8, 23, 22, 31),
+        (NanWidth::Binary64, 11, 52, 51, 63),
+        (NanWidth::Binary128, 15, 112, 111, 127),
+    ];
+    for (width, exponent, fraction, quiet, sign) in expected {
+        assert_eq!(width.exponent_bits(), exponent);
+        assert_eq!(width.fraction_bits(), fraction);
+        assert_eq!(width.payload_bits(), quiet);
+        assert_eq!(width.quiet_bit_index(), quiet);
+        assert_eq!(width.sign_bit_index(), sign);
+        assert_eq!(
+            1 + width.exponent_bits() + width.fraction_bits(),
+            width.bits()
+        );
+        assert_eq!(width.max_payload().count_ones(), width.payload_bits());
+    }
+    assert_eq!(NanWidth::Binary16.exponent_mask_u128(), 0x7C00);
+    assert_eq!(NanWidth::Binary16.fraction_mask_u128(), 0x03FF);
+    assert_eq!(NanWidth::Binary16.quiet_mask_u128(), 0x0200);
+    assert_eq!(NanWidth::Binary32.exponent_mask_u128(), 0x7F80_0000);
+    assert_eq!(NanWidth::Binary32.fraction_mask_u128(), 0x007F_FFFF);
+    assert_eq!(NanWidth::Binary32.quiet_mask_u128(), 0x0040_0000);
+    assert_eq!(
+        NanWidth::Binary64.exponent_mask_u128(),
+        f64::INFINITY.to_bits() as u128
+    );
+    assert_eq!(NanWidth::Binary64.quiet_mask_u128(), 0x0008_0000_0000_0000);
+}
+
+#[test]
+fn masks_partition_the_bits() {
+    for width in NanWidth::ALL {
+        let sign = 1u128 << width.sign_bit_index();
+        let exponent = width.exponent_mask_u128();
+        let fraction = width.fraction_mask_u128();
+        assert_eq!(sign & exponent, 0);
+        assert_eq!(exponent & fraction, 0);
+        assert_eq!(
+            sign | exponent | fraction,
+            u128::MAX >> (128 - width.bits())
+        );
+        assert_eq!(width.quiet_mask_u128() | width.max_payload(), fraction);
+        assert_eq!(width.quiet_mask_u128() & width.max_payload(), 0);
+    }
+}
+
+#[test]
+fn masks_agree_with_accessors() {
+    for width in NanWidth::ALL {
+        let payloads = [1, 0x2A, width.max_payload() >> 1, width.max_payload()];
+        for (sign, quiet, payload) in payloads.into_iter().flat_map(|p| {
+            [(false, false, p), (true, true, p), (true, false, p)]
+        }) {
+            let nan = NanBstr::from_parts(width, sign, quiet, payload).unwrap();
+            let bits = nan
+                .as_bytes()
+                .iter()
+                .fold(0u128, |acc, &b| (acc << 8) | b as u128);
+            assert_eq!(bits >> width.sign_bit_index() == 1, nan.sign());
+            assert_eq!(bits & width.quiet_mask_u128() != 0, nan.is_quiet());
+            assert_eq!(
+                bits & width.exponent_mask_u128(),
+                width.exponent_mask_u128()
+            );
+            assert_eq!(bits & width.fraction_mask_u128(), nan.fraction_bits());
+            assert_eq!(bits & width.max_payload(), nan.payload_bits());
+            assert_eq!(nan.payload_bits(), payload);
+            let view = nan.as_nan_ref();
+            assert_eq!(view.sign(), nan.sign());
+            assert_eq!(view.is_quiet(), nan.is_quiet());
+            assert_eq!(view.fraction_bits(), nan.fraction_bits());
+        }
+    }
+}
+
+#[test]
+fn for_payload_picks_the_narrowest_width() {
+    let boundaries = [
+        (9, NanWidth::Binary16, NanWidth::Binary32),
+        (22, NanWidth::Binary32, NanWidth::Binary64),
+        (51, NanWidth::Binary64, NanWidth::Binary128),
+    ];
+    for (bits, below, above) in boundaries {
+        let edge = 1u128 << bits;
+        assert_eq!(NanWidth::for_payload(edge - 1).unwrap(), below, "{bits}");
+        assert_eq!(NanWidth::for_payload(edge).unwrap(), above, "{bits}");
+        assert!(below.fits_payload(edge - 1));
+        assert!(!below.fits_payload(edge));
+        assert!(above.fits_payload(edge));
+    }
+    assert_eq!(NanWidth::for_payload(0).unwrap(), NanWidth::Binary16);
+    assert_eq!(NanWidth::for_payload(1).unwrap(), NanWidth::Binary16);
+    assert_eq!(
+        NanWidth::for_payload((1 << 111) - 1).unwrap(),
+        NanWidth::Binary128
+    );
+}
+
+#[test]
+fn for_payload_rejects_payloads_over_111_bits() {
+    for payload in [1u128 << 111, 1 << 112, u128::MAX] {
+        assert!(!NanWidth::Binary128.fits_payload(payload));
+        assert!(matches!(
+            NanWidth::for_payload(payload),
+            Err(Error::PayloadTooLarge { width: NanWidth::Binary128, payload: p })
+                if p == payload
+        ));
+    }
+}
+
+#[test]
+fn for_payload_agrees_with_fits_in() {
+    for bits in 0..111 {
+        for payload in [1u128 << bits, (2u128 << bits) - 1] {
+            let width = NanWidth::for_payload(payload).unwrap();
+            let nan =
+                NanBstr::from_parts(NanWidth::Binary128, false, true, payload)
+                    .unwrap();
+            for other in NanWidth::ALL {
+                assert_eq!(nan.fits_in(other), other >= width);
+                assert_eq!(other.fits_payload(payload), other >= width);
+            }
+        }
+    }
+}
+
+#[test]
+fn canonical_quiet_values() {
+    assert_eq!(NanWidth::Binary16.canonical_quiet_bits(), 0x7E00);
+    assert_eq!(NanWidth::Binary32.canonical_quiet_bits(), 0x7FC0_0000);
+    assert_eq!(
+        NanWidth::Binary64.canonical_quiet_bits(),
+        0x7FF8_0000_0000_0000
+    );
+    assert_eq!(
+        NanWidth::Binary128.canonical_quiet_bits(),
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000
+    );
+    assert_eq!(
+        NanWidth::Binary32.canonical_quiet_bits(),
+        f32::NAN.to_bits() as u128
+    );
+    assert_eq!(
+        NanWidth::Binary64.canonical_quiet_bits(),
+        f64::NAN.to_bits() as u128
+    );
+}
+
+#[test]
+fn canonical_quiet_byte_values() {
+    let expected: [&[u8]; 4] = [
+        &[0x7E, 0x00],
+        &[0x7F, 0xC0, 0x00, 0x00],
+        &[0x7F, 0xF8, 0, 0, 0, 0, 0, 0],
+        &[0x7F, 0xFF, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ];
+    for (width, pattern) in NanWidth::ALL.into_iter().zip(expected) {
+        let packed = width.canonical_quiet_bytes();
+        assert_eq!(packed.width as usize, width.len());
+        let padding = 16 - width.len();
+        assert_eq!(&packed.bytes[padding..], pattern);
+        assert!(packed.bytes[..padding].iter().all(|&b| b == 0));
+    }
+}
+
+#[test]
+fn max_payload_values() {
+    assert_eq!(NanWidth::Binary16.max_payload(), 0x1FF);
+    assert_eq!(NanWidth::Binary32.max_payload(), 0x3F_FFFF);
+    assert_eq!(NanWidth::Binary64.max_payload(), 0x7_FFFF_FFFF_FFFF);
+    assert_eq!(
+        NanWidth::Binary128.max_payload(),
+        0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF
+    );
+}
+
+#[test]
+fn canonical_quiet_agrees_with_nan_bstr() {
+    for width in NanWidth::ALL {
+        let nan = NanBstr::canonical_quiet(width);
+        let packed = width.canonical_quiet_bytes();
+        assert_eq!(NanBstr::try_from(packed).unwrap(), nan);
+        assert_eq!(
+            nan.as_bytes(),
+            &width.canonical_quiet_bits().to_be_bytes()[16 - width.len()..]
+        );
+        assert_eq!(nan.payload_bits(), 0);
+        let widest = NanBstr::from_payload(width, width.max_payload()).unwrap();
+        assert_eq!(widest.payload_bits(), width.max_payload());
+    }
+}
+
+#[test]
+fn helpers_are_const() {
+    const BITS: u128 = NanWidth::Binary16.canonical_quiet_bits();
+    const PACKED: cbor_nan_bstr::PackedNanBstr =
+        NanWidth::Binary16.canonical_quiet_bytes();
+    const MAX: u128 = NanWidth::Binary16.max_payload();
+    assert_eq!(BITS, 0x7E00);
+    assert_eq!(PACKED.bytes[14..], [0x7E, 0x00]);
+    assert_eq!(MAX, 0x1FF);
+}
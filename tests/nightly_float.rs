@@ -0,0 +1,71 @@
+#![cfg(feature = "nightly-float")]
+#![feature(f16, f128)]
+
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn f16_to_nanbstr_roundtrip() {
+    let n = NanBstr::try_from(f16::NAN).unwrap();
+    assert_eq!(n.width(), NanWidth::Binary16);
+
+    let back = f16::try_from(n).unwrap();
+    assert!(back.is_nan());
+}
+
+#[test]
+fn f16_keeps_exact_bits() {
+    for bits in [0x7C01, 0x7E00, 0xFDFF, 0xFFFF] {
+        let n = NanBstr::try_from(f16::from_bits(bits)).unwrap();
+        assert_eq!(n, NanBstr::from_binary16_bits(bits).unwrap());
+        assert_eq!(f16::try_from(n).unwrap().to_bits(), bits);
+    }
+}
+
+#[test]
+fn f16_try_from_rejects_non_nan() {
+    for value in [1.0f16, f16::INFINITY, f16::NEG_INFINITY, 0.0] {
+        assert!(matches!(NanBstr::try_from(value), Err(Error::NotANan)));
+    }
+}
+
+#[test]
+fn f16_try_from_nanbstr_rejects_wrong_width() {
+    let n = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert!(matches!(f16::try_from(n), Err(Error::InvalidLength(4))));
+}
+
+#[test]
+fn f128_to_nanbstr_roundtrip() {
+    let n = NanBstr::try_from(f128::NAN).unwrap();
+    assert_eq!(n.width(), NanWidth::Binary128);
+
+    let back = f128::try_from(n).unwrap();
+    assert!(back.is_nan());
+}
+
+#[test]
+fn f128_keeps_exact_bits() {
+    for bits in [
+        0x7FFF_0000_0000_0000_0000_0000_0000_0001,
+        0x7FFF_8000_0000_0000_0000_0000_0000_0000,
+        0xFFFF_0123_4567_89AB_CDEF_0123_4567_89AB,
+        u128::MAX,
+    ] {
+        let n = NanBstr::try_from(f128::from_bits(bits)).unwrap();
+        assert_eq!(n, NanBstr::from_binary128_bits(bits).unwrap());
+        assert_eq!(f128::try_from(n).unwrap().to_bits(), bits);
+    }
+}
+
+#[test]
+fn f128_try_from_rejects_non_nan() {
+    for value in [1.0f128, f128::INFINITY, f128::NEG_INFINITY, 0.0] {
+        assert!(matches!(NanBstr::try_from(value), Err(Error::NotANan)));
+    }
+}
+
+#[test]
+fn f128_try_from_nanbstr_rejects_wrong_width() {
+    let n = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap();
+    assert!(matches!(f128::try_from(n), Err(Error::InvalidLength(8))));
+}
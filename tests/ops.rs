@@ -0,0 +1,114 @@
+use cbor_nan_bstr::{
+    NanBstr,
+    ops::{MaybeFloat, maximum, maximum_number, minimum, minimum_number},
+};
+
+type Op = fn(MaybeFloat, MaybeFloat) -> MaybeFloat;
+
+const OPS: [(&str, Op); 4] = [
+    ("minimum", minimum),
+    ("maximum", maximum),
+    ("minimumNumber", minimum_number),
+    ("maximumNumber", maximum_number),
+];
+
+fn qnan() -> MaybeFloat {
+    NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123)
+        .unwrap()
+        .into()
+}
+
+fn snan() -> MaybeFloat {
+    NanBstr::from_binary32_bits(0xFF80_0042).unwrap().into()
+}
+
+fn number(n: f64) -> MaybeFloat {
+    MaybeFloat::Number(n)
+}
+
+fn bits(value: &MaybeFloat) -> Vec<u8> {
+    match value {
+        MaybeFloat::Number(n) => n.to_be_bytes().to_vec(),
+        MaybeFloat::Nan(nan) => nan.as_bytes().to_vec(),
+    }
+}
+
+#[test]
+fn numbers() {
+    let expected = [1.0, 2.0, 1.0, 2.0];
+    for ((name, op), want) in OPS.iter().zip(expected) {
+        assert_eq!(op(number(1.0), number(2.0)), number(want), "{}", name);
+        assert_eq!(op(number(2.0), number(1.0)), number(want), "{}", name);
+    }
+}
+
+#[test]
+fn signed_zeros_are_ordered() {
+    let expected = [-0.0, 0.0, -0.0, 0.0];
+    for ((name, op), want) in OPS.iter().zip(expected) {
+        for (a, b) in [(0.0, -0.0), (-0.0, 0.0)] {
+            let result = op(number(a), number(b));
+            assert_eq!(bits(&result), bits(&number(want)), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn quiet_nan_versus_number() {
+    for (a, b) in [(qnan(), number(1.0)), (number(1.0), qnan())] {
+        assert_eq!(bits(&minimum(a.clone(), b.clone())), bits(&qnan()));
+        assert_eq!(bits(&maximum(a.clone(), b.clone())), bits(&qnan()));
+        assert_eq!(minimum_number(a.clone(), b.clone()), number(1.0));
+        assert_eq!(maximum_number(a, b), number(1.0));
+    }
+}
+
+#[test]
+fn signaling_nan_versus_number() {
+    let quieted = NanBstr::from_binary32_bits(0xFFC0_0042).unwrap();
+    for (a, b) in [(snan(), number(1.0)), (number(1.0), snan())] {
+        assert!(a.is_signaling() || b.is_signaling());
+        for op in [minimum, maximum] {
+            let result = op(a.clone(), b.clone());
+            assert!(!result.is_signaling());
+            assert_eq!(result.as_nan(), Some(&quieted));
+        }
+        assert_eq!(minimum_number(a.clone(), b.clone()), number(1.0));
+        assert_eq!(maximum_number(a, b), number(1.0));
+    }
+}
+
+#[test]
+fn nan_versus_nan_propagates_the_first() {
+    let quieted = NanBstr::from_binary32_bits(0xFFC0_0042).unwrap();
+    for (name, op) in OPS {
+        let result = op(snan(), qnan());
+        assert_eq!(result.as_nan(), Some(&quieted), "{}", name);
+        let result = op(qnan(), snan());
+        assert_eq!(bits(&result), bits(&qnan()), "{}", name);
+    }
+}
+
+#[test]
+fn infinities_are_numbers() {
+    assert_eq!(
+        minimum_number(qnan(), number(f64::NEG_INFINITY)),
+        number(f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        minimum(number(f64::NEG_INFINITY), number(-1.0)),
+        number(f64::NEG_INFINITY)
+    );
+    assert_eq!(
+        maximum(number(f64::INFINITY), number(1.0)),
+        number(f64::INFINITY)
+    );
+}
+
+#[test]
+fn f64_nans_convert_exactly() {
+    let value = MaybeFloat::from(f64::from_bits(0x7FF0_0000_0000_0001));
+    assert!(value.is_signaling());
+    assert_eq!(value.as_number(), None);
+    assert_eq!(MaybeFloat::from(1.5).as_number(), Some(1.5));
+}
@@ -0,0 +1,56 @@
+use cbor_nan_bstr::{Error, NanBstr};
+use dcbor::prelude::*;
+
+#[test]
+fn vec_is_moved_not_copied() {
+    let bytes = vec![0x7F, 0xF8, 0, 0, 0, 0, 0x01, 0x23];
+    let ptr = bytes.as_ptr();
+    let nan = NanBstr::try_from(bytes).unwrap();
+    assert_eq!(nan.as_bytes().as_ptr(), ptr);
+    assert_eq!(
+        nan,
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap()
+    );
+}
+
+#[test]
+fn byte_string_is_moved_not_copied() {
+    let bytes = ByteString::from(vec![0x7E, 0x00]);
+    let ptr = bytes.data().as_ptr();
+    let nan = NanBstr::try_from(bytes).unwrap();
+    assert_eq!(nan.as_bytes().as_ptr(), ptr);
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
+
+#[test]
+fn owned_conversions_validate() {
+    assert!(matches!(
+        NanBstr::try_from(vec![0x7C, 0x00]),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::try_from(vec![0x7E, 0x00, 0x00]),
+        Err(Error::InvalidLength(3))
+    ));
+    assert!(matches!(
+        NanBstr::try_from(ByteString::from(vec![0x3F, 0x80, 0x00, 0x00])),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::try_from(ByteString::from(Vec::new())),
+        Err(Error::InvalidLength(0))
+    ));
+}
+
+#[test]
+fn decoding_keeps_the_bytes() {
+    let nan = NanBstr::from_binary128_bits(u128::MAX).unwrap();
+    let decoded = NanBstr::try_from(CBOR::from(nan.clone())).unwrap();
+    assert_eq!(decoded.as_bytes(), nan.as_bytes());
+    assert!(
+        NanBstr::from_untagged_cbor(CBOR::from(ByteString::from(vec![
+            0x7C, 0x00
+        ])))
+        .is_err()
+    );
+}
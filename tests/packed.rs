@@ -0,0 +1,72 @@
+use cbor_nan_bstr::{Error, NanBstr, PackedNanBstr};
+
+#[test]
+fn packs_and_unpacks_every_width() {
+    let nans = [
+        NanBstr::from_binary16_bits(0x7E01).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap(),
+        NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 1).unwrap(),
+    ];
+    for nan in nans {
+        let packed = PackedNanBstr::from(&nan);
+        assert_eq!(packed.width as usize, nan.as_bytes().len());
+        let padding = 16 - nan.as_bytes().len();
+        assert!(packed.bytes[..padding].iter().all(|&b| b == 0));
+        assert_eq!(packed.bytes, nan.to_bits_u128().to_be_bytes());
+        assert_eq!(packed.width, nan.to_fixed_record()[0]);
+        assert_eq!(packed.bytes, nan.to_fixed_record()[1..]);
+        assert_eq!(NanBstr::try_from(packed).unwrap(), nan);
+    }
+}
+
+#[test]
+fn rejects_corrupt_width_and_non_nans() {
+    let mut packed =
+        PackedNanBstr::from(&NanBstr::from_binary32_bits(0x7FC0_0000).unwrap());
+    packed.width = 3;
+    assert!(matches!(
+        NanBstr::try_from(packed),
+        Err(Error::InvalidLength(3))
+    ));
+    packed.width = 2;
+    packed.bytes[14..].copy_from_slice(&[0x7C, 0x00]);
+    assert!(matches!(NanBstr::try_from(packed), Err(Error::NotANan)));
+}
+
+#[test]
+fn layout_has_no_padding() {
+    assert_eq!(size_of::<PackedNanBstr>(), 17);
+    assert_eq!(align_of::<PackedNanBstr>(), 1);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn casts_byte_buffer_to_records() {
+    let mut buffer = vec![0u8; 17 * 3];
+    buffer[0] = 2;
+    buffer[15..17].copy_from_slice(&[0x7E, 0x00]);
+    buffer[17] = 8;
+    buffer[26..34].copy_from_slice(&0xFFF8_0000_0000_0001u64.to_be_bytes());
+    buffer[34] = 5;
+
+    let records: &[PackedNanBstr] = bytemuck::cast_slice(&buffer);
+    assert_eq!(records.len(), 3);
+    assert_eq!(
+        NanBstr::try_from(records[0]).unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+    assert_eq!(
+        NanBstr::try_from(records[1]).unwrap(),
+        NanBstr::from_binary64_bits(0xFFF8_0000_0000_0001).unwrap()
+    );
+    assert!(matches!(
+        NanBstr::try_from(records[2]),
+        Err(Error::InvalidLength(5))
+    ));
+
+    let packed = [PackedNanBstr::from(
+        &NanBstr::from_binary16_bits(0x7E00).unwrap(),
+    )];
+    assert_eq!(&bytemuck::cast_slice::<_, u8>(&packed)[..17], &buffer[..17]);
+}
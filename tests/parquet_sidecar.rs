@@ -0,0 +1,177 @@
+#![cfg(feature = "parquet")]
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use cbor_nan_bstr::{
+    Error, NanBstr,
+    parquet_sidecar::{
+        apply_sidecar, encode_sidecar, sidecar_column_name, sidecar_column_type,
+    },
+};
+use parquet::{
+    basic::{Repetition, Type as PhysicalType},
+    data_type::{ByteArray, ByteArrayType, DoubleType},
+    file::{
+        properties::WriterProperties, reader::FileReader,
+        serialized_reader::SerializedFileReader, writer::SerializedFileWriter,
+    },
+    schema::types::Type,
+};
+
+const PAYLOAD_NAN: u64 = 0x7FF8_0000_0000_0123;
+const SIGNALING_NAN: u64 = 0xFFF0_0000_0000_0001;
+
+fn column() -> Vec<f64> {
+    vec![
+        1.5,
+        f64::from_bits(PAYLOAD_NAN),
+        f64::NAN,
+        f64::from_bits(SIGNALING_NAN),
+        -0.0,
+    ]
+}
+
+/// Writes `values` and `sidecar` as a `DOUBLE` column and its sidecar,
+/// canonicalizing NaNs as some writers do.
+fn write(values: &[f64], sidecar: &[Option<Vec<u8>>]) -> Bytes {
+    let schema = Type::group_type_builder("schema")
+        .with_fields(vec![
+            Arc::new(
+                Type::primitive_type_builder("reading", PhysicalType::DOUBLE)
+                    .with_repetition(Repetition::REQUIRED)
+                    .build()
+                    .unwrap(),
+            ),
+            Arc::new(sidecar_column_type("reading")),
+        ])
+        .build()
+        .unwrap();
+    let canonical: Vec<f64> = values
+        .iter()
+        .map(|v| if v.is_nan() { f64::NAN } else { *v })
+        .collect();
+    let present: Vec<ByteArray> = sidecar
+        .iter()
+        .flatten()
+        .map(|data| ByteArray::from(data.clone()))
+        .collect();
+    let def_levels: Vec<i16> =
+        sidecar.iter().map(|e| e.is_some() as i16).collect();
+
+    let mut out = Vec::new();
+    let mut writer = SerializedFileWriter::new(
+        &mut out,
+        Arc::new(schema),
+        Arc::new(WriterProperties::builder().build()),
+    )
+    .unwrap();
+    let mut row_group = writer.next_row_group().unwrap();
+    let mut col = row_group.next_column().unwrap().unwrap();
+    col.typed::<DoubleType>()
+        .write_batch(&canonical, None, None)
+        .unwrap();
+    col.close().unwrap();
+    let mut col = row_group.next_column().unwrap().unwrap();
+    col.typed::<ByteArrayType>()
+        .write_batch(&present, Some(&def_levels), None)
+        .unwrap();
+    col.close().unwrap();
+    row_group.close().unwrap();
+    writer.close().unwrap();
+    Bytes::from(out)
+}
+
+/// Reads back the `DOUBLE` column and its sidecar.
+fn read(data: Bytes) -> (Vec<f64>, Vec<Option<Vec<u8>>>) {
+    let reader = SerializedFileReader::new(data).unwrap();
+    let fields = reader.metadata().file_metadata().schema().get_fields();
+    assert_eq!(fields[1].name(), sidecar_column_name("reading"));
+
+    let row_group = reader.get_row_group(0).unwrap();
+    let rows = row_group.metadata().num_rows() as usize;
+    let mut values = Vec::new();
+    let mut col = row_group.get_column_reader(0).unwrap();
+    match &mut col {
+        parquet::column::reader::ColumnReader::DoubleColumnReader(r) => {
+            r.read_records(rows, None, None, &mut values).unwrap();
+        }
+        _ => panic!("expected a DOUBLE column"),
+    }
+
+    let mut present = Vec::new();
+    let mut def_levels = Vec::new();
+    let mut col = row_group.get_column_reader(1).unwrap();
+    match &mut col {
+        parquet::column::reader::ColumnReader::ByteArrayColumnReader(r) => {
+            r.read_records(rows, Some(&mut def_levels), None, &mut present)
+                .unwrap();
+        }
+        _ => panic!("expected a BYTE_ARRAY column"),
+    }
+    let mut present = present.into_iter();
+    let sidecar = def_levels
+        .iter()
+        .map(|&level| {
+            (level == 1).then(|| present.next().unwrap().data().to_vec())
+        })
+        .collect();
+    (values, sidecar)
+}
+
+#[test]
+fn sidecar_records_only_noncanonical_nans() {
+    let sidecar = encode_sidecar(&column());
+    let expected: Vec<Option<Vec<u8>>> = vec![
+        None,
+        Some(NanBstr::from_f64_bits(PAYLOAD_NAN).unwrap().to_cbor_data()),
+        None,
+        Some(
+            NanBstr::from_f64_bits(SIGNALING_NAN)
+                .unwrap()
+                .to_cbor_data(),
+        ),
+        None,
+    ];
+    assert_eq!(sidecar, expected);
+}
+
+#[test]
+fn round_trips_through_parquet() {
+    let values = column();
+    let (mut read_values, sidecar) =
+        read(write(&values, &encode_sidecar(&values)));
+    assert_eq!(read_values[1].to_bits(), f64::NAN.to_bits());
+
+    apply_sidecar(&mut read_values, &sidecar).unwrap();
+    let bits = |v: &[f64]| v.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+    assert_eq!(bits(&read_values), bits(&values));
+}
+
+#[test]
+fn sidecar_must_match_the_column() {
+    let mut values = column();
+    let sidecar = encode_sidecar(&values);
+    assert!(matches!(
+        apply_sidecar(&mut values[1..], &sidecar),
+        Err(Error::SidecarLength {
+            values: 4,
+            sidecar: 5
+        })
+    ));
+
+    values[1] = 2.0;
+    assert!(matches!(
+        apply_sidecar(&mut values, &sidecar),
+        Err(Error::SidecarMismatch(1))
+    ));
+    assert_eq!(values[3].to_bits(), SIGNALING_NAN, "unchanged on failure");
+}
+
+#[test]
+fn narrower_entries_widen() {
+    let mut values = vec![f64::NAN];
+    let nan = NanBstr::from_binary16_bits(0x7E01).unwrap();
+    apply_sidecar(&mut values, &[Some(nan.to_cbor_data())]).unwrap();
+    assert_eq!(values[0].to_bits(), 0x7FF8_0000_0000_0001);
+}
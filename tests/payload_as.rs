@@ -0,0 +1,60 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+fn quiet(width: NanWidth, payload: u128) -> NanBstr {
+    NanBstr::from_payload(width, payload).unwrap()
+}
+
+#[test]
+fn narrow_widths_never_fail() {
+    for width in [NanWidth::Binary16, NanWidth::Binary32, NanWidth::Binary64] {
+        let max = width.max_payload();
+        for payload in [0, 1, max >> 1, max] {
+            let nan = quiet(width, payload);
+            assert_eq!(nan.payload_as_u64().unwrap() as u128, payload);
+        }
+    }
+    for width in [NanWidth::Binary16, NanWidth::Binary32] {
+        let nan = quiet(width, width.max_payload());
+        assert_eq!(nan.payload_as_u32().unwrap() as u128, width.max_payload());
+    }
+    let nan = quiet(NanWidth::Binary16, 0x1FF);
+    assert_eq!(nan.payload_as_u16().unwrap(), 0x1FF);
+}
+
+#[test]
+fn values_at_the_type_limit_fit() {
+    let nan = quiet(NanWidth::Binary128, u64::MAX as u128);
+    assert_eq!(nan.payload_as_u64().unwrap(), u64::MAX);
+    let nan = quiet(NanWidth::Binary64, u32::MAX as u128);
+    assert_eq!(nan.payload_as_u32().unwrap(), u32::MAX);
+    let nan = quiet(NanWidth::Binary32, u16::MAX as u128);
+    assert_eq!(nan.payload_as_u16().unwrap(), u16::MAX);
+    let nan = quiet(NanWidth::Binary128, 0x2A);
+    assert_eq!(nan.payload_as_u16().unwrap(), 0x2A);
+}
+
+#[test]
+fn overflow_reports_the_payload() {
+    let cases = [
+        (NanWidth::Binary128, 1u128 << 64, 64),
+        (NanWidth::Binary128, NanWidth::Binary128.max_payload(), 64),
+        (NanWidth::Binary64, 1 << 32, 32),
+        (NanWidth::Binary32, 1 << 16, 16),
+    ];
+    for (width, payload, bits) in cases {
+        let nan = quiet(width, payload);
+        let err = match bits {
+            64 => nan.payload_as_u64().unwrap_err(),
+            32 => nan.payload_as_u32().unwrap_err(),
+            _ => nan.payload_as_u16().unwrap_err(),
+        };
+        assert!(matches!(
+            err,
+            Error::PayloadOverflow { payload: p, bits: b } if p == payload && b == bits
+        ));
+        assert_eq!(
+            err.to_string(),
+            format!("payload 0x{payload:x} does not fit in u{bits}")
+        );
+    }
+}
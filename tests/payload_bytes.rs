@@ -0,0 +1,97 @@
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+fn round_trip(nan: &NanBstr) -> NanBstr {
+    let mut builder =
+        NanBstr::builder(nan.width()).payload_bytes(&nan.payload_bytes());
+    if nan.sign() {
+        builder = builder.negative();
+    }
+    if nan.is_signaling() {
+        builder = builder.signaling();
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn leading_zero_bytes_are_stripped() {
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123).unwrap();
+    assert_eq!(nan.payload_bytes(), [0x01, 0x23]);
+    let nan = NanBstr::from_binary32_bits(0x7FC0_00FF).unwrap();
+    assert_eq!(nan.payload_bytes(), [0xFF]);
+    let nan = NanBstr::from_binary16_bits(0x7D00).unwrap();
+    assert_eq!(nan.payload_bytes(), [0x01, 0x00]);
+}
+
+#[test]
+fn zero_payload_is_empty() {
+    for width in NanWidth::ALL {
+        let nan = NanBstr::canonical_quiet(width);
+        assert!(nan.payload_bytes().is_empty());
+        assert_eq!(nan.payload_bytes_padded(), vec![0; width.payload_len()]);
+    }
+}
+
+#[test]
+fn padded_lengths() {
+    for (width, len) in [
+        (NanWidth::Binary16, 2),
+        (NanWidth::Binary32, 3),
+        (NanWidth::Binary64, 7),
+        (NanWidth::Binary128, 14),
+    ] {
+        assert_eq!(width.payload_len(), len);
+        let nan = NanBstr::from_payload(width, 1).unwrap();
+        let mut expected = vec![0; len];
+        expected[len - 1] = 1;
+        assert_eq!(nan.payload_bytes_padded(), expected);
+        assert_eq!(nan.payload_bytes(), [1]);
+    }
+}
+
+#[test]
+fn binary128_payload_is_not_byte_aligned() {
+    // 111 bits: the top byte carries only 7 of them.
+    let max = NanWidth::Binary128.max_payload();
+    let nan = NanBstr::from_payload(NanWidth::Binary128, max).unwrap();
+    let mut expected = vec![0xFF; 14];
+    expected[0] = 0x7F;
+    assert_eq!(nan.payload_bytes(), expected);
+    assert_eq!(nan.payload_bytes_padded(), expected);
+
+    let top = 1u128 << 110;
+    let nan =
+        NanBstr::from_payload_signaling(NanWidth::Binary128, top).unwrap();
+    let mut expected = vec![0; 14];
+    expected[0] = 0x40;
+    assert_eq!(nan.payload_bytes(), expected);
+    assert_eq!(nan.payload_bytes_padded(), expected);
+    assert!(nan.is_signaling());
+
+    // The quiet bit sits just above the payload and never leaks into it.
+    let nan =
+        NanBstr::from_binary128_bits(0xFFFF_C000_0000_0000_0000_0000_0000_0001)
+            .unwrap();
+    assert_eq!(nan.payload_bytes()[0], 0x40);
+    assert_eq!(nan.payload_bytes().len(), 14);
+}
+
+#[test]
+fn round_trips_through_the_builder_and_payload_bits() {
+    for width in NanWidth::ALL {
+        let max = width.max_payload();
+        for payload in [1, 0x80, 0xFF, 0x100, max >> 7, max >> 1, max] {
+            for (sign, quiet) in [(false, true), (true, false)] {
+                let nan =
+                    NanBstr::from_parts(width, sign, quiet, payload).unwrap();
+                assert_eq!(round_trip(&nan), nan);
+                let bytes = nan.payload_bytes_padded();
+                let bits =
+                    bytes.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128);
+                assert_eq!(bits, nan.payload_bits());
+                let stripped = nan.payload_bytes();
+                assert!(bytes.ends_with(&stripped));
+                assert!(stripped.first().is_some_and(|&b| b != 0));
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use cbor_nan_bstr::{Error, NanBstr, NanWidth, PayloadField, PayloadLayout};
+
+fn layout() -> PayloadLayout {
+    PayloadLayout::new(vec![
+        PayloadField::new("sensor", 0, 12),
+        PayloadField::new("fault", 12, 4),
+        PayloadField::new("site", 16, 6),
+    ])
+    .unwrap()
+}
+
+fn values(pairs: &[(&str, u64)]) -> BTreeMap<String, u64> {
+    pairs.iter().map(|&(k, v)| (k.to_string(), v)).collect()
+}
+
+#[test]
+fn extract_and_compose_round_trip() {
+    let fields = values(&[("sensor", 0xABC), ("fault", 0x5), ("site", 0x2A)]);
+    let nan = layout()
+        .compose(&fields, NanWidth::Binary32, true, true)
+        .unwrap();
+    assert_eq!(u32::try_from(nan.clone()).unwrap(), 0xFFEA_5ABC);
+    assert_eq!(layout().extract(&nan).unwrap(), fields);
+
+    let nan = NanBstr::from_binary64_bits(0x7FF0_0000_0001_3001).unwrap();
+    let fields = layout().extract(&nan).unwrap();
+    assert_eq!(fields, values(&[("sensor", 1), ("fault", 3), ("site", 1)]));
+    let composed = layout()
+        .compose(&fields, NanWidth::Binary64, false, false)
+        .unwrap();
+    assert_eq!(composed, nan);
+}
+
+#[test]
+fn missing_values_are_zero() {
+    let nan = layout()
+        .compose(&values(&[("fault", 1)]), NanWidth::Binary32, false, true)
+        .unwrap();
+    assert_eq!(nan.payload_bits(), 0x1000);
+    assert_eq!(
+        layout().extract(&nan).unwrap(),
+        values(&[("sensor", 0), ("fault", 1), ("site", 0)])
+    );
+}
+
+#[test]
+fn overlapping_fields_are_rejected() {
+    let err = PayloadLayout::new(vec![
+        PayloadField::new("a", 0, 8),
+        PayloadField::new("b", 7, 2),
+    ])
+    .unwrap_err();
+    assert!(matches!(
+        &err,
+        Error::InvalidLayout { field, reason }
+            if field == "b" && reason == "overlaps `a`"
+    ));
+
+    assert!(matches!(
+        PayloadLayout::new(vec![
+            PayloadField::new("a", 0, 8),
+            PayloadField::new("a", 8, 8),
+        ]),
+        Err(Error::InvalidLayout { .. })
+    ));
+    for bits in [0, 65] {
+        assert!(matches!(
+            PayloadLayout::new(vec![PayloadField::new("a", 0, bits)]),
+            Err(Error::InvalidLayout { .. })
+        ));
+    }
+}
+
+#[test]
+fn fields_exceeding_the_width_are_rejected() {
+    let nan = NanBstr::from_binary16_bits(0x7E01).unwrap();
+    assert!(matches!(
+        layout().extract(&nan),
+        Err(Error::InvalidLayout { field, .. }) if field == "sensor"
+    ));
+    assert!(matches!(
+        layout().compose(&BTreeMap::new(), NanWidth::Binary16, false, true),
+        Err(Error::InvalidLayout { .. })
+    ));
+    assert!(layout().validate_for(NanWidth::Binary32).is_ok());
+    assert!(matches!(
+        PayloadLayout::new(vec![PayloadField::new("a", 100, 12)]),
+        Err(Error::InvalidLayout { .. })
+    ));
+}
+
+#[test]
+fn bad_values_are_rejected() {
+    assert!(matches!(
+        layout().compose(&values(&[("fault", 16)]), NanWidth::Binary32, false, true),
+        Err(Error::InvalidField { field, .. }) if field == "fault"
+    ));
+    assert!(matches!(
+        layout().compose(&values(&[("node", 1)]), NanWidth::Binary32, false, true),
+        Err(Error::InvalidField { field, .. }) if field == "node"
+    ));
+    assert!(matches!(
+        layout().compose(&BTreeMap::new(), NanWidth::Binary32, false, false),
+        Err(Error::NotANan)
+    ));
+}
+
+#[test]
+fn unassigned_payload_bits_are_rejected() {
+    let layout =
+        PayloadLayout::new(vec![PayloadField::new("low", 0, 16)]).unwrap();
+    let nan = NanBstr::from_binary32_bits(0x7FE0_0001).unwrap();
+    assert!(matches!(
+        layout.extract(&nan),
+        Err(Error::UnassignedPayloadBits(0x20_0000))
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn layout_loads_from_config() {
+    let json = r#"[
+        {"name": "sensor", "lsb": 0, "bits": 12},
+        {"name": "fault", "lsb": 12, "bits": 4},
+        {"name": "site", "lsb": 16, "bits": 6}
+    ]"#;
+    let loaded: PayloadLayout = serde_json::from_str(json).unwrap();
+    assert_eq!(loaded, layout());
+    let saved = serde_json::to_string(&loaded).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PayloadLayout>(&saved).unwrap(),
+        loaded
+    );
+
+    let overlapping = r#"[
+        {"name": "a", "lsb": 0, "bits": 8},
+        {"name": "b", "lsb": 4, "bits": 8}
+    ]"#;
+    let err = serde_json::from_str::<PayloadLayout>(overlapping).unwrap_err();
+    assert!(err.to_string().contains("overlaps `a`"));
+}
@@ -0,0 +1,103 @@
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+fn f32_nan(bits: u32) -> NanBstr {
+    NanBstr::from_binary32_bits(bits).unwrap()
+}
+
+fn f64_nan(bits: u64) -> NanBstr {
+    NanBstr::from_binary64_bits(bits).unwrap()
+}
+
+#[test]
+fn top_bits_of_a_binary16_payload() {
+    // Payload 0b0_0000_0101.
+    let nan = NanBstr::from_binary16_bits(0x7E05).unwrap();
+    assert_eq!(nan.payload_top_bits(0), 0);
+    assert_eq!(nan.payload_top_bits(3), 0);
+    assert_eq!(nan.payload_top_bits(7), 0b1);
+    assert_eq!(nan.payload_top_bits(8), 0b10);
+    assert_eq!(nan.payload_top_bits(9), 0b101);
+}
+
+#[test]
+fn top_bits_saturate_at_the_payload_size() {
+    let nan = NanBstr::from_binary16_bits(0x7E05).unwrap();
+    assert_eq!(nan.payload_top_bits(10), 5);
+    assert_eq!(nan.payload_top_bits(u32::MAX), 5);
+    let quad =
+        NanBstr::from_payload(NanWidth::Binary128, 1 << 110 | 1).unwrap();
+    assert_eq!(quad.payload_top_bits(1), 1);
+    assert_eq!(quad.payload_top_bits(2), 0b10);
+    assert_eq!(quad.payload_top_bits(200), 1 << 110 | 1);
+}
+
+#[test]
+fn top_bits_of_wider_payloads() {
+    // 51-bit payload 0x2000_0000: bit 29, so the top 22 bits are 1.
+    let double = f64_nan(0x7FF8_0000_2000_0000);
+    assert_eq!(double.payload_top_bits(22), 1);
+    assert_eq!(double.payload_top_bits(21), 0);
+    assert_eq!(double.payload_top_bits(23), 0b10);
+    let max = NanBstr::from_payload(
+        NanWidth::Binary64,
+        NanWidth::Binary64.max_payload(),
+    )
+    .unwrap();
+    assert_eq!(max.payload_top_bits(22), 0x3F_FFFF);
+}
+
+#[test]
+fn narrowed_binary64_matches_its_binary32() {
+    // Narrowing 0x7FF8_0000_2000_0000 keeps the top 23 fraction bits:
+    // 0x40_0001, giving 0x7FC0_0001.
+    let double = f64_nan(0x7FF8_0000_2000_0000);
+    let single = f32_nan(0x7FC0_0001);
+    assert!(double.payload_prefix_matches(&single));
+    assert!(single.payload_prefix_matches(&double));
+
+    // Low bits that narrowing drops do not matter.
+    let double = f64_nan(0x7FF8_0000_3FFF_FFFF);
+    assert!(double.payload_prefix_matches(&single));
+    assert!(single.payload_prefix_matches(&double));
+}
+
+#[test]
+fn different_prefixes_do_not_match() {
+    // Same integer payload, different alignment.
+    let double = f64_nan(0x7FF8_0000_0000_0001);
+    let single = f32_nan(0x7FC0_0001);
+    assert!(!double.payload_prefix_matches(&single));
+    assert!(!single.payload_prefix_matches(&double));
+    // Its prefix is zero, as in the canonical quiet NaN.
+    let canonical = f32_nan(0x7FC0_0000);
+    assert!(double.payload_prefix_matches(&canonical));
+    assert!(canonical.payload_prefix_matches(&double));
+}
+
+#[test]
+fn sign_and_quiet_bit_are_ignored() {
+    let double = f64_nan(0xFFF0_0000_2000_0000);
+    let single = f32_nan(0x7FC0_0001);
+    assert!(double.payload_prefix_matches(&single));
+    assert!(single.payload_prefix_matches(&double));
+}
+
+#[test]
+fn binary16_against_binary128() {
+    // The top payload bit of each: 0x7F00 and bit 110 of the quad.
+    let half = NanBstr::from_binary16_bits(0x7F00).unwrap();
+    let quad =
+        NanBstr::from_payload(NanWidth::Binary128, 1 << 110 | 0xFFFF).unwrap();
+    assert!(half.payload_prefix_matches(&quad));
+    assert!(quad.payload_prefix_matches(&half));
+    let half = NanBstr::from_binary16_bits(0x7E80).unwrap();
+    assert!(!half.payload_prefix_matches(&quad));
+    assert!(!quad.payload_prefix_matches(&half));
+}
+
+#[test]
+fn same_width_compares_whole_payloads() {
+    let a = f32_nan(0x7FC0_0001);
+    assert!(a.payload_prefix_matches(&f32_nan(0x7F80_0001)));
+    assert!(!a.payload_prefix_matches(&f32_nan(0x7FC0_0002)));
+}
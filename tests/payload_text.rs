@@ -0,0 +1,57 @@
+use cbor_nan_bstr::{NanBstr, NanWidth};
+
+fn tagged(width: NanWidth, tag: &[u8]) -> NanBstr {
+    NanBstr::builder(width).payload_bytes(tag).build().unwrap()
+}
+
+#[test]
+fn binary64_carries_na() {
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_4E41).unwrap();
+    assert_eq!(nan.payload_ascii().as_deref(), Some("NA"));
+    assert_eq!(nan.payload_utf8().as_deref(), Some("NA"));
+    assert_eq!(
+        tagged(NanWidth::Binary64, b"ERR"),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0045_5252).unwrap()
+    );
+}
+
+#[test]
+fn binary128_carries_a_longer_tag() {
+    let nan = tagged(NanWidth::Binary128, b"SENSOR-FAULT");
+    assert_eq!(nan.payload_ascii().as_deref(), Some("SENSOR-FAULT"));
+    assert_eq!(nan.payload_utf8().as_deref(), Some("SENSOR-FAULT"));
+    // All 14 payload bytes hold ASCII, which leaves the top bit clear.
+    let nan = tagged(NanWidth::Binary128, b"missing value!");
+    assert_eq!(nan.payload_ascii().as_deref(), Some("missing value!"));
+}
+
+#[test]
+fn utf8_is_lossless() {
+    let nan = tagged(NanWidth::Binary128, "µs".as_bytes());
+    assert_eq!(nan.payload_ascii(), None);
+    assert_eq!(nan.payload_utf8().as_deref(), Some("µs"));
+}
+
+#[test]
+fn garbage_payloads_are_none() {
+    // 0xFF 0xFE is neither ASCII nor UTF-8.
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_0000_FFFE).unwrap();
+    assert_eq!(nan.payload_ascii(), None);
+    assert_eq!(nan.payload_utf8(), None);
+    // A control character is not printable, though it is UTF-8.
+    let nan = NanBstr::from_binary32_bits(0x7FC0_4107).unwrap();
+    assert_eq!(nan.payload_ascii(), None);
+    assert_eq!(nan.payload_utf8().as_deref(), Some("A\u{7}"));
+    // A zero byte inside the payload is not stripped.
+    let nan = NanBstr::from_binary64_bits(0x7FF8_0000_4100_4200).unwrap();
+    assert_eq!(nan.payload_ascii(), None);
+}
+
+#[test]
+fn zero_payload_is_none() {
+    for width in NanWidth::ALL {
+        let nan = NanBstr::canonical_quiet(width);
+        assert_eq!(nan.payload_ascii(), None);
+        assert_eq!(nan.payload_utf8(), None);
+    }
+}
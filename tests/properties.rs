@@ -0,0 +1,174 @@
+#![cfg(feature = "proptest")]
+
+use cbor_nan_bstr::{
+    Error, NanBstr, NanClass, NanPattern, NanWidth, TruncationPolicy,
+    strategies::*,
+};
+use dcbor::prelude::*;
+use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+
+proptest! {
+    #[test]
+    fn cbor_round_trip(nan in any_nan()) {
+        let decoded = NanBstr::try_from(CBOR::from(nan.clone())).unwrap();
+        prop_assert_eq!(decoded, nan);
+    }
+
+    #[test]
+    fn bytes_round_trip(nan in any_nan()) {
+        prop_assert_eq!(NanBstr::from_be_bytes(nan.as_bytes()).unwrap(), nan);
+    }
+
+    #[test]
+    fn display_round_trip(nan in any_nan()) {
+        prop_assert_eq!(nan.to_string().parse::<NanBstr>().unwrap(), nan);
+    }
+
+    #[test]
+    fn widen_then_narrow_is_identity(
+        nan in any_nan(),
+        target in any_width(),
+    ) {
+        prop_assume!(target.len() >= nan.width().len());
+        let wide = nan.widen_to(target).unwrap();
+        prop_assert_eq!(wide.sign(), nan.sign());
+        prop_assert_eq!(wide.is_quiet(), nan.is_quiet());
+        prop_assert_eq!(wide.narrow_to(nan.width()).unwrap(), nan);
+    }
+
+    #[test]
+    fn reject_keeps_fields_or_fails(
+        nan in any_nan(),
+        target in any_width(),
+    ) {
+        let result = nan.convert_width(target, TruncationPolicy::Reject);
+        if target.fits_payload(nan.payload_bits()) {
+            let converted = result.unwrap();
+            prop_assert_eq!(converted.width(), target);
+            prop_assert_eq!(converted.sign(), nan.sign());
+            prop_assert_eq!(converted.is_quiet(), nan.is_quiet());
+            prop_assert_eq!(converted.payload_bits(), nan.payload_bits());
+        } else {
+            let rejected = matches!(
+                result,
+                Err(Error::PayloadTooLarge { width, payload })
+                    if width == target && payload == nan.payload_bits()
+            );
+            prop_assert!(rejected, "{:?}", result);
+        }
+    }
+
+    #[test]
+    fn truncate_keeps_low_payload_bits(
+        nan in nan_of_width(NanWidth::Binary128),
+        target in any_width(),
+    ) {
+        let converted =
+            nan.convert_width(target, TruncationPolicy::Truncate).unwrap();
+        let kept = nan.payload_bits() & target.max_payload();
+        prop_assert_eq!(converted.width(), target);
+        prop_assert_eq!(converted.sign(), nan.sign());
+        prop_assert_eq!(converted.payload_bits(), kept);
+        // Only a signaling NaN with no surviving payload bit is quieted.
+        prop_assert_eq!(converted.is_quiet(), nan.is_quiet() || kept == 0);
+    }
+
+    #[test]
+    fn f64_checked_round_trip(nan in nan_of_width(NanWidth::Binary32)) {
+        let f = nan.to_f64_checked().unwrap();
+        let back = NanBstr::from_f64_bits(f.to_bits())
+            .and_then(|n| n.narrow_to(nan.width()))
+            .unwrap();
+        prop_assert_eq!(back, nan);
+    }
+
+    #[test]
+    fn from_parts_round_trips(
+        width in any_width(),
+        sign in any::<bool>(),
+        quiet in any::<bool>(),
+        payload in any::<u128>(),
+    ) {
+        let payload = payload & width.max_payload();
+        let result = NanBstr::from_parts(width, sign, quiet, payload);
+        if !quiet && payload == 0 {
+            prop_assert!(matches!(result, Err(Error::NotANan)));
+        } else {
+            let nan = result.unwrap();
+            prop_assert_eq!(nan.width(), width);
+            prop_assert_eq!(nan.sign(), sign);
+            prop_assert_eq!(nan.is_quiet(), quiet);
+            prop_assert_eq!(nan.payload_bits(), payload);
+        }
+    }
+
+    #[test]
+    fn from_parts_rejects_wide_payloads(
+        width in any_width(),
+        excess in 1u128..=1 << 16,
+    ) {
+        prop_assume!(width != NanWidth::Binary128 || excess == 1);
+        let payload = width.max_payload() + excess;
+        let result = NanBstr::from_parts(width, false, true, payload);
+        let too_large = matches!(result, Err(Error::PayloadTooLarge { .. }));
+        prop_assert!(too_large);
+    }
+
+    #[test]
+    fn quiet_and_signaling_strategies(q in quiet_nan(), s in signaling_nan()) {
+        prop_assert!(q.is_quiet());
+        prop_assert!(s.is_signaling());
+    }
+
+    #[test]
+    fn matching_strategy_matches(
+        nan in nan_matching(&NanPattern::any().negative().signaling()),
+    ) {
+        prop_assert!(nan.sign() && nan.is_signaling());
+    }
+
+    #[test]
+    fn exponent_is_all_ones(nan in any_nan()) {
+        let width = nan.exponent_field_width();
+        prop_assert_eq!(width, nan.width().exponent_bits());
+        prop_assert_eq!(nan.exponent_bits(), (1 << width) - 1);
+    }
+
+    #[test]
+    fn binary128_words_round_trip(nan in nan_of_width(NanWidth::Binary128)) {
+        let (high, low) = nan.to_binary128_words().unwrap();
+        let back = NanBstr::from_binary128_words(high, low).unwrap();
+        prop_assert_eq!(back.to_binary128_words(), Some((high, low)));
+        prop_assert_eq!(back, nan);
+    }
+
+    #[test]
+    fn encoded_cbor_size_matches_the_encoder(nan in any_nan()) {
+        let size = nan.encoded_cbor_size();
+        prop_assert_eq!(size, nan.to_cbor_data().len());
+        prop_assert_eq!(size, CBOR::from(nan.clone()).to_cbor_data().len());
+        prop_assert_eq!(size, nan.encoded_len());
+    }
+
+    #[test]
+    fn classify_agrees_with_predicates(nan in any_nan()) {
+        let class = nan.classify();
+        prop_assert_eq!(class == NanClass::Signaling, nan.is_signaling());
+        prop_assert_eq!(
+            class == NanClass::QuietZeroPayload,
+            nan.is_quiet() && nan.payload_bits() == 0
+        );
+        prop_assert_eq!(
+            class == NanClass::QuietWithPayload,
+            nan.is_quiet() && nan.payload_bits() != 0
+        );
+    }
+}
+
+#[test]
+fn shrinks_to_canonical_quiet_binary16() {
+    let mut runner = TestRunner::deterministic();
+    let mut tree = any_nan().new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
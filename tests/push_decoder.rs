@@ -0,0 +1,149 @@
+use cbor_nan_bstr::{FrameError, NanBstr, NanBstrDecoder};
+use hex_literal::hex;
+
+mod common;
+use common::nans;
+
+fn stream() -> Vec<u8> {
+    nans().iter().flat_map(|nan| nan.to_cbor_data()).collect()
+}
+
+/// Pushes `bytes` one at a time, collecting every outcome.
+fn push_bytes(
+    decoder: &mut NanBstrDecoder,
+    bytes: &[u8],
+) -> Vec<Result<NanBstr, FrameError>> {
+    bytes
+        .iter()
+        .filter_map(|&b| decoder.push(b).map(|r| r.map(NanBstr::from)))
+        .collect()
+}
+
+/// Pushes `bytes` as a slice, collecting every outcome.
+fn push_all(
+    decoder: &mut NanBstrDecoder,
+    mut bytes: &[u8],
+) -> Vec<Result<NanBstr, FrameError>> {
+    let mut results = Vec::new();
+    while !bytes.is_empty() {
+        let (consumed, result) = decoder.push_slice(bytes);
+        results.extend(result.map(|r| r.map(NanBstr::from)));
+        bytes = &bytes[consumed..];
+    }
+    results
+}
+
+#[test]
+fn one_byte_at_a_time() {
+    let mut decoder = NanBstrDecoder::new();
+    let decoded: Vec<NanBstr> = push_bytes(&mut decoder, &stream())
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(decoded, nans());
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn split_at_every_boundary() {
+    let stream = stream();
+    for split in 0..=stream.len() {
+        let mut decoder = NanBstrDecoder::new();
+        let mut results = push_all(&mut decoder, &stream[..split]);
+        results.extend(push_all(&mut decoder, &stream[split..]));
+        let decoded: Vec<NanBstr> =
+            results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(decoded, nans(), "split at {}", split);
+    }
+}
+
+#[test]
+fn push_slice_stops_after_each_item() {
+    let mut decoder = NanBstrDecoder::new();
+    let stream = stream();
+    let (consumed, result) = decoder.push_slice(&stream);
+    assert_eq!(consumed, 5);
+    assert_eq!(result.unwrap().unwrap(), nans()[0]);
+    let (consumed, result) = decoder.push_slice(&stream[5..7]);
+    assert_eq!(consumed, 2);
+    assert!(result.is_none());
+    assert_eq!(decoder.buffered(), 2);
+}
+
+#[test]
+fn malformed_heads_error_and_reset() {
+    let mut decoder = NanBstrDecoder::new();
+    let results = push_bytes(&mut decoder, &hex!("d867 d866 427e00"));
+    assert_eq!(results[0], Err(FrameError::WrongTag(0x67)));
+    assert_eq!(results[1].as_ref().unwrap(), &nans()[0]);
+
+    let results = push_bytes(&mut decoder, &hex!("d866 f9 d866 427e00"));
+    assert_eq!(results[0], Err(FrameError::WrongType));
+    assert_eq!(results.len(), 2);
+    assert!(results[1].is_ok());
+
+    let results = push_bytes(&mut decoder, &hex!("d866 43 d866 427e00"));
+    assert_eq!(results[0], Err(FrameError::InvalidLength(3)));
+    assert!(results[1].is_ok());
+
+    let results = push_bytes(&mut decoder, &hex!("d866 42 7c00 d866 427e00"));
+    assert_eq!(results[0], Err(FrameError::NotANan));
+    assert!(results[1].is_ok());
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn resynchronizes_on_tag_head_byte() {
+    let mut decoder = NanBstrDecoder::new();
+    let results = push_bytes(&mut decoder, &hex!("d8 d866 427e00"));
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_err());
+    assert_eq!(results[1].as_ref().unwrap(), &nans()[0]);
+}
+
+#[test]
+fn reset_discards_partial_item() {
+    let mut decoder = NanBstrDecoder::new();
+    assert!(push_bytes(&mut decoder, &hex!("d866 44 7f")).is_empty());
+    assert_eq!(decoder.buffered(), 4);
+    decoder.reset();
+    assert_eq!(decoder.buffered(), 0);
+    let results = push_bytes(&mut decoder, &nans()[0].to_cbor_data());
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_ref().unwrap(), &nans()[0]);
+}
+
+#[test]
+fn completed_item_borrows_the_buffer() {
+    let mut decoder = NanBstrDecoder::new();
+    let bytes = nans()[1].to_cbor_data();
+    let (last, head) = bytes.split_last().unwrap();
+    assert!(head.iter().all(|&b| decoder.push(b).is_none()));
+    let view = decoder.push(*last).unwrap().unwrap();
+    assert_eq!(view, nans()[1]);
+    assert_eq!(view.as_bytes(), &bytes[3..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn owned_variants_copy_and_convert_errors() {
+    use cbor_nan_bstr::Error;
+
+    let mut decoder = NanBstrDecoder::new();
+    let results: Vec<_> = hex!("d867 d866 427e00")
+        .iter()
+        .filter_map(|&b| decoder.push_owned(b))
+        .collect();
+    assert!(matches!(
+        results[0],
+        Err(Error::Cbor(dcbor::Error::WrongTag(_, _)))
+    ));
+    assert_eq!(results[1].as_ref().unwrap(), &nans()[0]);
+
+    let stream = stream();
+    let (consumed, result) = decoder.push_slice_owned(&stream);
+    assert_eq!(consumed, 5);
+    assert_eq!(result.unwrap().unwrap(), nans()[0]);
+    let (_, result) = decoder.push_slice_owned(&hex!("d866 42 7c00"));
+    assert!(matches!(result, Some(Err(Error::NotANan))));
+}
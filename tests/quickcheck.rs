@@ -0,0 +1,100 @@
+#![cfg(feature = "quickcheck")]
+
+use std::collections::HashSet;
+
+use cbor_nan_bstr::{NanBstr, NanWidth};
+use dcbor::prelude::*;
+use quickcheck::{Arbitrary, Gen, quickcheck};
+
+quickcheck! {
+    fn cbor_round_trip(nan: NanBstr) -> bool {
+        NanBstr::try_from(CBOR::from(nan.clone())).unwrap() == nan
+    }
+
+    fn display_round_trip(nan: NanBstr) -> bool {
+        nan.to_string().parse::<NanBstr>().unwrap() == nan
+    }
+
+    fn widen_to_binary128_round_trips(nan: NanBstr) -> bool {
+        let wide = nan.widen_to(NanWidth::Binary128).unwrap();
+        wide.narrow_to(nan.width()).unwrap() == nan
+    }
+
+    fn shrink_candidates_are_valid_and_smaller(nan: NanBstr) -> bool {
+        nan.shrink().all(|candidate| {
+            NanBstr::from_be_bytes(candidate.as_bytes())
+                .is_ok_and(|reparsed| reparsed == candidate)
+                && rank(&candidate) < rank(&nan)
+        })
+    }
+}
+
+/// Orders NaNs by how far they are from fully shrunk: the sum of the sign,
+/// the signaling bit, the payload, and the width.
+fn rank(nan: &NanBstr) -> u128 {
+    nan.sign() as u128
+        + nan.is_signaling() as u128
+        + nan.payload_bits()
+        + nan.width().len() as u128
+}
+
+/// Shrinks `nan` as quickcheck does: repeatedly moves to the first
+/// candidate that still fails.
+fn minimize(mut nan: NanBstr, fails: impl Fn(&NanBstr) -> bool) -> NanBstr {
+    while let Some(next) = nan.shrink().find(|candidate| fails(candidate)) {
+        nan = next;
+    }
+    nan
+}
+
+#[test]
+fn arbitrary_reaches_every_width() {
+    let mut g = Gen::new(100);
+    let widths: HashSet<NanWidth> = (0..200)
+        .map(|_| NanBstr::arbitrary(&mut g).width())
+        .collect();
+    assert_eq!(widths.len(), 4);
+}
+
+#[test]
+fn shrink_steps_sign_quiet_payload_and_width_down() {
+    let nan = NanBstr::from_binary64_bits(0xFFF0_0000_0000_0010).unwrap();
+    let shrunk: Vec<NanBstr> = nan.shrink().collect();
+    assert_eq!(
+        shrunk,
+        vec![
+            NanBstr::from_binary64_bits(0x7FF0_0000_0000_0010).unwrap(),
+            NanBstr::from_binary64_bits(0xFFF8_0000_0000_0010).unwrap(),
+            NanBstr::from_binary64_bits(0xFFF0_0000_0000_0001).unwrap(),
+            NanBstr::from_binary64_bits(0xFFF0_0000_0000_0008).unwrap(),
+            NanBstr::from_binary64_bits(0xFFF0_0000_0000_000F).unwrap(),
+            NanBstr::from_binary16_bits(0xFC10).unwrap(),
+            NanBstr::from_binary32_bits(0xFF80_0010).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn shrink_minimizes_a_failing_property() {
+    // Fails for any payload of 3 or more; the minimal counterexample is
+    // positive, quiet, binary16, with payload 3.
+    let nan =
+        NanBstr::from_binary128_words(0xFFFF_0000_0000_0000, 0x1234).unwrap();
+    let minimal = minimize(nan, |nan| nan.payload_bits() >= 3);
+    assert_eq!(minimal, NanBstr::from_binary16_bits(0x7E03).unwrap());
+
+    // Fails for signaling NaNs, so the quiet bit stays clear.
+    let nan = NanBstr::from_binary32_bits(0xFFA0_0000).unwrap();
+    let minimal = minimize(nan, NanBstr::is_signaling);
+    assert_eq!(minimal, NanBstr::from_binary16_bits(0x7C01).unwrap());
+}
+
+#[test]
+fn shrink_ends_at_minimal_nan() {
+    let mut nan =
+        NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 0xFF).unwrap();
+    while let Some(next) = nan.shrink().next() {
+        nan = next;
+    }
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7E00).unwrap());
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "rand")]
+
+use std::collections::HashSet;
+
+use cbor_nan_bstr::{NanBstr, NanDistribution, NanWidth, Quietness};
+use dcbor::prelude::*;
+use rand::{SeedableRng, distr::Distribution, rngs::SmallRng};
+
+const SAMPLES: usize = 4000;
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(0x6e61_6e62)
+}
+
+/// Checks that `nan` is a valid NaN of `width` and not an infinity.
+fn check(nan: &NanBstr, width: NanWidth) {
+    assert_eq!(nan.width(), width);
+    assert_ne!(nan.fraction_bits(), 0, "{nan} is an infinity");
+    let reparsed = NanBstr::from_be_bytes(nan.as_bytes()).unwrap();
+    assert_eq!(&reparsed, nan);
+    let decoded = NanBstr::try_from(CBOR::from(nan.clone())).unwrap();
+    assert_eq!(&decoded, nan);
+}
+
+#[test]
+fn random_nans_are_valid_and_cover_both_signs() {
+    let mut rng = rng();
+    for width in NanWidth::ALL {
+        let (mut quiet, mut negative) = (0, 0);
+        for _ in 0..SAMPLES {
+            let nan = NanBstr::random(width, &mut rng);
+            check(&nan, width);
+            quiet += nan.is_quiet() as usize;
+            negative += nan.sign() as usize;
+        }
+        assert!(quiet > 0 && quiet < SAMPLES, "{width:?}: {quiet} quiet");
+        assert!(
+            negative > 0 && negative < SAMPLES,
+            "{width:?}: {negative} negative"
+        );
+    }
+}
+
+#[test]
+fn random_quiet_and_signaling() {
+    let mut rng = rng();
+    for width in NanWidth::ALL {
+        let mut negative = 0;
+        for _ in 0..SAMPLES {
+            let quiet = NanBstr::random_quiet(width, &mut rng);
+            check(&quiet, width);
+            assert!(quiet.is_quiet());
+
+            let signaling = NanBstr::random_signaling(width, &mut rng);
+            check(&signaling, width);
+            assert!(signaling.is_signaling());
+            assert_ne!(signaling.payload_bits(), 0);
+
+            negative += quiet.sign() as usize + signaling.sign() as usize;
+        }
+        assert!(negative > 0 && negative < 2 * SAMPLES);
+    }
+}
+
+#[test]
+fn signaling_binary16_covers_every_payload() {
+    // binary16 signaling NaNs have only 511 payloads, so enough samples
+    // reach every one of them, and never a zero payload.
+    let mut rng = rng();
+    let payloads: HashSet<u128> = (0..5 * SAMPLES)
+        .map(|_| {
+            NanBstr::random_signaling(NanWidth::Binary16, &mut rng)
+                .payload_bits()
+        })
+        .collect();
+    assert_eq!(payloads.len(), 511);
+    assert!(!payloads.contains(&0));
+}
+
+#[test]
+fn nan_distribution() {
+    let mut rng = rng();
+    let distribution =
+        NanDistribution::new(NanWidth::Binary32, Quietness::Quiet);
+    assert_eq!(
+        distribution,
+        NanDistribution {
+            width: NanWidth::Binary32,
+            quietness: Quietness::Quiet,
+        }
+    );
+    for nan in distribution.sample_iter(&mut rng).take(SAMPLES) {
+        check(&nan, NanWidth::Binary32);
+        assert!(nan.is_quiet());
+    }
+
+    let distribution = NanDistribution {
+        width: NanWidth::Binary128,
+        quietness: Quietness::default(),
+    };
+    let quiet = distribution
+        .sample_iter(&mut rng)
+        .take(SAMPLES)
+        .inspect(|nan| check(nan, NanWidth::Binary128))
+        .filter(NanBstr::is_quiet)
+        .count();
+    assert!(quiet > 0 && quiet < SAMPLES);
+}
+
+#[test]
+fn random_any_width_covers_all_widths() {
+    let mut rng = rng();
+    let mut widths = HashSet::new();
+    for _ in 0..SAMPLES {
+        let nan = NanBstr::random_any_width(&mut rng);
+        check(&nan, nan.width());
+        widths.insert(nan.width());
+    }
+    assert_eq!(widths.len(), NanWidth::ALL.len());
+}
+
+#[test]
+fn seeded_generation_is_reproducible() {
+    let first: Vec<_> = (0..16)
+        .scan(rng(), |rng, _| Some(NanBstr::random_any_width(rng)))
+        .collect();
+    let second: Vec<_> = (0..16)
+        .scan(rng(), |rng, _| Some(NanBstr::random_any_width(rng)))
+        .collect();
+    assert_eq!(first, second);
+}
@@ -0,0 +1,124 @@
+use cbor_nan_bstr::{Error, NanBstr, find_nan_bstrs, scan_cbor_bytes};
+use dcbor::prelude::*;
+use hex_literal::hex;
+
+/// Builds a large array of assorted items, returning its encoding and the
+/// offset and value of every real tag-102 item in it.
+fn synthetic_document() -> (Vec<u8>, Vec<(usize, NanBstr)>) {
+    const COUNT: u16 = 3000;
+    let decoy = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    let mut data = vec![0x99];
+    data.extend(COUNT.to_be_bytes());
+    let mut expected = Vec::new();
+    for i in 0..COUNT as u32 {
+        let item: CBOR = match i % 5 {
+            0 => {
+                let nan = NanBstr::from_binary64_bits(
+                    0x7FF8_0000_0000_0000 | i as u64,
+                )
+                .unwrap();
+                expected.push((data.len(), nan.clone()));
+                nan.into()
+            }
+            1 => CBOR::to_byte_string(decoy.to_cbor_data()),
+            2 => {
+                let mut map = Map::new();
+                map.insert("n", i);
+                map.insert("blob", CBOR::to_byte_string(hex!("d866427e00")));
+                map.insert("text", "d866 looks like a tag");
+                map.into()
+            }
+            3 => {
+                vec![CBOR::from(-1.5), CBOR::from(i as u64 * 1_000_003)].into()
+            }
+            _ => {
+                let nan =
+                    NanBstr::from_binary16_bits(0xFC00 | (i % 512) as u16 | 1)
+                        .unwrap();
+                let tagged = CBOR::to_tagged_value(40000, nan.clone());
+                // The NaN follows the three-byte tag head `d9 9c 40`.
+                expected.push((data.len() + 3, nan));
+                tagged
+            }
+        };
+        data.extend(item.to_cbor_data());
+    }
+    (data, expected)
+}
+
+#[test]
+fn finds_real_items_at_their_offsets() {
+    let (data, expected) = synthetic_document();
+    let found: Vec<(usize, NanBstr)> = scan_cbor_bytes(&data)
+        .map(|(offset, nan)| (offset, nan.unwrap()))
+        .collect();
+    assert_eq!(found, expected);
+    for (offset, nan) in &found {
+        assert_eq!(&data[*offset..*offset + 2], [0xD8, 0x66]);
+        let end = offset + nan.to_cbor_data().len();
+        assert_eq!(data[*offset..end], nan.to_cbor_data());
+    }
+}
+
+#[test]
+fn agrees_with_tree_walk() {
+    let (data, _) = synthetic_document();
+    let cbor = CBOR::try_from_data(&data).unwrap();
+    let walked: Vec<NanBstr> = find_nan_bstrs(&cbor)
+        .into_iter()
+        .map(|(_, nan)| nan)
+        .collect();
+    let scanned: Vec<NanBstr> = scan_cbor_bytes(&data)
+        .map(|(_, nan)| nan.unwrap())
+        .collect();
+    assert_eq!(scanned, walked);
+}
+
+#[test]
+fn scans_sequences_and_long_heads() {
+    // Two items: tag 102 in a two-byte head, then the usual encoding.
+    let data = hex!("d90066 42 7e00 d866 44 7f800001");
+    let found: Vec<usize> = scan_cbor_bytes(&data)
+        .map(|(offset, nan)| {
+            assert!(nan.is_ok());
+            offset
+        })
+        .collect();
+    assert_eq!(found, [0, 6]);
+}
+
+#[test]
+fn reports_invalid_content_and_continues() {
+    let data = hex!("82 d866 42 3c00 d866 f97e00");
+    let found: Vec<(usize, Result<NanBstr, Error>)> =
+        scan_cbor_bytes(&data).collect();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].0, 1);
+    assert!(matches!(found[0].1, Err(Error::NotANan)));
+    assert_eq!(found[1].0, 6);
+    assert!(matches!(
+        found[1].1,
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+}
+
+#[test]
+fn stops_at_malformed_heads() {
+    let truncated = hex!("d866 42 7e00 d866 44 7fc0");
+    let found: Vec<_> = scan_cbor_bytes(&truncated).collect();
+    assert_eq!(found.len(), 2);
+    assert!(found[0].1.is_ok());
+    assert_eq!(found[1].0, 5);
+    assert!(matches!(
+        found[1].1,
+        Err(Error::Cbor(dcbor::Error::Underrun))
+    ));
+
+    let reserved = hex!("1c d866 42 7e00");
+    let found: Vec<_> = scan_cbor_bytes(&reserved).collect();
+    assert_eq!(found.len(), 1);
+    assert!(matches!(
+        found[0].1,
+        Err(Error::Cbor(dcbor::Error::UnsupportedHeaderValue(28)))
+    ));
+}
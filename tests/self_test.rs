@@ -0,0 +1,69 @@
+use cbor_nan_bstr::{
+    NanBstr, PropagationOp, float_arg_passing_preserves_snan,
+    propagation_self_test,
+};
+
+#[test]
+fn bits_first_constructors_capture_exact_pattern() {
+    let bits32 = 0x7F80_0123u32;
+    let n = NanBstr::from_f32_bits(bits32).unwrap();
+    assert_eq!(n, NanBstr::from_binary32_bits(bits32).unwrap());
+    assert!(n.is_signaling());
+
+    let bits64 = 0xFFF0_0000_0000_0001u64;
+    let n = NanBstr::from_f64_bits(bits64).unwrap();
+    assert_eq!(n, NanBstr::from_binary64_bits(bits64).unwrap());
+
+    assert!(NanBstr::from_f32_bits(1.0f32.to_bits()).is_err());
+    assert!(NanBstr::from_f64_bits(f64::INFINITY.to_bits()).is_err());
+}
+
+#[test]
+fn reference_constructors_capture_exact_pattern() {
+    let snan32 = f32::from_bits(0x7F80_0123);
+    let n = NanBstr::from_f32_ref(&snan32).unwrap();
+    assert_eq!(n.as_bytes(), &[0x7F, 0x80, 0x01, 0x23]);
+
+    let values = [f64::from_bits(0x7FF0_0000_0000_0123), 1.0];
+    let n = NanBstr::from_f64_ref(&values[0]).unwrap();
+    assert_eq!(n.as_bytes(), &0x7FF0_0000_0000_0123u64.to_be_bytes());
+    assert!(NanBstr::from_f64_ref(&values[1]).is_err());
+}
+
+#[test]
+fn float_arg_passing_self_test_runs() {
+    // The answer is host-dependent; it must simply not panic.
+    let _ = float_arg_passing_preserves_snan();
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[test]
+fn float_arg_passing_preserves_snan_on_sse_and_neon() {
+    // x86_64 (SSE) and aarch64 pass floats in vector registers without
+    // arithmetic, so signaling NaNs survive.
+    assert!(float_arg_passing_preserves_snan());
+}
+
+#[test]
+fn propagation_report_structure() {
+    let report = propagation_self_test();
+    let ops: Vec<PropagationOp> = report.ops.iter().map(|r| r.op).collect();
+    assert_eq!(ops, PropagationOp::ALL);
+    for op in PropagationOp::ALL {
+        assert_eq!(report.get(op).unwrap().op, op);
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[test]
+fn propagation_known_good_on_x86_64_and_aarch64() {
+    let report = propagation_self_test();
+    // SSE and (with default-NaN mode off) AArch64 propagate the NaN operand
+    // of an arithmetic instruction, quieting it if it was signaling.
+    for op in [PropagationOp::Add, PropagationOp::Mul] {
+        let r = report.get(op).unwrap();
+        assert!(r.payload, "{:?} payload", op);
+        assert!(r.sign, "{:?} sign", op);
+        assert!(!r.quietness, "{:?} quietness", op);
+    }
+}
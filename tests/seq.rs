@@ -0,0 +1,141 @@
+use cbor_nan_bstr::{Error, NanBstr, NanBstrSeqIter};
+use hex_literal::hex;
+
+mod common;
+use common::nans;
+
+/// Returns the offset and inner error of a sequence error.
+fn seq_error(err: Error) -> (usize, Error) {
+    match err {
+        Error::SeqItem { offset, source } => (offset, *source),
+        other => panic!("{other:?}"),
+    }
+}
+
+#[test]
+fn encode_seq_concatenates_encodings() {
+    let data = NanBstr::encode_seq(&nans());
+    let expected: Vec<u8> =
+        nans().iter().flat_map(|nan| nan.to_cbor_data()).collect();
+    assert_eq!(data, expected);
+    assert_eq!(NanBstr::encode_seq(&[]), Vec::<u8>::new());
+}
+
+#[test]
+fn decode_seq_round_trips() {
+    let data = NanBstr::encode_seq(&nans());
+    assert_eq!(NanBstr::decode_seq(&data).unwrap(), nans());
+}
+
+#[test]
+fn empty_input_is_an_empty_sequence() {
+    assert!(NanBstr::decode_seq(&[]).unwrap().is_empty());
+    assert_eq!(NanBstrSeqIter::new(&[]).count(), 0);
+}
+
+#[test]
+fn iterator_tracks_offsets() {
+    let data = NanBstr::encode_seq(&nans());
+    let mut iter = NanBstrSeqIter::new(&data);
+    let mut offset = 0;
+    for nan in nans() {
+        assert_eq!(iter.offset(), offset);
+        assert_eq!(iter.next().unwrap().unwrap(), nan);
+        offset += nan.encoded_len();
+    }
+    assert_eq!(iter.offset(), data.len());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn other_items_are_per_item_errors() {
+    // A NaN, the unsigned integer 1, an array of a tag-102 item and text,
+    // tag 103, and another NaN.
+    let data = hex!(
+        "d866 427e00"
+        "01"
+        "82 d866427e00 6161"
+        "d867 427e00"
+        "d866 447fc00001"
+    );
+    let results: Vec<_> = NanBstrSeqIter::new(&data).collect();
+    assert_eq!(results.len(), 5);
+    assert_eq!(
+        results[0].as_ref().unwrap(),
+        &NanBstr::from_binary16_bits(0x7E00).unwrap()
+    );
+    assert_eq!(
+        results[4].as_ref().unwrap(),
+        &NanBstr::from_binary32_bits(0x7FC0_0001).unwrap()
+    );
+    let errors: Vec<_> = results
+        .into_iter()
+        .filter_map(Result::err)
+        .map(seq_error)
+        .collect();
+    assert!(matches!(
+        errors[0],
+        (5, Error::Cbor(dcbor::Error::WrongType))
+    ));
+    assert!(matches!(
+        errors[1],
+        (6, Error::Cbor(dcbor::Error::WrongType))
+    ));
+    assert!(matches!(
+        errors[2],
+        (14, Error::Cbor(dcbor::Error::WrongTag(..)))
+    ));
+
+    let err = NanBstr::decode_seq(&data).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("CBOR sequence item at offset 5: CBOR error"),
+        "{err}"
+    );
+}
+
+#[test]
+fn invalid_nans_are_per_item_errors() {
+    let data = hex!("d866 427c00 d866 43000000 d866 427e00");
+    let results: Vec<_> = NanBstrSeqIter::new(&data).collect();
+    assert_eq!(results.len(), 3);
+    let mut results = results.into_iter();
+    assert!(matches!(
+        seq_error(results.next().unwrap().unwrap_err()),
+        (0, Error::NotANan)
+    ));
+    assert!(matches!(
+        seq_error(results.next().unwrap().unwrap_err()),
+        (5, Error::InvalidLength(3))
+    ));
+    assert!(results.next().unwrap().is_ok());
+}
+
+#[test]
+fn truncated_item_ends_the_sequence() {
+    let mut data = NanBstr::encode_seq(&nans());
+    data.truncate(data.len() - 1);
+    let mut results: Vec<_> = NanBstrSeqIter::new(&data).collect();
+    assert_eq!(results.len(), 4);
+    let (offset, err) = seq_error(results.pop().unwrap().unwrap_err());
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(offset, 5 + 7 + 11);
+    assert!(matches!(err, Error::Cbor(dcbor::Error::Underrun)));
+}
+
+#[test]
+fn unskippable_items_end_the_sequence() {
+    // A reserved head, an indefinite-length array, and an array claiming
+    // more items than there are bytes.
+    for data in [
+        &hex!("d866427e00 1c d866427e00")[..],
+        &hex!("d866427e00 9f ff d866427e00"),
+        &hex!("d866427e00 9b ffffffffffffffff"),
+    ] {
+        let mut results: Vec<_> = NanBstrSeqIter::new(data).collect();
+        assert_eq!(results.len(), 2, "{data:02x?}");
+        let (offset, _) = seq_error(results.pop().unwrap().unwrap_err());
+        assert!(results[0].is_ok());
+        assert_eq!(offset, 5);
+    }
+}
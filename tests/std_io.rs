@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, Cursor, Read};
+
+use cbor_nan_bstr::{Error, NanBstr};
+use hex_literal::hex;
+
+mod common;
+use common::nans;
+
+/// Yields at most one byte per read, as a slow socket might.
+struct OneByte<R>(R);
+
+impl<R: Read> Read for OneByte<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(1);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+fn encoded() -> Vec<u8> {
+    let mut data = Vec::new();
+    for nan in nans() {
+        let written = nan.write_cbor_to(&mut data).unwrap();
+        assert_eq!(written, nan.encoded_len());
+    }
+    data
+}
+
+#[test]
+fn write_matches_to_cbor_data() {
+    let expected: Vec<u8> =
+        nans().iter().flat_map(|nan| nan.to_cbor_data()).collect();
+    assert_eq!(encoded(), expected);
+}
+
+#[test]
+fn reads_items_back_to_back() {
+    let mut cursor = Cursor::new(encoded());
+    for nan in nans() {
+        assert_eq!(NanBstr::read_cbor_from(&mut cursor).unwrap(), nan);
+    }
+    assert_eq!(cursor.position() as usize, cursor.get_ref().len());
+}
+
+#[test]
+fn reads_one_byte_at_a_time() {
+    let mut r = OneByte(Cursor::new(encoded()));
+    for nan in nans() {
+        assert_eq!(NanBstr::read_cbor_from(&mut r).unwrap(), nan);
+    }
+    let mut rest = Vec::new();
+    r.read_to_end(&mut rest).unwrap();
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn does_not_read_past_the_item() {
+    let mut cursor = Cursor::new(hex!("d866 427e00 ff 0102").to_vec());
+    let nan = NanBstr::read_cbor_from(&mut cursor).unwrap();
+    assert_eq!(nan, NanBstr::from_binary16_bits(0x7E00).unwrap());
+    assert_eq!(cursor.position(), 5);
+    let mut rest = Vec::new();
+    cursor.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, hex!("ff 0102"));
+}
+
+#[test]
+fn short_reads_are_unexpected_eof() {
+    let data = NanBstr::from_binary64_bits(0x7FF8_0000_0000_0123)
+        .unwrap()
+        .to_cbor_data();
+    for len in 0..data.len() {
+        let mut r = OneByte(Cursor::new(&data[..len]));
+        match NanBstr::read_cbor_from(&mut r) {
+            Err(Error::Io(err)) => {
+                assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof)
+            }
+            other => panic!("{len}: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn rejects_wrong_tag_and_content() {
+    let mut r = Cursor::new(hex!("d867 427e00"));
+    assert!(matches!(
+        NanBstr::read_cbor_from(&mut r),
+        Err(Error::Cbor(dcbor::Error::WrongTag(..)))
+    ));
+    let mut r = Cursor::new(hex!("d866 f97e00"));
+    assert!(matches!(
+        NanBstr::read_cbor_from(&mut r),
+        Err(Error::Cbor(dcbor::Error::WrongType))
+    ));
+    // A bad length is caught from the heads, before the pattern is read.
+    let mut r = Cursor::new(hex!("d866 43 7e0000"));
+    assert!(matches!(
+        NanBstr::read_cbor_from(&mut r),
+        Err(Error::InvalidLength(3))
+    ));
+    assert_eq!(r.position(), 3);
+}
+
+#[test]
+fn rejects_invalid_nan_bits() {
+    let mut r = Cursor::new(hex!("d866 427c00"));
+    assert!(matches!(
+        NanBstr::read_cbor_from(&mut r),
+        Err(Error::NotANan)
+    ));
+}
+
+#[test]
+fn write_reports_io_errors() {
+    let mut buf = [0u8; 4];
+    let mut w: &mut [u8] = &mut buf;
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    match nan.write_cbor_to(&mut w) {
+        Err(Error::Io(err)) => {
+            assert_eq!(err.kind(), io::ErrorKind::WriteZero)
+        }
+        other => panic!("{other:?}"),
+    }
+}
@@ -0,0 +1,50 @@
+use cbor_nan_bstr::{NanBstr, render_table};
+use indoc::indoc;
+
+#[test]
+fn three_observations() {
+    let sent = NanBstr::from_binary32_bits(0x7F80_0001).unwrap();
+    let received = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    let echoed = NanBstr::from_binary64_bits(0xFFF8_0000_0000_0001).unwrap();
+    let table = render_table(&[
+        ("sent", &sent),
+        ("received", &received),
+        ("echoed", &echoed),
+    ]);
+    assert_eq!(
+        table,
+        indoc! {"
+            label     width  sign  q/s  payload  raw
+            --------  -----  ----  ---  -------  ----------------
+            sent      32     +     s    0x1      7f800001
+            received  32     +     q    0x1      7fc00001
+            echoed    64     -     q    0x1      fff8000000000001
+                      ^^^^^  ^^^^  ^^^           ^^^^^^^^^^^^^^^^
+        "}
+    );
+}
+
+#[test]
+fn identical_rows_have_no_marker_row() {
+    let nan = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert_eq!(
+        render_table(&[("a", &nan), ("b", &nan)]),
+        indoc! {"
+            label  width  sign  q/s  payload  raw
+            -----  -----  ----  ---  -------  ----
+            a      16     +     q    0x0      7e00
+            b      16     +     q    0x0      7e00
+        "}
+    );
+}
+
+#[test]
+fn empty_table_is_a_header() {
+    assert_eq!(
+        render_table(&[]),
+        indoc! {"
+            label  width  sign  q/s  payload  raw
+            -----  -----  ----  ---  -------  ---
+        "}
+    );
+}
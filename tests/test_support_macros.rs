@@ -0,0 +1,121 @@
+#![cfg(feature = "test-support")]
+
+use cbor_nan_bstr::{
+    NanBstr, assert_decodes_to, assert_encodes_as,
+    test_support::{annotated_hex, check_decodes_to, check_encodes_as},
+};
+use dcbor::prelude::*;
+use indoc::indoc;
+
+fn qnan16() -> NanBstr {
+    NanBstr::from_binary16_bits(0x7E00).unwrap()
+}
+
+#[test]
+fn macros_pass() {
+    assert_encodes_as!(qnan16(), "d866427e00");
+    assert_encodes_as!(qnan16(), "d866 42 7e00");
+    assert_encodes_as!(vec![1.5, 2.0], "82 f93e00 02");
+    assert_decodes_to!("d866427e00", qnan16());
+    assert_decodes_to!("d866 44 7fc00001", {
+        NanBstr::from_binary32_bits(0x7FC0_0001).unwrap()
+    });
+    assert_decodes_to!("f93e00", 1.5f64);
+}
+
+#[test]
+#[should_panic(expected = "encoding mismatch")]
+fn encodes_as_panics() {
+    assert_encodes_as!(qnan16(), "d86642fe00");
+}
+
+#[test]
+#[should_panic(expected = "decoded value differs from expected")]
+fn decodes_to_panics() {
+    assert_decodes_to!("d866427e01", qnan16());
+}
+
+#[test]
+fn annotation_describes_tag_102_content() {
+    let mut map = Map::new();
+    map.insert("a", qnan16());
+    map.insert(
+        "b",
+        CBOR::to_tagged_value(102, ByteString::from([0x7Cu8, 0x00])),
+    );
+    assert_eq!(
+        annotated_hex(&CBOR::from(map).to_cbor_data()),
+        indoc! {"
+            a2                  # map(2)
+                61              # text(1)
+                    61          # \"a\"
+                d8 66           # tag(102) nan-bstr
+                    42          # bytes(2)
+                        7e00    # NaN[binary16]: + quiet frac=0x200 payload=0x0
+                61              # text(1)
+                    62          # \"b\"
+                d8 66           # tag(102) nan-bstr
+                    42          # bytes(2)
+                        7c00    # invalid NaN (not a NaN bit pattern): h'7c00' is binary16 sign=+ exponent=0x1f fraction=0x0, an infinity; 1 bit away from canonical-qnan-16"
+        }
+    );
+    assert_eq!(
+        annotated_hex(&[0xD8, 0x66]),
+        "d866 # not deterministic CBOR"
+    );
+}
+
+#[test]
+fn encodes_as_failure_message() {
+    let nan = NanBstr::from_binary16_bits(0xFE00).unwrap();
+    assert_eq!(
+        check_encodes_as(&nan, "d866427e00").unwrap_err(),
+        indoc! {"
+            encoding mismatch
+            expected: d866427e00
+              actual: d86642fe00
+            diff (- expected, + actual):
+              d8 66           # tag(102) nan-bstr
+                  42          # bytes(2)
+            -         7e00    # NaN[binary16]: + quiet frac=0x200 payload=0x0
+            +         fe00    # NaN[binary16]: - quiet frac=0x200 payload=0x0"
+        }
+    );
+    assert!(
+        check_encodes_as(&nan, "d866 42 fe0")
+            .unwrap_err()
+            .starts_with("invalid hex `d866 42 fe0`")
+    );
+}
+
+#[test]
+fn decodes_to_failure_messages() {
+    assert_eq!(
+        check_decodes_to("d866427e01", &qnan16()).unwrap_err(),
+        indoc! {"
+            decoded value differs from expected
+            expected: NanBstr { width: Binary16, sign: false, quiet: true, payload: 0x0, bytes: \"7e00\" }
+              actual: NanBstr { width: Binary16, sign: false, quiet: true, payload: 0x1, bytes: \"7e01\" }
+            diff (- expected, + actual):
+              d8 66           # tag(102) nan-bstr
+                  42          # bytes(2)
+            -         7e00    # NaN[binary16]: + quiet frac=0x200 payload=0x0
+            +         7e01    # NaN[binary16]: + quiet frac=0x201 payload=0x1"
+        }
+    );
+    assert_eq!(
+        check_decodes_to("d8664200", &qnan16()).unwrap_err(),
+        indoc! {"
+            decoding failed: early end of CBOR data
+            input: d8664200
+            d8664200 # not deterministic CBOR"
+        }
+    );
+    let message = check_decodes_to("d866427c00", &qnan16()).unwrap_err();
+    assert!(message.starts_with("decoding failed: "), "{}", message);
+    assert!(
+        message.ends_with("an infinity; 1 bit away from canonical-qnan-16"),
+        "{}",
+        message
+    );
+}
@@ -0,0 +1,72 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use cbor_nan_bstr::{ByTotalOrder, NanBstr};
+
+/// NaNs in `totalOrder`, as extended across widths.
+fn reference() -> Vec<NanBstr> {
+    vec![
+        NanBstr::from_binary64_bits(0xFFF8_0000_0000_0123).unwrap(),
+        NanBstr::from_binary16_bits(0xFE00).unwrap(),
+        NanBstr::from_binary32_bits(0xFFC0_0000).unwrap(),
+        NanBstr::from_binary32_bits(0xFF80_0001).unwrap(),
+        NanBstr::from_binary16_bits(0x7C01).unwrap(),
+        NanBstr::from_binary128_words(0x7FFF_0000_0000_0000, 1).unwrap(),
+        NanBstr::from_binary16_bits(0x7DFF).unwrap(),
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0005).unwrap(),
+        NanBstr::from_binary64_bits(0x7FFF_FFFF_FFFF_FFFF).unwrap(),
+    ]
+}
+
+fn shuffled() -> Vec<NanBstr> {
+    let reference = reference();
+    [7, 2, 10, 0, 5, 9, 3, 8, 1, 6, 4]
+        .iter()
+        .map(|&i| reference[i].clone())
+        .collect()
+}
+
+#[test]
+fn btree_set_iterates_in_total_order() {
+    let set: BTreeSet<ByTotalOrder> =
+        shuffled().into_iter().map(ByTotalOrder::from).collect();
+    let order: Vec<NanBstr> = set.into_iter().map(NanBstr::from).collect();
+    assert_eq!(order, reference());
+}
+
+#[test]
+fn btree_map_counts() {
+    let mut counts: BTreeMap<ByTotalOrder, usize> = BTreeMap::new();
+    for nan in shuffled()
+        .into_iter()
+        .chain(reference().into_iter().take(3))
+    {
+        *counts.entry(nan.into()).or_default() += 1;
+    }
+    let first: Vec<(NanBstr, usize)> = counts
+        .into_iter()
+        .take(4)
+        .map(|(k, v)| (k.into_inner(), v))
+        .collect();
+    let expected: Vec<(NanBstr, usize)> =
+        reference().into_iter().zip([2, 2, 2, 1]).collect();
+    assert_eq!(first, expected);
+}
+
+#[test]
+fn total_cmp_sorts_like_the_adapter() {
+    let mut nans = shuffled();
+    nans.sort_by(NanBstr::total_cmp);
+    assert_eq!(nans, reference());
+}
+
+#[test]
+fn ordering_agrees_with_equality() {
+    for a in reference() {
+        for b in reference() {
+            let (a, b) = (ByTotalOrder(a.clone()), ByTotalOrder(b));
+            assert_eq!(a == b, a.cmp(&b).is_eq());
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "ts-rs")]
+
+use std::collections::BTreeSet;
+
+use cbor_nan_bstr::{
+    AuditEntryJson, AuditReportJson, MalformedNanJson, NanBstr, NanFields,
+    NanPolicy, NanWidth, NearestPatternJson, audit_report,
+};
+use dcbor::prelude::*;
+use serde_json::Value;
+use ts_rs::{Config, TS};
+
+/// Returns the property names of the object type in a `type X = { ... };`
+/// declaration.
+fn declared_fields(decl: &str) -> BTreeSet<String> {
+    let mut body =
+        decl[decl.find('{').unwrap() + 1..decl.rfind('}').unwrap()].to_string();
+    while let Some(start) = body.find("/**") {
+        let end = body[start..].find("*/").unwrap() + start + 2;
+        body.replace_range(start..end, "");
+    }
+    body.split(',')
+        .filter_map(|field| field.split(':').next())
+        .map(|name| name.trim().trim_end_matches('?').to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn serialized_fields(value: &Value) -> BTreeSet<String> {
+    value.as_object().unwrap().keys().cloned().collect()
+}
+
+fn report_json() -> Value {
+    let mut map = Map::new();
+    map.insert("a", NanBstr::from_binary32_bits(0xFF80_0001).unwrap());
+    map.insert("b", NanBstr::from_binary16_bits(0x7E00).unwrap());
+    map.insert(
+        "bad",
+        CBOR::to_tagged_value(102, CBOR::to_byte_string([0x7C, 0x01, 0x00])),
+    );
+    let report = audit_report(&map.into(), Some(&NanPolicy::canonical_only()));
+    serde_json::to_value(AuditReportJson::from(&report)).unwrap()
+}
+
+#[test]
+fn nan_width_is_a_string_union() {
+    assert_eq!(
+        NanWidth::decl(&Config::new()),
+        r#"type NanWidth = "binary16" | "binary32" | "binary64" | "binary128";"#
+    );
+    assert_eq!(
+        serde_json::to_value(NanWidth::Binary128).unwrap(),
+        Value::from("binary128")
+    );
+}
+
+#[test]
+fn nan_fields_match_serde_output() {
+    let nan = NanBstr::from_binary64_bits(0xFFF0_0000_0000_002A).unwrap();
+    let value = serde_json::to_value(NanFields::from(&nan)).unwrap();
+    assert_eq!(
+        serialized_fields(&value),
+        declared_fields(&NanFields::decl(&Config::new()))
+    );
+    assert_eq!(value["width"], "binary64");
+    assert_eq!(value["payload"], "0x2a");
+    assert_eq!(
+        serde_json::from_value::<NanFields>(value).unwrap(),
+        NanFields::from(&nan)
+    );
+}
+
+#[test]
+fn audit_report_matches_serde_output() {
+    let value = report_json();
+    assert_eq!(
+        serialized_fields(&value),
+        declared_fields(&AuditReportJson::decl(&Config::new()))
+    );
+    let entry = &value["nans"][0];
+    assert_eq!(
+        serialized_fields(entry),
+        declared_fields(&AuditEntryJson::decl(&Config::new()))
+    );
+    assert_eq!(
+        serialized_fields(&entry["nan"]),
+        declared_fields(&NanFields::decl(&Config::new()))
+    );
+    let malformed = &value["malformed"][0];
+    assert_eq!(
+        serialized_fields(malformed),
+        declared_fields(&MalformedNanJson::decl(&Config::new()))
+    );
+    assert_eq!(value["passes"], false);
+    assert_eq!(value["counts"]["signaling"], 1);
+}
+
+#[test]
+fn nearest_pattern_matches_serde_output() {
+    let nearest = NearestPatternJson {
+        name: "canonical-qnan-16".into(),
+        distance: 1,
+    };
+    let value = serde_json::to_value(nearest).unwrap();
+    assert_eq!(
+        serialized_fields(&value),
+        declared_fields(&NearestPatternJson::decl(&Config::new()))
+    );
+}
+
+#[test]
+fn exports_every_definition() {
+    let dir = std::env::temp_dir().join("cbor-nan-bstr-ts-rs");
+    AuditReportJson::export_all(&Config::new().with_out_dir(&dir)).unwrap();
+    for name in [
+        "AuditReportJson",
+        "AuditEntryJson",
+        "MalformedNanJson",
+        "NearestPatternJson",
+        "NanFields",
+        "NanWidth",
+    ] {
+        let path = dir.join(format!("{}.ts", name));
+        let ts = std::fs::read_to_string(&path).unwrap();
+        assert!(ts.contains(&format!("export type {} =", name)), "{}", ts);
+    }
+}
@@ -0,0 +1,77 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+/// Deterministic pseudo-random bits (xorshift64).
+fn noise(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+#[test]
+fn agrees_with_checked_on_valid_input() {
+    let mut state = 0x6e61_6e62;
+    let mut checked = 0;
+    for _ in 0..4000 {
+        let bits =
+            ((noise(&mut state) as u128) << 64) | noise(&mut state) as u128;
+        for len in [2, 4, 8, 16] {
+            let pattern = &bits.to_be_bytes()[16 - len..];
+            // Force the exponent to all ones so most patterns are NaNs.
+            let mut pattern = pattern.to_vec();
+            let width = NanWidth::from_len(len).unwrap();
+            pattern[0] |= 0x7F;
+            pattern[1] |= match width {
+                NanWidth::Binary16 => 0xFC,
+                NanWidth::Binary32 => 0x80,
+                NanWidth::Binary64 => 0xF0,
+                NanWidth::Binary128 => 0xFF,
+            };
+            let Ok(nan) = NanBstr::from_be_bytes(&pattern) else {
+                continue;
+            };
+            let unchecked = NanBstr::from_be_bytes_unchecked(&pattern).unwrap();
+            assert_eq!(unchecked, nan);
+            assert_eq!(unchecked.width(), nan.width());
+            assert_eq!(unchecked.payload_bits(), nan.payload_bits());
+            checked += 1;
+        }
+    }
+    assert!(checked > 15_000);
+}
+
+#[test]
+fn checks_the_length() {
+    for len in [0, 1, 3, 5, 9, 17] {
+        assert!(matches!(
+            NanBstr::from_be_bytes_unchecked(vec![0xFF; len]),
+            Err(Error::InvalidLength(n)) if n == len
+        ));
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "not a NaN")]
+fn debug_builds_assert_the_bits() {
+    let _ = NanBstr::from_be_bytes_unchecked([0x7C, 0x00]);
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn release_builds_keep_the_bits_as_given() {
+    // An infinity: the accessors report its fields without panicking.
+    let inf = NanBstr::from_be_bytes_unchecked([0xFC, 0x00]).unwrap();
+    assert_eq!(inf.width(), NanWidth::Binary16);
+    assert!(inf.sign());
+    assert_eq!(inf.exponent_bits(), 0x1F);
+    assert_eq!(inf.fraction_bits(), 0);
+    // A corrupted exponent reads back as it arrived.
+    let one = NanBstr::from_be_bytes_unchecked([0x3C, 0x01]).unwrap();
+    assert_eq!(one.exponent_bits(), 0x0F);
+    assert_eq!(one.exponent_field_width(), 5);
+    assert_eq!(one.fraction_bits(), 1);
+    assert!(one.explain().contains("0xf 01111 (not all ones)"));
+    assert_eq!(inf.as_bytes(), [0xFC, 0x00]);
+    assert!(NanBstr::from_cbor_data(inf.to_cbor_data()).is_err());
+}
@@ -0,0 +1,95 @@
+use cbor_nan_bstr::{BitsLiteral, NanBstr, NanVisitor};
+
+/// Records which arm was called and with what bits.
+struct Recorder;
+
+impl NanVisitor for Recorder {
+    type Output = (u32, u128);
+
+    fn visit_b16(self, bits: u16) -> (u32, u128) {
+        (16, bits as u128)
+    }
+
+    fn visit_b32(self, bits: u32) -> (u32, u128) {
+        (32, bits as u128)
+    }
+
+    fn visit_b64(self, bits: u64) -> (u32, u128) {
+        (64, bits as u128)
+    }
+
+    fn visit_b128(self, bits: u128) -> (u32, u128) {
+        (128, bits)
+    }
+}
+
+#[test]
+fn each_arm_receives_native_bits() {
+    let cases = [
+        (NanBstr::from_binary16_bits(0xFE01).unwrap(), 16, 0xFE01),
+        (
+            NanBstr::from_binary32_bits(0x7F80_0001).unwrap(),
+            32,
+            0x7F80_0001,
+        ),
+        (
+            NanBstr::from_binary64_bits(0xFFF8_0000_0000_0123).unwrap(),
+            64,
+            0xFFF8_0000_0000_0123,
+        ),
+        (
+            NanBstr::from_binary128_words(0x7FFF_8000_0000_0000, 7).unwrap(),
+            128,
+            0x7FFF_8000_0000_0000_0000_0000_0000_0007,
+        ),
+    ];
+    for (nan, width, bits) in cases {
+        assert_eq!(nan.visit(Recorder), (width, bits));
+    }
+}
+
+#[test]
+fn bits_match_native_floats() {
+    let f = f32::from_bits(0x7FC0_1234);
+    let nan = NanBstr::try_from(f).unwrap();
+    struct F32Bits;
+    impl NanVisitor for F32Bits {
+        type Output = Option<u32>;
+        fn visit_b16(self, _: u16) -> Option<u32> {
+            None
+        }
+        fn visit_b32(self, bits: u32) -> Option<u32> {
+            Some(bits)
+        }
+        fn visit_b64(self, _: u64) -> Option<u32> {
+            None
+        }
+        fn visit_b128(self, _: u128) -> Option<u32> {
+            None
+        }
+    }
+    assert_eq!(nan.visit(F32Bits), Some(f.to_bits()));
+}
+
+#[test]
+fn bits_literal() {
+    let literal = |nan: NanBstr| nan.visit(BitsLiteral);
+    assert_eq!(
+        literal(NanBstr::from_binary16_bits(0x7E00).unwrap()),
+        "0x7e00_u16"
+    );
+    assert_eq!(
+        literal(NanBstr::from_binary32_bits(0x7FC0_0001).unwrap()),
+        "0x7fc00001_u32"
+    );
+    assert_eq!(
+        literal(NanBstr::from_binary64_bits(0x7FF0_0000_0000_0001).unwrap()),
+        "0x7ff0000000000001_u64"
+    );
+    assert_eq!(
+        literal(
+            NanBstr::from_binary128_words(0xFFFF_0000_0000_0000, 1).unwrap()
+        ),
+        "0xffff0000000000000000000000000001_u128"
+    );
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "wasmtime")]
+
+use cbor_nan_bstr::{
+    Error, NanBstr, PayloadVerdict, WASM_PROBE_F32, WASM_PROBE_F64,
+    check_roundtrip_through,
+};
+use wasmtime::{Engine, Instance, Module, Store, Val};
+
+const GUEST: &str = r#"
+    (module
+      (func (export "id32") (param f32) (result f32) local.get 0)
+      (func (export "id64") (param f64) (result f64) local.get 0)
+      (func (export "add_zero64") (param f64) (result f64)
+        local.get 0
+        f64.const -0
+        f64.add)
+      (func (export "canonical64") (param f64) (result f64)
+        f64.const nan)
+      (func (export "flip_sign32") (param f32) (result f32)
+        local.get 0
+        f32.neg)
+      (func (export "zero32") (param f32) (result f32)
+        f32.const 0)
+      (func (export "trap64") (param f64) (result f64)
+        unreachable)
+      (func (export "mixed") (param f32) (result f64)
+        f64.const 0))
+"#;
+
+fn instantiate() -> (Store<()>, Instance) {
+    let engine = Engine::default();
+    let module = Module::new(&engine, wat::parse_str(GUEST).unwrap()).unwrap();
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[]).unwrap();
+    (store, instance)
+}
+
+#[test]
+fn from_wasm_vals() {
+    let nan = NanBstr::from_wasm_f32(&Val::F32(0x7FC0_0001)).unwrap();
+    assert_eq!(nan, NanBstr::from_binary32_bits(0x7FC0_0001).unwrap());
+    let nan = NanBstr::from_wasm_f64(&Val::F64(WASM_PROBE_F64)).unwrap();
+    assert!(nan.is_signaling());
+    assert!(matches!(
+        NanBstr::from_wasm_f32(&Val::F64(WASM_PROBE_F64)),
+        Err(Error::WasmValType("f32"))
+    ));
+    assert!(matches!(
+        NanBstr::from_wasm_f64(&Val::I64(1)),
+        Err(Error::WasmValType("f64"))
+    ));
+    assert!(matches!(
+        NanBstr::from_wasm_f32(&Val::F32(1.5f32.to_bits())),
+        Err(Error::NotANan)
+    ));
+    assert!(NanBstr::from_wasm_f32(&Val::F32(WASM_PROBE_F32)).is_ok());
+}
+
+#[test]
+fn identity_preserves_the_pattern() {
+    let (mut store, instance) = instantiate();
+    for name in ["id32", "id64"] {
+        assert_eq!(
+            check_roundtrip_through(&mut store, &instance, name).unwrap(),
+            PayloadVerdict::Intact,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn arithmetic_may_change_the_pattern() {
+    let (mut store, instance) = instantiate();
+    // Wasm permits either canonical or arithmetic NaN results here, so
+    // the verdict depends on the host; it is never intact, as the input is
+    // signaling.
+    let verdict =
+        check_roundtrip_through(&mut store, &instance, "add_zero64").unwrap();
+    assert!(
+        matches!(
+            verdict,
+            PayloadVerdict::Quieted | PayloadVerdict::ReplacedByCanonical
+        ),
+        "{:?}",
+        verdict
+    );
+    assert_eq!(
+        check_roundtrip_through(&mut store, &instance, "canonical64").unwrap(),
+        PayloadVerdict::ReplacedByCanonical
+    );
+    assert_eq!(
+        check_roundtrip_through(&mut store, &instance, "flip_sign32").unwrap(),
+        PayloadVerdict::PayloadChanged {
+            from: NanBstr::from_binary32_bits(WASM_PROBE_F32).unwrap(),
+            to: NanBstr::from_binary32_bits(WASM_PROBE_F32 | 1 << 31).unwrap(),
+        }
+    );
+    assert_eq!(
+        check_roundtrip_through(&mut store, &instance, "zero32").unwrap(),
+        PayloadVerdict::NoLongerNan
+    );
+}
+
+#[test]
+fn unusable_functions() {
+    let (mut store, instance) = instantiate();
+    for (name, message) in [
+        ("missing", "no function `missing`"),
+        ("mixed", "must have type"),
+        ("trap64", "`trap64` failed"),
+    ] {
+        match check_roundtrip_through(&mut store, &instance, name) {
+            Err(Error::Wasm(err)) => {
+                assert!(err.contains(message), "{}", err)
+            }
+            other => panic!("{}: {:?}", name, other),
+        }
+    }
+}
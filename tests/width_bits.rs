@@ -0,0 +1,77 @@
+use cbor_nan_bstr::{Error, NanBstr, NanWidth};
+
+#[test]
+fn constructors_round_trip() {
+    let half = NanBstr::from_binary16_bits(0xFE01).unwrap();
+    assert_eq!(half.to_binary16_bits(), Some(0xFE01));
+    let single = NanBstr::from_binary32_bits(0x7FC0_0001).unwrap();
+    assert_eq!(single.to_binary32_bits(), Some(0x7FC0_0001));
+    let double = NanBstr::from_binary64_bits(0xFFF0_0000_0000_0123).unwrap();
+    assert_eq!(double.to_binary64_bits(), Some(0xFFF0_0000_0000_0123));
+    let quad = 0x7FFF_0000_0000_0000_0000_0000_0000_0001;
+    let quad_nan = NanBstr::from_binary128_bits(quad).unwrap();
+    assert_eq!(quad_nan.to_binary128_bits(), Some(quad));
+}
+
+#[test]
+fn round_trip_every_width_pattern() {
+    for width in NanWidth::ALL {
+        for nan in [
+            NanBstr::canonical_quiet(width),
+            NanBstr::minimal_signaling(width),
+            NanBstr::from_parts(width, true, true, width.max_payload())
+                .unwrap(),
+        ] {
+            let bits = nan.to_bits_u128();
+            match width {
+                NanWidth::Binary16 => {
+                    assert_eq!(nan.to_binary16_bits(), Some(bits as u16))
+                }
+                NanWidth::Binary32 => {
+                    assert_eq!(nan.to_binary32_bits(), Some(bits as u32))
+                }
+                NanWidth::Binary64 => {
+                    assert_eq!(nan.to_binary64_bits(), Some(bits as u64))
+                }
+                NanWidth::Binary128 => {
+                    assert_eq!(nan.to_binary128_bits(), Some(bits))
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn other_widths_are_none() {
+    for width in NanWidth::ALL {
+        let nan = NanBstr::canonical_quiet(width);
+        let present = [
+            nan.to_binary16_bits().is_some(),
+            nan.to_binary32_bits().is_some(),
+            nan.to_binary64_bits().is_some(),
+            nan.to_binary128_bits().is_some(),
+        ];
+        let expected = NanWidth::ALL.map(|w| w == width);
+        assert_eq!(present, expected, "{width}");
+    }
+}
+
+#[test]
+fn try_from_names_the_width() {
+    let nan = NanBstr::canonical_quiet(NanWidth::Binary64);
+    assert_eq!(u64::try_from(&nan).unwrap(), 0x7FF8_0000_0000_0000);
+    assert!(matches!(
+        u32::try_from(&nan),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary32,
+            actual: NanWidth::Binary64,
+        })
+    ));
+    assert!(matches!(
+        u16::try_from(nan),
+        Err(Error::WidthMismatch {
+            expected: NanWidth::Binary16,
+            actual: NanWidth::Binary64,
+        })
+    ));
+}
@@ -0,0 +1,112 @@
+use cbor_nan_bstr::{
+    Error, NanBstr, NanPattern, NanPolicy, NanWidth, PolicyViolation, WidthSet,
+};
+
+#[test]
+fn constants() {
+    assert!(WidthSet::EMPTY.is_empty());
+    assert_eq!(WidthSet::ALL.len(), 4);
+    assert_eq!(WidthSet::NARROW.len(), 3);
+    assert!(!WidthSet::NARROW.contains(NanWidth::Binary128));
+    assert_eq!(WidthSet::NARROW | WidthSet::BINARY128, WidthSet::ALL);
+    assert_eq!(WidthSet::default(), WidthSet::EMPTY);
+    assert_eq!(WidthSet::from(NanWidth::Binary32), WidthSet::BINARY32);
+}
+
+#[test]
+fn set_operations() {
+    let a = WidthSet::BINARY16 | WidthSet::BINARY32;
+    let b = WidthSet::BINARY32 | WidthSet::BINARY64;
+    assert_eq!(a | b, WidthSet::NARROW);
+    assert_eq!(a & b, WidthSet::BINARY32);
+    assert_eq!(a - b, WidthSet::BINARY16);
+    assert!(WidthSet::ALL.is_superset(a));
+    assert!(!a.is_superset(b));
+
+    let mut set = WidthSet::EMPTY;
+    set.insert(NanWidth::Binary128);
+    set.insert(NanWidth::Binary16);
+    set |= WidthSet::BINARY64;
+    set &= WidthSet::BINARY16 | WidthSet::BINARY128;
+    set.remove(NanWidth::Binary128);
+    assert_eq!(set, WidthSet::BINARY16);
+}
+
+#[test]
+fn iterates_narrowest_first() {
+    let set: WidthSet = [
+        NanWidth::Binary128,
+        NanWidth::Binary16,
+        NanWidth::Binary64,
+        NanWidth::Binary16,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [NanWidth::Binary16, NanWidth::Binary64, NanWidth::Binary128]
+    );
+    assert_eq!(set.into_iter().count(), 3);
+    assert_eq!(WidthSet::EMPTY.iter().count(), 0);
+}
+
+#[test]
+fn display_and_parse() {
+    assert_eq!(WidthSet::NARROW.to_string(), "16|32|64");
+    assert_eq!(WidthSet::ALL.to_string(), "16|32|64|128");
+    assert_eq!(WidthSet::EMPTY.to_string(), "none");
+    assert_eq!(
+        " 64 | 16 ".parse::<WidthSet>().unwrap(),
+        WidthSet::BINARY16 | WidthSet::BINARY64
+    );
+    for set in [WidthSet::EMPTY, WidthSet::NARROW, WidthSet::BINARY128] {
+        assert_eq!(set.to_string().parse::<WidthSet>().unwrap(), set);
+    }
+    for bad in ["", "8", "16,32", "16||32", "binary16"] {
+        assert!(matches!(
+            bad.parse::<WidthSet>(),
+            Err(Error::InvalidWidthSet(s)) if s == bad
+        ));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_uses_text_form() {
+    let json = serde_json::to_string(&WidthSet::NARROW).unwrap();
+    assert_eq!(json, r#""16|32|64""#);
+    let set: WidthSet = serde_json::from_str(&json).unwrap();
+    assert_eq!(set, WidthSet::NARROW);
+    assert!(serde_json::from_str::<WidthSet>(r#""12""#).is_err());
+}
+
+#[test]
+fn policy_checks_allowed_widths() {
+    let policy = NanPolicy {
+        allowed_widths: WidthSet::NARROW,
+        ..NanPolicy::permissive()
+    };
+    let wide = NanBstr::from_binary128_bits(0x7FFF_8000 << 96).unwrap();
+    assert_eq!(
+        policy.violations(&wide),
+        [PolicyViolation::WidthNotAllowed(NanWidth::Binary128)]
+    );
+    assert!(
+        policy
+            .violations(&NanBstr::from_binary32_bits(0x7FC0_0000).unwrap())
+            .is_empty()
+    );
+}
+
+#[test]
+fn pattern_width_set() {
+    let pattern = NanPattern::Width(WidthSet::BINARY16 | WidthSet::BINARY64);
+    assert!(pattern.matches(&NanBstr::from_binary16_bits(0x7E00).unwrap()));
+    assert!(
+        !pattern.matches(&NanBstr::from_binary32_bits(0x7FC0_0000).unwrap())
+    );
+    assert_eq!(
+        NanPattern::any().widths([NanWidth::Binary64, NanWidth::Binary16]),
+        NanPattern::any().and(pattern)
+    );
+}
@@ -0,0 +1,113 @@
+use cbor_nan_bstr::{Error, NanBstr};
+
+const QUAD: u128 = 0xFFFF_8123_4567_89AB_CDEF_0123_4567_89AB;
+
+#[test]
+fn u64_words_are_most_significant_first() {
+    let nan =
+        NanBstr::from_be_words(&[0xFFFF_8123_4567_89AB, 0xCDEF_0123_4567_89AB])
+            .unwrap();
+    assert_eq!(nan, NanBstr::from_binary128_bits(QUAD).unwrap());
+    assert_eq!(
+        nan,
+        NanBstr::from_binary128_words(
+            0xFFFF_8123_4567_89AB,
+            0xCDEF_0123_4567_89AB
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        nan.to_be_words().unwrap(),
+        [0xFFFF_8123_4567_89AB, 0xCDEF_0123_4567_89AB]
+    );
+
+    let nan = NanBstr::from_be_words(&[0x7FF8_0000_0000_0001]).unwrap();
+    assert_eq!(
+        nan,
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap()
+    );
+    assert_eq!(nan.to_be_words().unwrap(), [0x7FF8_0000_0000_0001]);
+}
+
+#[test]
+fn u32_words_are_most_significant_first() {
+    let words = [0xFFFF_8123, 0x4567_89AB, 0xCDEF_0123, 0x4567_89AB];
+    let nan = NanBstr::from_be_u32_words(&words).unwrap();
+    assert_eq!(nan, NanBstr::from_binary128_bits(QUAD).unwrap());
+    assert_eq!(nan.to_be_u32_words().unwrap(), words);
+
+    let nan = NanBstr::from_be_u32_words(&[0x7FF8_0000, 0x0000_0001]).unwrap();
+    assert_eq!(
+        nan,
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0001).unwrap()
+    );
+    assert_eq!(nan.to_be_u32_words().unwrap(), [0x7FF8_0000, 0x0000_0001]);
+
+    let nan = NanBstr::from_be_u32_words(&[0x7FC0_0001]).unwrap();
+    assert_eq!(nan, NanBstr::from_binary32_bits(0x7FC0_0001).unwrap());
+    assert_eq!(nan.to_be_u32_words().unwrap(), [0x7FC0_0001]);
+}
+
+#[test]
+fn narrow_widths_have_no_words() {
+    let half = NanBstr::from_binary16_bits(0x7E00).unwrap();
+    assert_eq!(half.to_be_words(), None);
+    assert_eq!(half.to_be_u32_words(), None);
+    let single = NanBstr::from_binary32_bits(0x7FC0_0000).unwrap();
+    assert_eq!(single.to_be_words(), None);
+}
+
+#[test]
+fn word_counts_without_a_width_are_invalid_length() {
+    assert!(matches!(
+        NanBstr::from_be_words(&[]),
+        Err(Error::InvalidLength(0))
+    ));
+    assert!(matches!(
+        NanBstr::from_be_words(&[u64::MAX; 3]),
+        Err(Error::InvalidLength(24))
+    ));
+    assert!(matches!(
+        NanBstr::from_be_u32_words(&[]),
+        Err(Error::InvalidLength(0))
+    ));
+    assert!(matches!(
+        NanBstr::from_be_u32_words(&[u32::MAX; 3]),
+        Err(Error::InvalidLength(12))
+    ));
+}
+
+#[test]
+fn words_must_hold_a_nan() {
+    assert!(matches!(
+        NanBstr::from_be_words(&[0x7FF0_0000_0000_0000]),
+        Err(Error::NotANan)
+    ));
+    assert!(matches!(
+        NanBstr::from_be_u32_words(&[0x3F80_0000]),
+        Err(Error::NotANan)
+    ));
+}
+
+#[test]
+fn binary128_words_are_high_then_low() {
+    let nan = NanBstr::from_binary128_bits(QUAD).unwrap();
+    assert_eq!(
+        nan.to_binary128_words(),
+        Some((0xFFFF_8123_4567_89AB, 0xCDEF_0123_4567_89AB))
+    );
+    let (high, low) = nan.to_binary128_words().unwrap();
+    assert_eq!(NanBstr::from_binary128_words(high, low).unwrap(), nan);
+    assert_eq!(nan.to_be_words().unwrap(), [high, low]);
+}
+
+#[test]
+fn narrower_widths_have_no_binary128_words() {
+    for nan in [
+        NanBstr::from_binary16_bits(0x7E00).unwrap(),
+        NanBstr::from_binary32_bits(0x7FC0_0000).unwrap(),
+        NanBstr::from_binary64_bits(0x7FF8_0000_0000_0000).unwrap(),
+    ] {
+        assert_eq!(nan.to_binary128_words(), None);
+    }
+}